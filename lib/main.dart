@@ -320,11 +320,11 @@ class _MyAppState extends State<MyApp> {
   }
 
   void refreshTrackList() {
-    futureTrackList = getAllTracks();
-    futureTrackIdsSortedByTitle = getAllTrackIdsSortedByTitle();
-    futureTrackIdsSortedByArtist = getAllTrackIdsSortedByArtist();
-    futureTrackIdsSortedByAlbum = getAllTrackIdsSortedByAlbum();
-    futureTrackIdsSortedByDuration = getAllTrackIdsSortedByDuration();
+    futureTrackList = getAllTracks(hideOffline: false);
+    futureTrackIdsSortedByTitle = getAllTrackIdsSortedByTitle(hideOffline: false);
+    futureTrackIdsSortedByArtist = getAllTrackIdsSortedByArtist(hideOffline: false);
+    futureTrackIdsSortedByAlbum = getAllTrackIdsSortedByAlbum(hideOffline: false);
+    futureTrackIdsSortedByDuration = getAllTrackIdsSortedByDuration(hideOffline: false);
   }
 
   Row bottomBarInner() {
@@ -575,9 +575,13 @@ class _MyAppState extends State<MyApp> {
             (value) {
               if (value != null) {
                 syncDirectory(mountPoint: value)
-                    .whenComplete(() => setState(() {
+                    .then((_) => setState(() {
                           refreshTrackList();
-                        }));
+                        }))
+                    .catchError((e) {
+                  ScaffoldMessenger.of(context)
+                      .showSnackBar(SnackBar(content: Text('$e')));
+                });
               }
             },
           );
@@ -711,7 +715,7 @@ class _MyAppState extends State<MyApp> {
             actions.add(MenuAction(
                 title: "Add Album to Queue",
                 callback: () {
-                  findTrackByAlbum(albumId: track.album!.id).then((tracks) {
+                  findTrackByAlbum(albumId: track.album!.id, hideOffline: false).then((tracks) {
                     AddManyToQueueAction(tracks, player, playlist.value)
                         .invoke(null);
                   });
@@ -0,0 +1,217 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.28.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import '../model.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+
+/// Every album with a visible track, by the reading of its name.
+Future<List<AlbumSummary>> getAllAlbums(
+        {required bool hideOffline, dynamic hint}) =>
+    RustLib.instance.api.getAllAlbums(hideOffline: hideOffline, hint: hint);
+
+/// Every artist credited on a visible track or album, by the reading of their
+/// name.
+Future<List<ArtistSummary>> getAllArtists(
+        {required bool hideOffline, dynamic hint}) =>
+    RustLib.instance.api.getAllArtists(hideOffline: hideOffline, hint: hint);
+
+/// The album with its tracks grouped by disc, or `None` if it has no visible
+/// track.
+Future<AlbumDetail?> getAlbumDetail(
+        {required int albumId, required bool hideOffline, dynamic hint}) =>
+    RustLib.instance.api
+        .getAlbumDetail(albumId: albumId, hideOffline: hideOffline, hint: hint);
+
+/// The artist with their albums grouped by release type, or `None` if they
+/// have no visible track or album.
+Future<ArtistDetail?> getArtistDetail(
+        {required int artistId, required bool hideOffline, dynamic hint}) =>
+    RustLib.instance.api.getArtistDetail(
+        artistId: artistId, hideOffline: hideOffline, hint: hint);
+
+class AlbumDetail {
+  final AlbumSummary album;
+  final List<DiscGroup> discs;
+
+  const AlbumDetail({
+    required this.album,
+    required this.discs,
+  });
+
+  @override
+  int get hashCode => album.hashCode ^ discs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AlbumDetail &&
+          runtimeType == other.runtimeType &&
+          album == other.album &&
+          discs == other.discs;
+}
+
+class AlbumSummary {
+  final int id;
+  final String name;
+  final Artist? artist;
+  final String? releaseType;
+  /// Earliest year any of the album's tracks is tagged with.
+  final int? year;
+  final int trackCount;
+  final int durationMs;
+  final String? pictureId;
+
+  const AlbumSummary({
+    required this.id,
+    required this.name,
+    this.artist,
+    this.releaseType,
+    this.year,
+    required this.trackCount,
+    required this.durationMs,
+    this.pictureId,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      name.hashCode ^
+      artist.hashCode ^
+      releaseType.hashCode ^
+      year.hashCode ^
+      trackCount.hashCode ^
+      durationMs.hashCode ^
+      pictureId.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AlbumSummary &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          name == other.name &&
+          artist == other.artist &&
+          releaseType == other.releaseType &&
+          year == other.year &&
+          trackCount == other.trackCount &&
+          durationMs == other.durationMs &&
+          pictureId == other.pictureId;
+}
+
+class ArtistDetail {
+  final ArtistSummary artist;
+  /// The artist's own albums, grouped by release type.
+  final List<ReleaseGroup> releaseGroups;
+  /// Albums by other artists that the artist is credited on.
+  final List<AlbumSummary> appearsOn;
+
+  const ArtistDetail({
+    required this.artist,
+    required this.releaseGroups,
+    required this.appearsOn,
+  });
+
+  @override
+  int get hashCode =>
+      artist.hashCode ^ releaseGroups.hashCode ^ appearsOn.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ArtistDetail &&
+          runtimeType == other.runtimeType &&
+          artist == other.artist &&
+          releaseGroups == other.releaseGroups &&
+          appearsOn == other.appearsOn;
+}
+
+class ArtistSummary {
+  final int id;
+  final String name;
+  /// Tracks the artist is credited on in any role.
+  final int trackCount;
+  /// Albums credited to the artist as album artist.
+  final int albumCount;
+  final int durationMs;
+  final String? pictureId;
+
+  const ArtistSummary({
+    required this.id,
+    required this.name,
+    required this.trackCount,
+    required this.albumCount,
+    required this.durationMs,
+    this.pictureId,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      name.hashCode ^
+      trackCount.hashCode ^
+      albumCount.hashCode ^
+      durationMs.hashCode ^
+      pictureId.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ArtistSummary &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          name == other.name &&
+          trackCount == other.trackCount &&
+          albumCount == other.albumCount &&
+          durationMs == other.durationMs &&
+          pictureId == other.pictureId;
+}
+
+class DiscGroup {
+  final int? disc;
+  /// The first `DISCSUBTITLE` tagged on the disc's tracks.
+  final String? subtitle;
+  final List<TrackDTO> tracks;
+
+  const DiscGroup({
+    this.disc,
+    this.subtitle,
+    required this.tracks,
+  });
+
+  @override
+  int get hashCode => disc.hashCode ^ subtitle.hashCode ^ tracks.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DiscGroup &&
+          runtimeType == other.runtimeType &&
+          disc == other.disc &&
+          subtitle == other.subtitle &&
+          tracks == other.tracks;
+}
+
+class ReleaseGroup {
+  final String? releaseType;
+  final List<AlbumSummary> albums;
+
+  const ReleaseGroup({
+    this.releaseType,
+    required this.albums,
+  });
+
+  @override
+  int get hashCode => releaseType.hashCode ^ albums.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReleaseGroup &&
+          runtimeType == other.runtimeType &&
+          releaseType == other.releaseType &&
+          albums == other.albums;
+}
@@ -0,0 +1,65 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.28.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// Returns the saved config, or the default one if none was saved or it
+/// cannot be read.
+Config getConfig({dynamic hint}) => RustLib.instance.api.getConfig(hint: hint);
+
+Future<void> setConfig({required Config config, dynamic hint}) =>
+    RustLib.instance.api.setConfig(config: config, hint: hint);
+
+Config getDefaultConfig({dynamic hint}) =>
+    RustLib.instance.api.getDefaultConfig(hint: hint);
+
+/// User settings, stored as `config.toml` in [`get_config_path`].
+class Config {
+  /// Extensions of the files the scanner imports, matched case-insensitively.
+  final List<String> extensions;
+  /// Whether files without an extension, or with one in
+  /// [`Config::sniffed_extensions`], are sniffed for an audio format instead
+  /// of being skipped.
+  final bool detectByContent;
+  /// Extensions of files that are sniffed for an audio format when
+  /// [`Config::detect_by_content`] is set, for audio saved under a wrong
+  /// extension. Other extensions not in [`Config::extensions`] are skipped
+  /// without being opened.
+  final List<String> sniffedExtensions;
+  /// Strings that split an artist, remixer or composer tag into several
+  /// artists, matched case-insensitively. Artists after a featuring
+  /// separator such as `" feat. "` are credited as featured artists.
+  final List<String> artistSeparators;
+  /// Strings that split a genre tag into several genres.
+  final List<String> genreSeparators;
+
+  const Config({
+    required this.extensions,
+    required this.detectByContent,
+    required this.sniffedExtensions,
+    required this.artistSeparators,
+    required this.genreSeparators,
+  });
+
+  @override
+  int get hashCode =>
+      extensions.hashCode ^
+      detectByContent.hashCode ^
+      sniffedExtensions.hashCode ^
+      artistSeparators.hashCode ^
+      genreSeparators.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Config &&
+          runtimeType == other.runtimeType &&
+          extensions == other.extensions &&
+          detectByContent == other.detectByContent &&
+          sniffedExtensions == other.sniffedExtensions &&
+          artistSeparators == other.artistSeparators &&
+          genreSeparators == other.genreSeparators;
+}
@@ -0,0 +1,90 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.28.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import '../model.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+
+// The type `FoldedText` is not used by any `pub` functions, thus it is ignored.
+// The type `WordMatch` is not used by any `pub` functions, thus it is ignored.
+
+/// Up to `limit` tracks whose title, album or main artist match every word
+/// of `query`, best matches first.
+///
+/// Matching ignores case, character width and diacritics, tolerates a typo
+/// in words of four or more characters and two in words of eight or more,
+/// and also looks at the romaji and hiragana readings of Japanese text.
+/// Title matches count for more than album matches, which count for more
+/// than artist matches.
+Future<List<TrackSearchResult>> fuzzySearchTracks(
+        {required String query,
+        required int limit,
+        required bool hideOffline,
+        dynamic hint}) =>
+    RustLib.instance.api.fuzzySearchTracks(
+        query: query, limit: limit, hideOffline: hideOffline, hint: hint);
+
+/// Part of a text to highlight, in UTF-16 code units from its start as Dart
+/// indexes strings, end exclusive.
+class HighlightRange {
+  final int start;
+  final int end;
+
+  const HighlightRange({
+    required this.start,
+    required this.end,
+  });
+
+  @override
+  int get hashCode => start.hashCode ^ end.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is HighlightRange &&
+          runtimeType == other.runtimeType &&
+          start == other.start &&
+          end == other.end;
+}
+
+class TrackSearchResult {
+  final TrackDTO track;
+  /// Between 0 and 1, higher for closer matches in weightier fields.
+  final double score;
+  /// Matched parts of the track's title.
+  final List<HighlightRange> titleRanges;
+  /// Matched parts of the name of the track's album.
+  final List<HighlightRange> albumRanges;
+  /// Matched parts of the name of the track's main artist.
+  final List<HighlightRange> artistRanges;
+
+  const TrackSearchResult({
+    required this.track,
+    required this.score,
+    required this.titleRanges,
+    required this.albumRanges,
+    required this.artistRanges,
+  });
+
+  @override
+  int get hashCode =>
+      track.hashCode ^
+      score.hashCode ^
+      titleRanges.hashCode ^
+      albumRanges.hashCode ^
+      artistRanges.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TrackSearchResult &&
+          runtimeType == other.runtimeType &&
+          track == other.track &&
+          score == other.score &&
+          titleRanges == other.titleRanges &&
+          albumRanges == other.albumRanges &&
+          artistRanges == other.artistRanges;
+}
@@ -0,0 +1,79 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.28.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import '../model.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'scan.dart';
+
+/// Registers a folder as a library root. The folder is not scanned until
+/// [`rescan_library_root`] is called.
+///
+/// Roots may not be nested, since every track belongs to exactly one root.
+Future<LibraryRoot> addLibraryRoot({required String path, dynamic hint}) =>
+    RustLib.instance.api.addLibraryRoot(path: path, hint: hint);
+
+Future<List<LibraryRoot>> getLibraryRoots({dynamic hint}) =>
+    RustLib.instance.api.getLibraryRoots(hint: hint);
+
+Future<List<LibraryRootAvailability>> getLibraryRootAvailability(
+        {dynamic hint}) =>
+    RustLib.instance.api.getLibraryRootAvailability(hint: hint);
+
+/// Unregisters a library root and removes its tracks, along with any albums
+/// and artists left without tracks.
+Future<void> removeLibraryRoot({required int id, dynamic hint}) =>
+    RustLib.instance.api.removeLibraryRoot(id: id, hint: hint);
+
+/// Disabled roots keep their tracks but are skipped by
+/// [`rescan_library_roots`] and by the library watcher.
+Future<void> setLibraryRootEnabled(
+        {required int id, required bool enabled, dynamic hint}) =>
+    RustLib.instance.api
+        .setLibraryRootEnabled(id: id, enabled: enabled, hint: hint);
+
+Future<SyncSummary> rescanLibraryRoot({required int id, dynamic hint}) =>
+    RustLib.instance.api.rescanLibraryRoot(id: id, hint: hint);
+
+/// Rescans every enabled library root, returning one summary per root in the
+/// order of [`get_library_roots`].
+Future<List<SyncSummary>> rescanLibraryRoots({dynamic hint}) =>
+    RustLib.instance.api.rescanLibraryRoots(hint: hint);
+
+/// Points a library root at a new folder, rewriting the `location` and
+/// `mount_point` of its tracks in place so their ids are kept.
+///
+/// Fails without changing anything if any track's file is missing under
+/// `new_path`.
+Future<LibraryRoot> relocateLibraryRoot(
+        {required int id, required String newPath, dynamic hint}) =>
+    RustLib.instance.api
+        .relocateLibraryRoot(id: id, newPath: newPath, hint: hint);
+
+/// Reachability of a library root, e.g. whether its removable drive is
+/// mounted.
+class LibraryRootAvailability {
+  final int id;
+  final String path;
+  final bool available;
+
+  const LibraryRootAvailability({
+    required this.id,
+    required this.path,
+    required this.available,
+  });
+
+  @override
+  int get hashCode => id.hashCode ^ path.hashCode ^ available.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LibraryRootAvailability &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          path == other.path &&
+          available == other.available;
+}
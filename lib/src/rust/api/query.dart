@@ -0,0 +1,174 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.28.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import '../model.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+import 'simple.dart';
+part 'query.freezed.dart';
+
+/// Ids of the tracks matching `query`, in its order.
+Future<Int32List> queryTrackIds({required TrackQuery query, dynamic hint}) =>
+    RustLib.instance.api.queryTrackIds(query: query, hint: hint);
+
+/// The tracks matching `query`, in its order.
+Future<List<TrackDTO>> queryTracks({required TrackQuery query, dynamic hint}) =>
+    RustLib.instance.api.queryTracks(query: query, hint: hint);
+
+/// What tracks can be sorted by. Titles and names sort by their hiragana
+/// reading, so Japanese text comes in kana order after Latin text.
+enum SortField {
+  title,
+  /// The track's main artist.
+  artist,
+  /// The album artist, or the track artist for tracks without an album.
+  albumArtist,
+  /// Album name, keeping albums of the same name apart.
+  album,
+  /// Disc, then track number, then location, as albums are played.
+  trackNumber,
+  duration,
+  year,
+  dateAdded,
+  bitrate,
+  sampleRate,
+  bitDepth,
+  channels,
+  fileSize,
+  playCount,
+  lastPlayed,
+}
+
+class SortKey {
+  final SortField field;
+  final bool descending;
+
+  const SortKey({
+    required this.field,
+    required this.descending,
+  });
+
+  @override
+  int get hashCode => field.hashCode ^ descending.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SortKey &&
+          runtimeType == other.runtimeType &&
+          field == other.field &&
+          descending == other.descending;
+}
+
+/// Which text of a track a [`TrackFilter::Text`] looks at.
+enum TextField {
+  /// Title, artists, album, genres and composers.
+  any,
+  title,
+  /// Every credited artist and the album artist.
+  artist,
+  album,
+  genre,
+  composer,
+}
+
+@freezed
+sealed class TrackFilter with _$TrackFilter {
+  /// Tracks the artist is credited on in any role.
+  const factory TrackFilter.artist(
+    int field0,
+  ) = TrackFilter_Artist;
+  const factory TrackFilter.album(
+    int field0,
+  ) = TrackFilter_Album;
+  const factory TrackFilter.genre(
+    int field0,
+  ) = TrackFilter_Genre;
+  const factory TrackFilter.year({
+    int? min,
+    int? max,
+  }) = TrackFilter_Year;
+  const factory TrackFilter.durationMs({
+    int? min,
+    int? max,
+  }) = TrackFilter_DurationMs;
+  /// Lowercase codec name, e.g. `flac`, `mp3`, `alac` or `pcm`.
+  const factory TrackFilter.codec(
+    String field0,
+  ) = TrackFilter_Codec;
+  /// File extension without the dot, e.g. `flac`, `m4a` or `wav`, matched
+  /// case-insensitively.
+  const factory TrackFilter.fileExtension(
+    String field0,
+  ) = TrackFilter_FileExtension;
+  const factory TrackFilter.lossless(
+    bool field0,
+  ) = TrackFilter_Lossless;
+  const factory TrackFilter.bitrate({
+    int? min,
+    int? max,
+  }) = TrackFilter_Bitrate;
+  const factory TrackFilter.minSampleRate(
+    int field0,
+  ) = TrackFilter_MinSampleRate;
+  const factory TrackFilter.minBitDepth(
+    int field0,
+  ) = TrackFilter_MinBitDepth;
+  const factory TrackFilter.channels(
+    int field0,
+  ) = TrackFilter_Channels;
+  /// Tracks below the library root with this id.
+  const factory TrackFilter.libraryRoot(
+    int field0,
+  ) = TrackFilter_LibraryRoot;
+  /// Tracks with words starting with every word of `text` in `field`, as
+  /// written or by their romaji or hiragana reading.
+  const factory TrackFilter.text({
+    required TextField field,
+    required String text,
+  }) = TrackFilter_Text;
+  /// Tracks not matching the inner filter.
+  const factory TrackFilter.not(
+    TrackFilter field0,
+  ) = TrackFilter_Not;
+}
+
+class TrackQuery {
+  /// Sort keys, most significant first. Tracks missing a sorted value come
+  /// last in either direction, and ties are broken by id.
+  final List<SortKey> sort;
+  final List<TrackFilter> filters;
+  final bool hideOffline;
+  final int offset;
+  final int? limit;
+
+  const TrackQuery({
+    required this.sort,
+    required this.filters,
+    required this.hideOffline,
+    required this.offset,
+    this.limit,
+  });
+
+  @override
+  int get hashCode =>
+      sort.hashCode ^
+      filters.hashCode ^
+      hideOffline.hashCode ^
+      offset.hashCode ^
+      limit.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TrackQuery &&
+          runtimeType == other.runtimeType &&
+          sort == other.sort &&
+          filters == other.filters &&
+          hideOffline == other.hideOffline &&
+          offset == other.offset &&
+          limit == other.limit;
+}
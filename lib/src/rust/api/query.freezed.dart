@@ -0,0 +1,3421 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'query.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+    'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models');
+
+/// @nodoc
+mixin _$TrackFilter {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int field0) artist,
+    required TResult Function(int field0) album,
+    required TResult Function(int field0) genre,
+    required TResult Function(int? min, int? max) year,
+    required TResult Function(int? min, int? max) durationMs,
+    required TResult Function(String field0) codec,
+    required TResult Function(String field0) fileExtension,
+    required TResult Function(bool field0) lossless,
+    required TResult Function(int? min, int? max) bitrate,
+    required TResult Function(int field0) minSampleRate,
+    required TResult Function(int field0) minBitDepth,
+    required TResult Function(int field0) channels,
+    required TResult Function(int field0) libraryRoot,
+    required TResult Function(TextField field, String text) text,
+    required TResult Function(TrackFilter field0) not,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int field0)? artist,
+    TResult? Function(int field0)? album,
+    TResult? Function(int field0)? genre,
+    TResult? Function(int? min, int? max)? year,
+    TResult? Function(int? min, int? max)? durationMs,
+    TResult? Function(String field0)? codec,
+    TResult? Function(String field0)? fileExtension,
+    TResult? Function(bool field0)? lossless,
+    TResult? Function(int? min, int? max)? bitrate,
+    TResult? Function(int field0)? minSampleRate,
+    TResult? Function(int field0)? minBitDepth,
+    TResult? Function(int field0)? channels,
+    TResult? Function(int field0)? libraryRoot,
+    TResult? Function(TextField field, String text)? text,
+    TResult? Function(TrackFilter field0)? not,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int field0)? artist,
+    TResult Function(int field0)? album,
+    TResult Function(int field0)? genre,
+    TResult Function(int? min, int? max)? year,
+    TResult Function(int? min, int? max)? durationMs,
+    TResult Function(String field0)? codec,
+    TResult Function(String field0)? fileExtension,
+    TResult Function(bool field0)? lossless,
+    TResult Function(int? min, int? max)? bitrate,
+    TResult Function(int field0)? minSampleRate,
+    TResult Function(int field0)? minBitDepth,
+    TResult Function(int field0)? channels,
+    TResult Function(int field0)? libraryRoot,
+    TResult Function(TextField field, String text)? text,
+    TResult Function(TrackFilter field0)? not,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(TrackFilter_Artist value) artist,
+    required TResult Function(TrackFilter_Album value) album,
+    required TResult Function(TrackFilter_Genre value) genre,
+    required TResult Function(TrackFilter_Year value) year,
+    required TResult Function(TrackFilter_DurationMs value) durationMs,
+    required TResult Function(TrackFilter_Codec value) codec,
+    required TResult Function(TrackFilter_FileExtension value) fileExtension,
+    required TResult Function(TrackFilter_Lossless value) lossless,
+    required TResult Function(TrackFilter_Bitrate value) bitrate,
+    required TResult Function(TrackFilter_MinSampleRate value) minSampleRate,
+    required TResult Function(TrackFilter_MinBitDepth value) minBitDepth,
+    required TResult Function(TrackFilter_Channels value) channels,
+    required TResult Function(TrackFilter_LibraryRoot value) libraryRoot,
+    required TResult Function(TrackFilter_Text value) text,
+    required TResult Function(TrackFilter_Not value) not,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(TrackFilter_Artist value)? artist,
+    TResult? Function(TrackFilter_Album value)? album,
+    TResult? Function(TrackFilter_Genre value)? genre,
+    TResult? Function(TrackFilter_Year value)? year,
+    TResult? Function(TrackFilter_DurationMs value)? durationMs,
+    TResult? Function(TrackFilter_Codec value)? codec,
+    TResult? Function(TrackFilter_FileExtension value)? fileExtension,
+    TResult? Function(TrackFilter_Lossless value)? lossless,
+    TResult? Function(TrackFilter_Bitrate value)? bitrate,
+    TResult? Function(TrackFilter_MinSampleRate value)? minSampleRate,
+    TResult? Function(TrackFilter_MinBitDepth value)? minBitDepth,
+    TResult? Function(TrackFilter_Channels value)? channels,
+    TResult? Function(TrackFilter_LibraryRoot value)? libraryRoot,
+    TResult? Function(TrackFilter_Text value)? text,
+    TResult? Function(TrackFilter_Not value)? not,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(TrackFilter_Artist value)? artist,
+    TResult Function(TrackFilter_Album value)? album,
+    TResult Function(TrackFilter_Genre value)? genre,
+    TResult Function(TrackFilter_Year value)? year,
+    TResult Function(TrackFilter_DurationMs value)? durationMs,
+    TResult Function(TrackFilter_Codec value)? codec,
+    TResult Function(TrackFilter_FileExtension value)? fileExtension,
+    TResult Function(TrackFilter_Lossless value)? lossless,
+    TResult Function(TrackFilter_Bitrate value)? bitrate,
+    TResult Function(TrackFilter_MinSampleRate value)? minSampleRate,
+    TResult Function(TrackFilter_MinBitDepth value)? minBitDepth,
+    TResult Function(TrackFilter_Channels value)? channels,
+    TResult Function(TrackFilter_LibraryRoot value)? libraryRoot,
+    TResult Function(TrackFilter_Text value)? text,
+    TResult Function(TrackFilter_Not value)? not,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $TrackFilterCopyWith<$Res> {
+  factory $TrackFilterCopyWith(
+          TrackFilter value, $Res Function(TrackFilter) then) =
+      _$TrackFilterCopyWithImpl<$Res, TrackFilter>;
+}
+
+/// @nodoc
+class _$TrackFilterCopyWithImpl<$Res, $Val extends TrackFilter>
+    implements $TrackFilterCopyWith<$Res> {
+  _$TrackFilterCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+}
+
+/// @nodoc
+abstract class _$$TrackFilter_ArtistImplCopyWith<$Res> {
+  factory _$$TrackFilter_ArtistImplCopyWith(
+          _$TrackFilter_ArtistImpl value,
+          $Res Function(_$TrackFilter_ArtistImpl) then) =
+      __$$TrackFilter_ArtistImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({int field0});
+}
+
+/// @nodoc
+class __$$TrackFilter_ArtistImplCopyWithImpl<$Res>
+    extends _$TrackFilterCopyWithImpl<$Res, _$TrackFilter_ArtistImpl>
+    implements _$$TrackFilter_ArtistImplCopyWith<$Res> {
+  __$$TrackFilter_ArtistImplCopyWithImpl(
+      _$TrackFilter_ArtistImpl _value,
+      $Res Function(_$TrackFilter_ArtistImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$TrackFilter_ArtistImpl(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$TrackFilter_ArtistImpl implements TrackFilter_Artist {
+  const _$TrackFilter_ArtistImpl(this.field0);
+
+  @override
+  final int field0;
+
+  @override
+  String toString() {
+    return 'TrackFilter.artist(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$TrackFilter_ArtistImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$TrackFilter_ArtistImplCopyWith<_$TrackFilter_ArtistImpl> get copyWith =>
+      __$$TrackFilter_ArtistImplCopyWithImpl<_$TrackFilter_ArtistImpl>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int field0) artist,
+    required TResult Function(int field0) album,
+    required TResult Function(int field0) genre,
+    required TResult Function(int? min, int? max) year,
+    required TResult Function(int? min, int? max) durationMs,
+    required TResult Function(String field0) codec,
+    required TResult Function(String field0) fileExtension,
+    required TResult Function(bool field0) lossless,
+    required TResult Function(int? min, int? max) bitrate,
+    required TResult Function(int field0) minSampleRate,
+    required TResult Function(int field0) minBitDepth,
+    required TResult Function(int field0) channels,
+    required TResult Function(int field0) libraryRoot,
+    required TResult Function(TextField field, String text) text,
+    required TResult Function(TrackFilter field0) not,
+  }) {
+    return artist(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int field0)? artist,
+    TResult? Function(int field0)? album,
+    TResult? Function(int field0)? genre,
+    TResult? Function(int? min, int? max)? year,
+    TResult? Function(int? min, int? max)? durationMs,
+    TResult? Function(String field0)? codec,
+    TResult? Function(String field0)? fileExtension,
+    TResult? Function(bool field0)? lossless,
+    TResult? Function(int? min, int? max)? bitrate,
+    TResult? Function(int field0)? minSampleRate,
+    TResult? Function(int field0)? minBitDepth,
+    TResult? Function(int field0)? channels,
+    TResult? Function(int field0)? libraryRoot,
+    TResult? Function(TextField field, String text)? text,
+    TResult? Function(TrackFilter field0)? not,
+  }) {
+    return artist?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int field0)? artist,
+    TResult Function(int field0)? album,
+    TResult Function(int field0)? genre,
+    TResult Function(int? min, int? max)? year,
+    TResult Function(int? min, int? max)? durationMs,
+    TResult Function(String field0)? codec,
+    TResult Function(String field0)? fileExtension,
+    TResult Function(bool field0)? lossless,
+    TResult Function(int? min, int? max)? bitrate,
+    TResult Function(int field0)? minSampleRate,
+    TResult Function(int field0)? minBitDepth,
+    TResult Function(int field0)? channels,
+    TResult Function(int field0)? libraryRoot,
+    TResult Function(TextField field, String text)? text,
+    TResult Function(TrackFilter field0)? not,
+    required TResult orElse(),
+  }) {
+    if (artist != null) {
+      return artist(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(TrackFilter_Artist value) artist,
+    required TResult Function(TrackFilter_Album value) album,
+    required TResult Function(TrackFilter_Genre value) genre,
+    required TResult Function(TrackFilter_Year value) year,
+    required TResult Function(TrackFilter_DurationMs value) durationMs,
+    required TResult Function(TrackFilter_Codec value) codec,
+    required TResult Function(TrackFilter_FileExtension value) fileExtension,
+    required TResult Function(TrackFilter_Lossless value) lossless,
+    required TResult Function(TrackFilter_Bitrate value) bitrate,
+    required TResult Function(TrackFilter_MinSampleRate value) minSampleRate,
+    required TResult Function(TrackFilter_MinBitDepth value) minBitDepth,
+    required TResult Function(TrackFilter_Channels value) channels,
+    required TResult Function(TrackFilter_LibraryRoot value) libraryRoot,
+    required TResult Function(TrackFilter_Text value) text,
+    required TResult Function(TrackFilter_Not value) not,
+  }) {
+    return artist(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(TrackFilter_Artist value)? artist,
+    TResult? Function(TrackFilter_Album value)? album,
+    TResult? Function(TrackFilter_Genre value)? genre,
+    TResult? Function(TrackFilter_Year value)? year,
+    TResult? Function(TrackFilter_DurationMs value)? durationMs,
+    TResult? Function(TrackFilter_Codec value)? codec,
+    TResult? Function(TrackFilter_FileExtension value)? fileExtension,
+    TResult? Function(TrackFilter_Lossless value)? lossless,
+    TResult? Function(TrackFilter_Bitrate value)? bitrate,
+    TResult? Function(TrackFilter_MinSampleRate value)? minSampleRate,
+    TResult? Function(TrackFilter_MinBitDepth value)? minBitDepth,
+    TResult? Function(TrackFilter_Channels value)? channels,
+    TResult? Function(TrackFilter_LibraryRoot value)? libraryRoot,
+    TResult? Function(TrackFilter_Text value)? text,
+    TResult? Function(TrackFilter_Not value)? not,
+  }) {
+    return artist?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(TrackFilter_Artist value)? artist,
+    TResult Function(TrackFilter_Album value)? album,
+    TResult Function(TrackFilter_Genre value)? genre,
+    TResult Function(TrackFilter_Year value)? year,
+    TResult Function(TrackFilter_DurationMs value)? durationMs,
+    TResult Function(TrackFilter_Codec value)? codec,
+    TResult Function(TrackFilter_FileExtension value)? fileExtension,
+    TResult Function(TrackFilter_Lossless value)? lossless,
+    TResult Function(TrackFilter_Bitrate value)? bitrate,
+    TResult Function(TrackFilter_MinSampleRate value)? minSampleRate,
+    TResult Function(TrackFilter_MinBitDepth value)? minBitDepth,
+    TResult Function(TrackFilter_Channels value)? channels,
+    TResult Function(TrackFilter_LibraryRoot value)? libraryRoot,
+    TResult Function(TrackFilter_Text value)? text,
+    TResult Function(TrackFilter_Not value)? not,
+    required TResult orElse(),
+  }) {
+    if (artist != null) {
+      return artist(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class TrackFilter_Artist implements TrackFilter {
+  const factory TrackFilter_Artist(final int field0) = _$TrackFilter_ArtistImpl;
+
+  int get field0;
+  @JsonKey(ignore: true)
+  _$$TrackFilter_ArtistImplCopyWith<_$TrackFilter_ArtistImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$TrackFilter_AlbumImplCopyWith<$Res> {
+  factory _$$TrackFilter_AlbumImplCopyWith(
+          _$TrackFilter_AlbumImpl value,
+          $Res Function(_$TrackFilter_AlbumImpl) then) =
+      __$$TrackFilter_AlbumImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({int field0});
+}
+
+/// @nodoc
+class __$$TrackFilter_AlbumImplCopyWithImpl<$Res>
+    extends _$TrackFilterCopyWithImpl<$Res, _$TrackFilter_AlbumImpl>
+    implements _$$TrackFilter_AlbumImplCopyWith<$Res> {
+  __$$TrackFilter_AlbumImplCopyWithImpl(
+      _$TrackFilter_AlbumImpl _value,
+      $Res Function(_$TrackFilter_AlbumImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$TrackFilter_AlbumImpl(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$TrackFilter_AlbumImpl implements TrackFilter_Album {
+  const _$TrackFilter_AlbumImpl(this.field0);
+
+  @override
+  final int field0;
+
+  @override
+  String toString() {
+    return 'TrackFilter.album(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$TrackFilter_AlbumImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$TrackFilter_AlbumImplCopyWith<_$TrackFilter_AlbumImpl> get copyWith =>
+      __$$TrackFilter_AlbumImplCopyWithImpl<_$TrackFilter_AlbumImpl>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int field0) artist,
+    required TResult Function(int field0) album,
+    required TResult Function(int field0) genre,
+    required TResult Function(int? min, int? max) year,
+    required TResult Function(int? min, int? max) durationMs,
+    required TResult Function(String field0) codec,
+    required TResult Function(String field0) fileExtension,
+    required TResult Function(bool field0) lossless,
+    required TResult Function(int? min, int? max) bitrate,
+    required TResult Function(int field0) minSampleRate,
+    required TResult Function(int field0) minBitDepth,
+    required TResult Function(int field0) channels,
+    required TResult Function(int field0) libraryRoot,
+    required TResult Function(TextField field, String text) text,
+    required TResult Function(TrackFilter field0) not,
+  }) {
+    return album(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int field0)? artist,
+    TResult? Function(int field0)? album,
+    TResult? Function(int field0)? genre,
+    TResult? Function(int? min, int? max)? year,
+    TResult? Function(int? min, int? max)? durationMs,
+    TResult? Function(String field0)? codec,
+    TResult? Function(String field0)? fileExtension,
+    TResult? Function(bool field0)? lossless,
+    TResult? Function(int? min, int? max)? bitrate,
+    TResult? Function(int field0)? minSampleRate,
+    TResult? Function(int field0)? minBitDepth,
+    TResult? Function(int field0)? channels,
+    TResult? Function(int field0)? libraryRoot,
+    TResult? Function(TextField field, String text)? text,
+    TResult? Function(TrackFilter field0)? not,
+  }) {
+    return album?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int field0)? artist,
+    TResult Function(int field0)? album,
+    TResult Function(int field0)? genre,
+    TResult Function(int? min, int? max)? year,
+    TResult Function(int? min, int? max)? durationMs,
+    TResult Function(String field0)? codec,
+    TResult Function(String field0)? fileExtension,
+    TResult Function(bool field0)? lossless,
+    TResult Function(int? min, int? max)? bitrate,
+    TResult Function(int field0)? minSampleRate,
+    TResult Function(int field0)? minBitDepth,
+    TResult Function(int field0)? channels,
+    TResult Function(int field0)? libraryRoot,
+    TResult Function(TextField field, String text)? text,
+    TResult Function(TrackFilter field0)? not,
+    required TResult orElse(),
+  }) {
+    if (album != null) {
+      return album(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(TrackFilter_Artist value) artist,
+    required TResult Function(TrackFilter_Album value) album,
+    required TResult Function(TrackFilter_Genre value) genre,
+    required TResult Function(TrackFilter_Year value) year,
+    required TResult Function(TrackFilter_DurationMs value) durationMs,
+    required TResult Function(TrackFilter_Codec value) codec,
+    required TResult Function(TrackFilter_FileExtension value) fileExtension,
+    required TResult Function(TrackFilter_Lossless value) lossless,
+    required TResult Function(TrackFilter_Bitrate value) bitrate,
+    required TResult Function(TrackFilter_MinSampleRate value) minSampleRate,
+    required TResult Function(TrackFilter_MinBitDepth value) minBitDepth,
+    required TResult Function(TrackFilter_Channels value) channels,
+    required TResult Function(TrackFilter_LibraryRoot value) libraryRoot,
+    required TResult Function(TrackFilter_Text value) text,
+    required TResult Function(TrackFilter_Not value) not,
+  }) {
+    return album(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(TrackFilter_Artist value)? artist,
+    TResult? Function(TrackFilter_Album value)? album,
+    TResult? Function(TrackFilter_Genre value)? genre,
+    TResult? Function(TrackFilter_Year value)? year,
+    TResult? Function(TrackFilter_DurationMs value)? durationMs,
+    TResult? Function(TrackFilter_Codec value)? codec,
+    TResult? Function(TrackFilter_FileExtension value)? fileExtension,
+    TResult? Function(TrackFilter_Lossless value)? lossless,
+    TResult? Function(TrackFilter_Bitrate value)? bitrate,
+    TResult? Function(TrackFilter_MinSampleRate value)? minSampleRate,
+    TResult? Function(TrackFilter_MinBitDepth value)? minBitDepth,
+    TResult? Function(TrackFilter_Channels value)? channels,
+    TResult? Function(TrackFilter_LibraryRoot value)? libraryRoot,
+    TResult? Function(TrackFilter_Text value)? text,
+    TResult? Function(TrackFilter_Not value)? not,
+  }) {
+    return album?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(TrackFilter_Artist value)? artist,
+    TResult Function(TrackFilter_Album value)? album,
+    TResult Function(TrackFilter_Genre value)? genre,
+    TResult Function(TrackFilter_Year value)? year,
+    TResult Function(TrackFilter_DurationMs value)? durationMs,
+    TResult Function(TrackFilter_Codec value)? codec,
+    TResult Function(TrackFilter_FileExtension value)? fileExtension,
+    TResult Function(TrackFilter_Lossless value)? lossless,
+    TResult Function(TrackFilter_Bitrate value)? bitrate,
+    TResult Function(TrackFilter_MinSampleRate value)? minSampleRate,
+    TResult Function(TrackFilter_MinBitDepth value)? minBitDepth,
+    TResult Function(TrackFilter_Channels value)? channels,
+    TResult Function(TrackFilter_LibraryRoot value)? libraryRoot,
+    TResult Function(TrackFilter_Text value)? text,
+    TResult Function(TrackFilter_Not value)? not,
+    required TResult orElse(),
+  }) {
+    if (album != null) {
+      return album(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class TrackFilter_Album implements TrackFilter {
+  const factory TrackFilter_Album(final int field0) = _$TrackFilter_AlbumImpl;
+
+  int get field0;
+  @JsonKey(ignore: true)
+  _$$TrackFilter_AlbumImplCopyWith<_$TrackFilter_AlbumImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$TrackFilter_GenreImplCopyWith<$Res> {
+  factory _$$TrackFilter_GenreImplCopyWith(
+          _$TrackFilter_GenreImpl value,
+          $Res Function(_$TrackFilter_GenreImpl) then) =
+      __$$TrackFilter_GenreImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({int field0});
+}
+
+/// @nodoc
+class __$$TrackFilter_GenreImplCopyWithImpl<$Res>
+    extends _$TrackFilterCopyWithImpl<$Res, _$TrackFilter_GenreImpl>
+    implements _$$TrackFilter_GenreImplCopyWith<$Res> {
+  __$$TrackFilter_GenreImplCopyWithImpl(
+      _$TrackFilter_GenreImpl _value,
+      $Res Function(_$TrackFilter_GenreImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$TrackFilter_GenreImpl(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$TrackFilter_GenreImpl implements TrackFilter_Genre {
+  const _$TrackFilter_GenreImpl(this.field0);
+
+  @override
+  final int field0;
+
+  @override
+  String toString() {
+    return 'TrackFilter.genre(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$TrackFilter_GenreImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$TrackFilter_GenreImplCopyWith<_$TrackFilter_GenreImpl> get copyWith =>
+      __$$TrackFilter_GenreImplCopyWithImpl<_$TrackFilter_GenreImpl>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int field0) artist,
+    required TResult Function(int field0) album,
+    required TResult Function(int field0) genre,
+    required TResult Function(int? min, int? max) year,
+    required TResult Function(int? min, int? max) durationMs,
+    required TResult Function(String field0) codec,
+    required TResult Function(String field0) fileExtension,
+    required TResult Function(bool field0) lossless,
+    required TResult Function(int? min, int? max) bitrate,
+    required TResult Function(int field0) minSampleRate,
+    required TResult Function(int field0) minBitDepth,
+    required TResult Function(int field0) channels,
+    required TResult Function(int field0) libraryRoot,
+    required TResult Function(TextField field, String text) text,
+    required TResult Function(TrackFilter field0) not,
+  }) {
+    return genre(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int field0)? artist,
+    TResult? Function(int field0)? album,
+    TResult? Function(int field0)? genre,
+    TResult? Function(int? min, int? max)? year,
+    TResult? Function(int? min, int? max)? durationMs,
+    TResult? Function(String field0)? codec,
+    TResult? Function(String field0)? fileExtension,
+    TResult? Function(bool field0)? lossless,
+    TResult? Function(int? min, int? max)? bitrate,
+    TResult? Function(int field0)? minSampleRate,
+    TResult? Function(int field0)? minBitDepth,
+    TResult? Function(int field0)? channels,
+    TResult? Function(int field0)? libraryRoot,
+    TResult? Function(TextField field, String text)? text,
+    TResult? Function(TrackFilter field0)? not,
+  }) {
+    return genre?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int field0)? artist,
+    TResult Function(int field0)? album,
+    TResult Function(int field0)? genre,
+    TResult Function(int? min, int? max)? year,
+    TResult Function(int? min, int? max)? durationMs,
+    TResult Function(String field0)? codec,
+    TResult Function(String field0)? fileExtension,
+    TResult Function(bool field0)? lossless,
+    TResult Function(int? min, int? max)? bitrate,
+    TResult Function(int field0)? minSampleRate,
+    TResult Function(int field0)? minBitDepth,
+    TResult Function(int field0)? channels,
+    TResult Function(int field0)? libraryRoot,
+    TResult Function(TextField field, String text)? text,
+    TResult Function(TrackFilter field0)? not,
+    required TResult orElse(),
+  }) {
+    if (genre != null) {
+      return genre(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(TrackFilter_Artist value) artist,
+    required TResult Function(TrackFilter_Album value) album,
+    required TResult Function(TrackFilter_Genre value) genre,
+    required TResult Function(TrackFilter_Year value) year,
+    required TResult Function(TrackFilter_DurationMs value) durationMs,
+    required TResult Function(TrackFilter_Codec value) codec,
+    required TResult Function(TrackFilter_FileExtension value) fileExtension,
+    required TResult Function(TrackFilter_Lossless value) lossless,
+    required TResult Function(TrackFilter_Bitrate value) bitrate,
+    required TResult Function(TrackFilter_MinSampleRate value) minSampleRate,
+    required TResult Function(TrackFilter_MinBitDepth value) minBitDepth,
+    required TResult Function(TrackFilter_Channels value) channels,
+    required TResult Function(TrackFilter_LibraryRoot value) libraryRoot,
+    required TResult Function(TrackFilter_Text value) text,
+    required TResult Function(TrackFilter_Not value) not,
+  }) {
+    return genre(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(TrackFilter_Artist value)? artist,
+    TResult? Function(TrackFilter_Album value)? album,
+    TResult? Function(TrackFilter_Genre value)? genre,
+    TResult? Function(TrackFilter_Year value)? year,
+    TResult? Function(TrackFilter_DurationMs value)? durationMs,
+    TResult? Function(TrackFilter_Codec value)? codec,
+    TResult? Function(TrackFilter_FileExtension value)? fileExtension,
+    TResult? Function(TrackFilter_Lossless value)? lossless,
+    TResult? Function(TrackFilter_Bitrate value)? bitrate,
+    TResult? Function(TrackFilter_MinSampleRate value)? minSampleRate,
+    TResult? Function(TrackFilter_MinBitDepth value)? minBitDepth,
+    TResult? Function(TrackFilter_Channels value)? channels,
+    TResult? Function(TrackFilter_LibraryRoot value)? libraryRoot,
+    TResult? Function(TrackFilter_Text value)? text,
+    TResult? Function(TrackFilter_Not value)? not,
+  }) {
+    return genre?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(TrackFilter_Artist value)? artist,
+    TResult Function(TrackFilter_Album value)? album,
+    TResult Function(TrackFilter_Genre value)? genre,
+    TResult Function(TrackFilter_Year value)? year,
+    TResult Function(TrackFilter_DurationMs value)? durationMs,
+    TResult Function(TrackFilter_Codec value)? codec,
+    TResult Function(TrackFilter_FileExtension value)? fileExtension,
+    TResult Function(TrackFilter_Lossless value)? lossless,
+    TResult Function(TrackFilter_Bitrate value)? bitrate,
+    TResult Function(TrackFilter_MinSampleRate value)? minSampleRate,
+    TResult Function(TrackFilter_MinBitDepth value)? minBitDepth,
+    TResult Function(TrackFilter_Channels value)? channels,
+    TResult Function(TrackFilter_LibraryRoot value)? libraryRoot,
+    TResult Function(TrackFilter_Text value)? text,
+    TResult Function(TrackFilter_Not value)? not,
+    required TResult orElse(),
+  }) {
+    if (genre != null) {
+      return genre(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class TrackFilter_Genre implements TrackFilter {
+  const factory TrackFilter_Genre(final int field0) = _$TrackFilter_GenreImpl;
+
+  int get field0;
+  @JsonKey(ignore: true)
+  _$$TrackFilter_GenreImplCopyWith<_$TrackFilter_GenreImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$TrackFilter_YearImplCopyWith<$Res> {
+  factory _$$TrackFilter_YearImplCopyWith(
+          _$TrackFilter_YearImpl value,
+          $Res Function(_$TrackFilter_YearImpl) then) =
+      __$$TrackFilter_YearImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({int? min, int? max});
+}
+
+/// @nodoc
+class __$$TrackFilter_YearImplCopyWithImpl<$Res>
+    extends _$TrackFilterCopyWithImpl<$Res, _$TrackFilter_YearImpl>
+    implements _$$TrackFilter_YearImplCopyWith<$Res> {
+  __$$TrackFilter_YearImplCopyWithImpl(
+      _$TrackFilter_YearImpl _value,
+      $Res Function(_$TrackFilter_YearImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? min = freezed,
+    Object? max = freezed,
+  }) {
+    return _then(_$TrackFilter_YearImpl(
+      min: freezed == min
+          ? _value.min
+          : min // ignore: cast_nullable_to_non_nullable
+              as int?,
+      max: freezed == max
+          ? _value.max
+          : max // ignore: cast_nullable_to_non_nullable
+              as int?,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$TrackFilter_YearImpl implements TrackFilter_Year {
+  const _$TrackFilter_YearImpl({this.min, this.max});
+
+  @override
+  final int? min;
+  @override
+  final int? max;
+
+  @override
+  String toString() {
+    return 'TrackFilter.year(min: $min, max: $max)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$TrackFilter_YearImpl &&
+            (identical(other.min, min) || other.min == min) &&
+            (identical(other.max, max) || other.max == max));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, min, max);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$TrackFilter_YearImplCopyWith<_$TrackFilter_YearImpl> get copyWith =>
+      __$$TrackFilter_YearImplCopyWithImpl<_$TrackFilter_YearImpl>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int field0) artist,
+    required TResult Function(int field0) album,
+    required TResult Function(int field0) genre,
+    required TResult Function(int? min, int? max) year,
+    required TResult Function(int? min, int? max) durationMs,
+    required TResult Function(String field0) codec,
+    required TResult Function(String field0) fileExtension,
+    required TResult Function(bool field0) lossless,
+    required TResult Function(int? min, int? max) bitrate,
+    required TResult Function(int field0) minSampleRate,
+    required TResult Function(int field0) minBitDepth,
+    required TResult Function(int field0) channels,
+    required TResult Function(int field0) libraryRoot,
+    required TResult Function(TextField field, String text) text,
+    required TResult Function(TrackFilter field0) not,
+  }) {
+    return year(min, max);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int field0)? artist,
+    TResult? Function(int field0)? album,
+    TResult? Function(int field0)? genre,
+    TResult? Function(int? min, int? max)? year,
+    TResult? Function(int? min, int? max)? durationMs,
+    TResult? Function(String field0)? codec,
+    TResult? Function(String field0)? fileExtension,
+    TResult? Function(bool field0)? lossless,
+    TResult? Function(int? min, int? max)? bitrate,
+    TResult? Function(int field0)? minSampleRate,
+    TResult? Function(int field0)? minBitDepth,
+    TResult? Function(int field0)? channels,
+    TResult? Function(int field0)? libraryRoot,
+    TResult? Function(TextField field, String text)? text,
+    TResult? Function(TrackFilter field0)? not,
+  }) {
+    return year?.call(min, max);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int field0)? artist,
+    TResult Function(int field0)? album,
+    TResult Function(int field0)? genre,
+    TResult Function(int? min, int? max)? year,
+    TResult Function(int? min, int? max)? durationMs,
+    TResult Function(String field0)? codec,
+    TResult Function(String field0)? fileExtension,
+    TResult Function(bool field0)? lossless,
+    TResult Function(int? min, int? max)? bitrate,
+    TResult Function(int field0)? minSampleRate,
+    TResult Function(int field0)? minBitDepth,
+    TResult Function(int field0)? channels,
+    TResult Function(int field0)? libraryRoot,
+    TResult Function(TextField field, String text)? text,
+    TResult Function(TrackFilter field0)? not,
+    required TResult orElse(),
+  }) {
+    if (year != null) {
+      return year(min, max);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(TrackFilter_Artist value) artist,
+    required TResult Function(TrackFilter_Album value) album,
+    required TResult Function(TrackFilter_Genre value) genre,
+    required TResult Function(TrackFilter_Year value) year,
+    required TResult Function(TrackFilter_DurationMs value) durationMs,
+    required TResult Function(TrackFilter_Codec value) codec,
+    required TResult Function(TrackFilter_FileExtension value) fileExtension,
+    required TResult Function(TrackFilter_Lossless value) lossless,
+    required TResult Function(TrackFilter_Bitrate value) bitrate,
+    required TResult Function(TrackFilter_MinSampleRate value) minSampleRate,
+    required TResult Function(TrackFilter_MinBitDepth value) minBitDepth,
+    required TResult Function(TrackFilter_Channels value) channels,
+    required TResult Function(TrackFilter_LibraryRoot value) libraryRoot,
+    required TResult Function(TrackFilter_Text value) text,
+    required TResult Function(TrackFilter_Not value) not,
+  }) {
+    return year(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(TrackFilter_Artist value)? artist,
+    TResult? Function(TrackFilter_Album value)? album,
+    TResult? Function(TrackFilter_Genre value)? genre,
+    TResult? Function(TrackFilter_Year value)? year,
+    TResult? Function(TrackFilter_DurationMs value)? durationMs,
+    TResult? Function(TrackFilter_Codec value)? codec,
+    TResult? Function(TrackFilter_FileExtension value)? fileExtension,
+    TResult? Function(TrackFilter_Lossless value)? lossless,
+    TResult? Function(TrackFilter_Bitrate value)? bitrate,
+    TResult? Function(TrackFilter_MinSampleRate value)? minSampleRate,
+    TResult? Function(TrackFilter_MinBitDepth value)? minBitDepth,
+    TResult? Function(TrackFilter_Channels value)? channels,
+    TResult? Function(TrackFilter_LibraryRoot value)? libraryRoot,
+    TResult? Function(TrackFilter_Text value)? text,
+    TResult? Function(TrackFilter_Not value)? not,
+  }) {
+    return year?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(TrackFilter_Artist value)? artist,
+    TResult Function(TrackFilter_Album value)? album,
+    TResult Function(TrackFilter_Genre value)? genre,
+    TResult Function(TrackFilter_Year value)? year,
+    TResult Function(TrackFilter_DurationMs value)? durationMs,
+    TResult Function(TrackFilter_Codec value)? codec,
+    TResult Function(TrackFilter_FileExtension value)? fileExtension,
+    TResult Function(TrackFilter_Lossless value)? lossless,
+    TResult Function(TrackFilter_Bitrate value)? bitrate,
+    TResult Function(TrackFilter_MinSampleRate value)? minSampleRate,
+    TResult Function(TrackFilter_MinBitDepth value)? minBitDepth,
+    TResult Function(TrackFilter_Channels value)? channels,
+    TResult Function(TrackFilter_LibraryRoot value)? libraryRoot,
+    TResult Function(TrackFilter_Text value)? text,
+    TResult Function(TrackFilter_Not value)? not,
+    required TResult orElse(),
+  }) {
+    if (year != null) {
+      return year(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class TrackFilter_Year implements TrackFilter {
+  const factory TrackFilter_Year({final int? min, final int? max}) =
+      _$TrackFilter_YearImpl;
+
+  int? get min;
+  int? get max;
+  @JsonKey(ignore: true)
+  _$$TrackFilter_YearImplCopyWith<_$TrackFilter_YearImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$TrackFilter_DurationMsImplCopyWith<$Res> {
+  factory _$$TrackFilter_DurationMsImplCopyWith(
+          _$TrackFilter_DurationMsImpl value,
+          $Res Function(_$TrackFilter_DurationMsImpl) then) =
+      __$$TrackFilter_DurationMsImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({int? min, int? max});
+}
+
+/// @nodoc
+class __$$TrackFilter_DurationMsImplCopyWithImpl<$Res>
+    extends _$TrackFilterCopyWithImpl<$Res, _$TrackFilter_DurationMsImpl>
+    implements _$$TrackFilter_DurationMsImplCopyWith<$Res> {
+  __$$TrackFilter_DurationMsImplCopyWithImpl(
+      _$TrackFilter_DurationMsImpl _value,
+      $Res Function(_$TrackFilter_DurationMsImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? min = freezed,
+    Object? max = freezed,
+  }) {
+    return _then(_$TrackFilter_DurationMsImpl(
+      min: freezed == min
+          ? _value.min
+          : min // ignore: cast_nullable_to_non_nullable
+              as int?,
+      max: freezed == max
+          ? _value.max
+          : max // ignore: cast_nullable_to_non_nullable
+              as int?,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$TrackFilter_DurationMsImpl implements TrackFilter_DurationMs {
+  const _$TrackFilter_DurationMsImpl({this.min, this.max});
+
+  @override
+  final int? min;
+  @override
+  final int? max;
+
+  @override
+  String toString() {
+    return 'TrackFilter.durationMs(min: $min, max: $max)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$TrackFilter_DurationMsImpl &&
+            (identical(other.min, min) || other.min == min) &&
+            (identical(other.max, max) || other.max == max));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, min, max);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$TrackFilter_DurationMsImplCopyWith<_$TrackFilter_DurationMsImpl>
+      get copyWith => __$$TrackFilter_DurationMsImplCopyWithImpl<
+          _$TrackFilter_DurationMsImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int field0) artist,
+    required TResult Function(int field0) album,
+    required TResult Function(int field0) genre,
+    required TResult Function(int? min, int? max) year,
+    required TResult Function(int? min, int? max) durationMs,
+    required TResult Function(String field0) codec,
+    required TResult Function(String field0) fileExtension,
+    required TResult Function(bool field0) lossless,
+    required TResult Function(int? min, int? max) bitrate,
+    required TResult Function(int field0) minSampleRate,
+    required TResult Function(int field0) minBitDepth,
+    required TResult Function(int field0) channels,
+    required TResult Function(int field0) libraryRoot,
+    required TResult Function(TextField field, String text) text,
+    required TResult Function(TrackFilter field0) not,
+  }) {
+    return durationMs(min, max);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int field0)? artist,
+    TResult? Function(int field0)? album,
+    TResult? Function(int field0)? genre,
+    TResult? Function(int? min, int? max)? year,
+    TResult? Function(int? min, int? max)? durationMs,
+    TResult? Function(String field0)? codec,
+    TResult? Function(String field0)? fileExtension,
+    TResult? Function(bool field0)? lossless,
+    TResult? Function(int? min, int? max)? bitrate,
+    TResult? Function(int field0)? minSampleRate,
+    TResult? Function(int field0)? minBitDepth,
+    TResult? Function(int field0)? channels,
+    TResult? Function(int field0)? libraryRoot,
+    TResult? Function(TextField field, String text)? text,
+    TResult? Function(TrackFilter field0)? not,
+  }) {
+    return durationMs?.call(min, max);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int field0)? artist,
+    TResult Function(int field0)? album,
+    TResult Function(int field0)? genre,
+    TResult Function(int? min, int? max)? year,
+    TResult Function(int? min, int? max)? durationMs,
+    TResult Function(String field0)? codec,
+    TResult Function(String field0)? fileExtension,
+    TResult Function(bool field0)? lossless,
+    TResult Function(int? min, int? max)? bitrate,
+    TResult Function(int field0)? minSampleRate,
+    TResult Function(int field0)? minBitDepth,
+    TResult Function(int field0)? channels,
+    TResult Function(int field0)? libraryRoot,
+    TResult Function(TextField field, String text)? text,
+    TResult Function(TrackFilter field0)? not,
+    required TResult orElse(),
+  }) {
+    if (durationMs != null) {
+      return durationMs(min, max);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(TrackFilter_Artist value) artist,
+    required TResult Function(TrackFilter_Album value) album,
+    required TResult Function(TrackFilter_Genre value) genre,
+    required TResult Function(TrackFilter_Year value) year,
+    required TResult Function(TrackFilter_DurationMs value) durationMs,
+    required TResult Function(TrackFilter_Codec value) codec,
+    required TResult Function(TrackFilter_FileExtension value) fileExtension,
+    required TResult Function(TrackFilter_Lossless value) lossless,
+    required TResult Function(TrackFilter_Bitrate value) bitrate,
+    required TResult Function(TrackFilter_MinSampleRate value) minSampleRate,
+    required TResult Function(TrackFilter_MinBitDepth value) minBitDepth,
+    required TResult Function(TrackFilter_Channels value) channels,
+    required TResult Function(TrackFilter_LibraryRoot value) libraryRoot,
+    required TResult Function(TrackFilter_Text value) text,
+    required TResult Function(TrackFilter_Not value) not,
+  }) {
+    return durationMs(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(TrackFilter_Artist value)? artist,
+    TResult? Function(TrackFilter_Album value)? album,
+    TResult? Function(TrackFilter_Genre value)? genre,
+    TResult? Function(TrackFilter_Year value)? year,
+    TResult? Function(TrackFilter_DurationMs value)? durationMs,
+    TResult? Function(TrackFilter_Codec value)? codec,
+    TResult? Function(TrackFilter_FileExtension value)? fileExtension,
+    TResult? Function(TrackFilter_Lossless value)? lossless,
+    TResult? Function(TrackFilter_Bitrate value)? bitrate,
+    TResult? Function(TrackFilter_MinSampleRate value)? minSampleRate,
+    TResult? Function(TrackFilter_MinBitDepth value)? minBitDepth,
+    TResult? Function(TrackFilter_Channels value)? channels,
+    TResult? Function(TrackFilter_LibraryRoot value)? libraryRoot,
+    TResult? Function(TrackFilter_Text value)? text,
+    TResult? Function(TrackFilter_Not value)? not,
+  }) {
+    return durationMs?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(TrackFilter_Artist value)? artist,
+    TResult Function(TrackFilter_Album value)? album,
+    TResult Function(TrackFilter_Genre value)? genre,
+    TResult Function(TrackFilter_Year value)? year,
+    TResult Function(TrackFilter_DurationMs value)? durationMs,
+    TResult Function(TrackFilter_Codec value)? codec,
+    TResult Function(TrackFilter_FileExtension value)? fileExtension,
+    TResult Function(TrackFilter_Lossless value)? lossless,
+    TResult Function(TrackFilter_Bitrate value)? bitrate,
+    TResult Function(TrackFilter_MinSampleRate value)? minSampleRate,
+    TResult Function(TrackFilter_MinBitDepth value)? minBitDepth,
+    TResult Function(TrackFilter_Channels value)? channels,
+    TResult Function(TrackFilter_LibraryRoot value)? libraryRoot,
+    TResult Function(TrackFilter_Text value)? text,
+    TResult Function(TrackFilter_Not value)? not,
+    required TResult orElse(),
+  }) {
+    if (durationMs != null) {
+      return durationMs(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class TrackFilter_DurationMs implements TrackFilter {
+  const factory TrackFilter_DurationMs({final int? min, final int? max}) =
+      _$TrackFilter_DurationMsImpl;
+
+  int? get min;
+  int? get max;
+  @JsonKey(ignore: true)
+  _$$TrackFilter_DurationMsImplCopyWith<_$TrackFilter_DurationMsImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$TrackFilter_CodecImplCopyWith<$Res> {
+  factory _$$TrackFilter_CodecImplCopyWith(
+          _$TrackFilter_CodecImpl value,
+          $Res Function(_$TrackFilter_CodecImpl) then) =
+      __$$TrackFilter_CodecImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String field0});
+}
+
+/// @nodoc
+class __$$TrackFilter_CodecImplCopyWithImpl<$Res>
+    extends _$TrackFilterCopyWithImpl<$Res, _$TrackFilter_CodecImpl>
+    implements _$$TrackFilter_CodecImplCopyWith<$Res> {
+  __$$TrackFilter_CodecImplCopyWithImpl(
+      _$TrackFilter_CodecImpl _value,
+      $Res Function(_$TrackFilter_CodecImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$TrackFilter_CodecImpl(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$TrackFilter_CodecImpl implements TrackFilter_Codec {
+  const _$TrackFilter_CodecImpl(this.field0);
+
+  @override
+  final String field0;
+
+  @override
+  String toString() {
+    return 'TrackFilter.codec(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$TrackFilter_CodecImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$TrackFilter_CodecImplCopyWith<_$TrackFilter_CodecImpl> get copyWith =>
+      __$$TrackFilter_CodecImplCopyWithImpl<_$TrackFilter_CodecImpl>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int field0) artist,
+    required TResult Function(int field0) album,
+    required TResult Function(int field0) genre,
+    required TResult Function(int? min, int? max) year,
+    required TResult Function(int? min, int? max) durationMs,
+    required TResult Function(String field0) codec,
+    required TResult Function(String field0) fileExtension,
+    required TResult Function(bool field0) lossless,
+    required TResult Function(int? min, int? max) bitrate,
+    required TResult Function(int field0) minSampleRate,
+    required TResult Function(int field0) minBitDepth,
+    required TResult Function(int field0) channels,
+    required TResult Function(int field0) libraryRoot,
+    required TResult Function(TextField field, String text) text,
+    required TResult Function(TrackFilter field0) not,
+  }) {
+    return codec(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int field0)? artist,
+    TResult? Function(int field0)? album,
+    TResult? Function(int field0)? genre,
+    TResult? Function(int? min, int? max)? year,
+    TResult? Function(int? min, int? max)? durationMs,
+    TResult? Function(String field0)? codec,
+    TResult? Function(String field0)? fileExtension,
+    TResult? Function(bool field0)? lossless,
+    TResult? Function(int? min, int? max)? bitrate,
+    TResult? Function(int field0)? minSampleRate,
+    TResult? Function(int field0)? minBitDepth,
+    TResult? Function(int field0)? channels,
+    TResult? Function(int field0)? libraryRoot,
+    TResult? Function(TextField field, String text)? text,
+    TResult? Function(TrackFilter field0)? not,
+  }) {
+    return codec?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int field0)? artist,
+    TResult Function(int field0)? album,
+    TResult Function(int field0)? genre,
+    TResult Function(int? min, int? max)? year,
+    TResult Function(int? min, int? max)? durationMs,
+    TResult Function(String field0)? codec,
+    TResult Function(String field0)? fileExtension,
+    TResult Function(bool field0)? lossless,
+    TResult Function(int? min, int? max)? bitrate,
+    TResult Function(int field0)? minSampleRate,
+    TResult Function(int field0)? minBitDepth,
+    TResult Function(int field0)? channels,
+    TResult Function(int field0)? libraryRoot,
+    TResult Function(TextField field, String text)? text,
+    TResult Function(TrackFilter field0)? not,
+    required TResult orElse(),
+  }) {
+    if (codec != null) {
+      return codec(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(TrackFilter_Artist value) artist,
+    required TResult Function(TrackFilter_Album value) album,
+    required TResult Function(TrackFilter_Genre value) genre,
+    required TResult Function(TrackFilter_Year value) year,
+    required TResult Function(TrackFilter_DurationMs value) durationMs,
+    required TResult Function(TrackFilter_Codec value) codec,
+    required TResult Function(TrackFilter_FileExtension value) fileExtension,
+    required TResult Function(TrackFilter_Lossless value) lossless,
+    required TResult Function(TrackFilter_Bitrate value) bitrate,
+    required TResult Function(TrackFilter_MinSampleRate value) minSampleRate,
+    required TResult Function(TrackFilter_MinBitDepth value) minBitDepth,
+    required TResult Function(TrackFilter_Channels value) channels,
+    required TResult Function(TrackFilter_LibraryRoot value) libraryRoot,
+    required TResult Function(TrackFilter_Text value) text,
+    required TResult Function(TrackFilter_Not value) not,
+  }) {
+    return codec(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(TrackFilter_Artist value)? artist,
+    TResult? Function(TrackFilter_Album value)? album,
+    TResult? Function(TrackFilter_Genre value)? genre,
+    TResult? Function(TrackFilter_Year value)? year,
+    TResult? Function(TrackFilter_DurationMs value)? durationMs,
+    TResult? Function(TrackFilter_Codec value)? codec,
+    TResult? Function(TrackFilter_FileExtension value)? fileExtension,
+    TResult? Function(TrackFilter_Lossless value)? lossless,
+    TResult? Function(TrackFilter_Bitrate value)? bitrate,
+    TResult? Function(TrackFilter_MinSampleRate value)? minSampleRate,
+    TResult? Function(TrackFilter_MinBitDepth value)? minBitDepth,
+    TResult? Function(TrackFilter_Channels value)? channels,
+    TResult? Function(TrackFilter_LibraryRoot value)? libraryRoot,
+    TResult? Function(TrackFilter_Text value)? text,
+    TResult? Function(TrackFilter_Not value)? not,
+  }) {
+    return codec?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(TrackFilter_Artist value)? artist,
+    TResult Function(TrackFilter_Album value)? album,
+    TResult Function(TrackFilter_Genre value)? genre,
+    TResult Function(TrackFilter_Year value)? year,
+    TResult Function(TrackFilter_DurationMs value)? durationMs,
+    TResult Function(TrackFilter_Codec value)? codec,
+    TResult Function(TrackFilter_FileExtension value)? fileExtension,
+    TResult Function(TrackFilter_Lossless value)? lossless,
+    TResult Function(TrackFilter_Bitrate value)? bitrate,
+    TResult Function(TrackFilter_MinSampleRate value)? minSampleRate,
+    TResult Function(TrackFilter_MinBitDepth value)? minBitDepth,
+    TResult Function(TrackFilter_Channels value)? channels,
+    TResult Function(TrackFilter_LibraryRoot value)? libraryRoot,
+    TResult Function(TrackFilter_Text value)? text,
+    TResult Function(TrackFilter_Not value)? not,
+    required TResult orElse(),
+  }) {
+    if (codec != null) {
+      return codec(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class TrackFilter_Codec implements TrackFilter {
+  const factory TrackFilter_Codec(final String field0) =
+      _$TrackFilter_CodecImpl;
+
+  String get field0;
+  @JsonKey(ignore: true)
+  _$$TrackFilter_CodecImplCopyWith<_$TrackFilter_CodecImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$TrackFilter_FileExtensionImplCopyWith<$Res> {
+  factory _$$TrackFilter_FileExtensionImplCopyWith(
+          _$TrackFilter_FileExtensionImpl value,
+          $Res Function(_$TrackFilter_FileExtensionImpl) then) =
+      __$$TrackFilter_FileExtensionImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String field0});
+}
+
+/// @nodoc
+class __$$TrackFilter_FileExtensionImplCopyWithImpl<$Res>
+    extends _$TrackFilterCopyWithImpl<$Res, _$TrackFilter_FileExtensionImpl>
+    implements _$$TrackFilter_FileExtensionImplCopyWith<$Res> {
+  __$$TrackFilter_FileExtensionImplCopyWithImpl(
+      _$TrackFilter_FileExtensionImpl _value,
+      $Res Function(_$TrackFilter_FileExtensionImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$TrackFilter_FileExtensionImpl(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$TrackFilter_FileExtensionImpl implements TrackFilter_FileExtension {
+  const _$TrackFilter_FileExtensionImpl(this.field0);
+
+  @override
+  final String field0;
+
+  @override
+  String toString() {
+    return 'TrackFilter.fileExtension(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$TrackFilter_FileExtensionImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$TrackFilter_FileExtensionImplCopyWith<_$TrackFilter_FileExtensionImpl>
+      get copyWith => __$$TrackFilter_FileExtensionImplCopyWithImpl<
+          _$TrackFilter_FileExtensionImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int field0) artist,
+    required TResult Function(int field0) album,
+    required TResult Function(int field0) genre,
+    required TResult Function(int? min, int? max) year,
+    required TResult Function(int? min, int? max) durationMs,
+    required TResult Function(String field0) codec,
+    required TResult Function(String field0) fileExtension,
+    required TResult Function(bool field0) lossless,
+    required TResult Function(int? min, int? max) bitrate,
+    required TResult Function(int field0) minSampleRate,
+    required TResult Function(int field0) minBitDepth,
+    required TResult Function(int field0) channels,
+    required TResult Function(int field0) libraryRoot,
+    required TResult Function(TextField field, String text) text,
+    required TResult Function(TrackFilter field0) not,
+  }) {
+    return fileExtension(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int field0)? artist,
+    TResult? Function(int field0)? album,
+    TResult? Function(int field0)? genre,
+    TResult? Function(int? min, int? max)? year,
+    TResult? Function(int? min, int? max)? durationMs,
+    TResult? Function(String field0)? codec,
+    TResult? Function(String field0)? fileExtension,
+    TResult? Function(bool field0)? lossless,
+    TResult? Function(int? min, int? max)? bitrate,
+    TResult? Function(int field0)? minSampleRate,
+    TResult? Function(int field0)? minBitDepth,
+    TResult? Function(int field0)? channels,
+    TResult? Function(int field0)? libraryRoot,
+    TResult? Function(TextField field, String text)? text,
+    TResult? Function(TrackFilter field0)? not,
+  }) {
+    return fileExtension?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int field0)? artist,
+    TResult Function(int field0)? album,
+    TResult Function(int field0)? genre,
+    TResult Function(int? min, int? max)? year,
+    TResult Function(int? min, int? max)? durationMs,
+    TResult Function(String field0)? codec,
+    TResult Function(String field0)? fileExtension,
+    TResult Function(bool field0)? lossless,
+    TResult Function(int? min, int? max)? bitrate,
+    TResult Function(int field0)? minSampleRate,
+    TResult Function(int field0)? minBitDepth,
+    TResult Function(int field0)? channels,
+    TResult Function(int field0)? libraryRoot,
+    TResult Function(TextField field, String text)? text,
+    TResult Function(TrackFilter field0)? not,
+    required TResult orElse(),
+  }) {
+    if (fileExtension != null) {
+      return fileExtension(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(TrackFilter_Artist value) artist,
+    required TResult Function(TrackFilter_Album value) album,
+    required TResult Function(TrackFilter_Genre value) genre,
+    required TResult Function(TrackFilter_Year value) year,
+    required TResult Function(TrackFilter_DurationMs value) durationMs,
+    required TResult Function(TrackFilter_Codec value) codec,
+    required TResult Function(TrackFilter_FileExtension value) fileExtension,
+    required TResult Function(TrackFilter_Lossless value) lossless,
+    required TResult Function(TrackFilter_Bitrate value) bitrate,
+    required TResult Function(TrackFilter_MinSampleRate value) minSampleRate,
+    required TResult Function(TrackFilter_MinBitDepth value) minBitDepth,
+    required TResult Function(TrackFilter_Channels value) channels,
+    required TResult Function(TrackFilter_LibraryRoot value) libraryRoot,
+    required TResult Function(TrackFilter_Text value) text,
+    required TResult Function(TrackFilter_Not value) not,
+  }) {
+    return fileExtension(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(TrackFilter_Artist value)? artist,
+    TResult? Function(TrackFilter_Album value)? album,
+    TResult? Function(TrackFilter_Genre value)? genre,
+    TResult? Function(TrackFilter_Year value)? year,
+    TResult? Function(TrackFilter_DurationMs value)? durationMs,
+    TResult? Function(TrackFilter_Codec value)? codec,
+    TResult? Function(TrackFilter_FileExtension value)? fileExtension,
+    TResult? Function(TrackFilter_Lossless value)? lossless,
+    TResult? Function(TrackFilter_Bitrate value)? bitrate,
+    TResult? Function(TrackFilter_MinSampleRate value)? minSampleRate,
+    TResult? Function(TrackFilter_MinBitDepth value)? minBitDepth,
+    TResult? Function(TrackFilter_Channels value)? channels,
+    TResult? Function(TrackFilter_LibraryRoot value)? libraryRoot,
+    TResult? Function(TrackFilter_Text value)? text,
+    TResult? Function(TrackFilter_Not value)? not,
+  }) {
+    return fileExtension?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(TrackFilter_Artist value)? artist,
+    TResult Function(TrackFilter_Album value)? album,
+    TResult Function(TrackFilter_Genre value)? genre,
+    TResult Function(TrackFilter_Year value)? year,
+    TResult Function(TrackFilter_DurationMs value)? durationMs,
+    TResult Function(TrackFilter_Codec value)? codec,
+    TResult Function(TrackFilter_FileExtension value)? fileExtension,
+    TResult Function(TrackFilter_Lossless value)? lossless,
+    TResult Function(TrackFilter_Bitrate value)? bitrate,
+    TResult Function(TrackFilter_MinSampleRate value)? minSampleRate,
+    TResult Function(TrackFilter_MinBitDepth value)? minBitDepth,
+    TResult Function(TrackFilter_Channels value)? channels,
+    TResult Function(TrackFilter_LibraryRoot value)? libraryRoot,
+    TResult Function(TrackFilter_Text value)? text,
+    TResult Function(TrackFilter_Not value)? not,
+    required TResult orElse(),
+  }) {
+    if (fileExtension != null) {
+      return fileExtension(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class TrackFilter_FileExtension implements TrackFilter {
+  const factory TrackFilter_FileExtension(final String field0) =
+      _$TrackFilter_FileExtensionImpl;
+
+  String get field0;
+  @JsonKey(ignore: true)
+  _$$TrackFilter_FileExtensionImplCopyWith<_$TrackFilter_FileExtensionImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$TrackFilter_LosslessImplCopyWith<$Res> {
+  factory _$$TrackFilter_LosslessImplCopyWith(
+          _$TrackFilter_LosslessImpl value,
+          $Res Function(_$TrackFilter_LosslessImpl) then) =
+      __$$TrackFilter_LosslessImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({bool field0});
+}
+
+/// @nodoc
+class __$$TrackFilter_LosslessImplCopyWithImpl<$Res>
+    extends _$TrackFilterCopyWithImpl<$Res, _$TrackFilter_LosslessImpl>
+    implements _$$TrackFilter_LosslessImplCopyWith<$Res> {
+  __$$TrackFilter_LosslessImplCopyWithImpl(
+      _$TrackFilter_LosslessImpl _value,
+      $Res Function(_$TrackFilter_LosslessImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$TrackFilter_LosslessImpl(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as bool,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$TrackFilter_LosslessImpl implements TrackFilter_Lossless {
+  const _$TrackFilter_LosslessImpl(this.field0);
+
+  @override
+  final bool field0;
+
+  @override
+  String toString() {
+    return 'TrackFilter.lossless(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$TrackFilter_LosslessImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$TrackFilter_LosslessImplCopyWith<_$TrackFilter_LosslessImpl>
+      get copyWith => __$$TrackFilter_LosslessImplCopyWithImpl<
+          _$TrackFilter_LosslessImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int field0) artist,
+    required TResult Function(int field0) album,
+    required TResult Function(int field0) genre,
+    required TResult Function(int? min, int? max) year,
+    required TResult Function(int? min, int? max) durationMs,
+    required TResult Function(String field0) codec,
+    required TResult Function(String field0) fileExtension,
+    required TResult Function(bool field0) lossless,
+    required TResult Function(int? min, int? max) bitrate,
+    required TResult Function(int field0) minSampleRate,
+    required TResult Function(int field0) minBitDepth,
+    required TResult Function(int field0) channels,
+    required TResult Function(int field0) libraryRoot,
+    required TResult Function(TextField field, String text) text,
+    required TResult Function(TrackFilter field0) not,
+  }) {
+    return lossless(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int field0)? artist,
+    TResult? Function(int field0)? album,
+    TResult? Function(int field0)? genre,
+    TResult? Function(int? min, int? max)? year,
+    TResult? Function(int? min, int? max)? durationMs,
+    TResult? Function(String field0)? codec,
+    TResult? Function(String field0)? fileExtension,
+    TResult? Function(bool field0)? lossless,
+    TResult? Function(int? min, int? max)? bitrate,
+    TResult? Function(int field0)? minSampleRate,
+    TResult? Function(int field0)? minBitDepth,
+    TResult? Function(int field0)? channels,
+    TResult? Function(int field0)? libraryRoot,
+    TResult? Function(TextField field, String text)? text,
+    TResult? Function(TrackFilter field0)? not,
+  }) {
+    return lossless?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int field0)? artist,
+    TResult Function(int field0)? album,
+    TResult Function(int field0)? genre,
+    TResult Function(int? min, int? max)? year,
+    TResult Function(int? min, int? max)? durationMs,
+    TResult Function(String field0)? codec,
+    TResult Function(String field0)? fileExtension,
+    TResult Function(bool field0)? lossless,
+    TResult Function(int? min, int? max)? bitrate,
+    TResult Function(int field0)? minSampleRate,
+    TResult Function(int field0)? minBitDepth,
+    TResult Function(int field0)? channels,
+    TResult Function(int field0)? libraryRoot,
+    TResult Function(TextField field, String text)? text,
+    TResult Function(TrackFilter field0)? not,
+    required TResult orElse(),
+  }) {
+    if (lossless != null) {
+      return lossless(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(TrackFilter_Artist value) artist,
+    required TResult Function(TrackFilter_Album value) album,
+    required TResult Function(TrackFilter_Genre value) genre,
+    required TResult Function(TrackFilter_Year value) year,
+    required TResult Function(TrackFilter_DurationMs value) durationMs,
+    required TResult Function(TrackFilter_Codec value) codec,
+    required TResult Function(TrackFilter_FileExtension value) fileExtension,
+    required TResult Function(TrackFilter_Lossless value) lossless,
+    required TResult Function(TrackFilter_Bitrate value) bitrate,
+    required TResult Function(TrackFilter_MinSampleRate value) minSampleRate,
+    required TResult Function(TrackFilter_MinBitDepth value) minBitDepth,
+    required TResult Function(TrackFilter_Channels value) channels,
+    required TResult Function(TrackFilter_LibraryRoot value) libraryRoot,
+    required TResult Function(TrackFilter_Text value) text,
+    required TResult Function(TrackFilter_Not value) not,
+  }) {
+    return lossless(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(TrackFilter_Artist value)? artist,
+    TResult? Function(TrackFilter_Album value)? album,
+    TResult? Function(TrackFilter_Genre value)? genre,
+    TResult? Function(TrackFilter_Year value)? year,
+    TResult? Function(TrackFilter_DurationMs value)? durationMs,
+    TResult? Function(TrackFilter_Codec value)? codec,
+    TResult? Function(TrackFilter_FileExtension value)? fileExtension,
+    TResult? Function(TrackFilter_Lossless value)? lossless,
+    TResult? Function(TrackFilter_Bitrate value)? bitrate,
+    TResult? Function(TrackFilter_MinSampleRate value)? minSampleRate,
+    TResult? Function(TrackFilter_MinBitDepth value)? minBitDepth,
+    TResult? Function(TrackFilter_Channels value)? channels,
+    TResult? Function(TrackFilter_LibraryRoot value)? libraryRoot,
+    TResult? Function(TrackFilter_Text value)? text,
+    TResult? Function(TrackFilter_Not value)? not,
+  }) {
+    return lossless?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(TrackFilter_Artist value)? artist,
+    TResult Function(TrackFilter_Album value)? album,
+    TResult Function(TrackFilter_Genre value)? genre,
+    TResult Function(TrackFilter_Year value)? year,
+    TResult Function(TrackFilter_DurationMs value)? durationMs,
+    TResult Function(TrackFilter_Codec value)? codec,
+    TResult Function(TrackFilter_FileExtension value)? fileExtension,
+    TResult Function(TrackFilter_Lossless value)? lossless,
+    TResult Function(TrackFilter_Bitrate value)? bitrate,
+    TResult Function(TrackFilter_MinSampleRate value)? minSampleRate,
+    TResult Function(TrackFilter_MinBitDepth value)? minBitDepth,
+    TResult Function(TrackFilter_Channels value)? channels,
+    TResult Function(TrackFilter_LibraryRoot value)? libraryRoot,
+    TResult Function(TrackFilter_Text value)? text,
+    TResult Function(TrackFilter_Not value)? not,
+    required TResult orElse(),
+  }) {
+    if (lossless != null) {
+      return lossless(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class TrackFilter_Lossless implements TrackFilter {
+  const factory TrackFilter_Lossless(final bool field0) =
+      _$TrackFilter_LosslessImpl;
+
+  bool get field0;
+  @JsonKey(ignore: true)
+  _$$TrackFilter_LosslessImplCopyWith<_$TrackFilter_LosslessImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$TrackFilter_BitrateImplCopyWith<$Res> {
+  factory _$$TrackFilter_BitrateImplCopyWith(
+          _$TrackFilter_BitrateImpl value,
+          $Res Function(_$TrackFilter_BitrateImpl) then) =
+      __$$TrackFilter_BitrateImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({int? min, int? max});
+}
+
+/// @nodoc
+class __$$TrackFilter_BitrateImplCopyWithImpl<$Res>
+    extends _$TrackFilterCopyWithImpl<$Res, _$TrackFilter_BitrateImpl>
+    implements _$$TrackFilter_BitrateImplCopyWith<$Res> {
+  __$$TrackFilter_BitrateImplCopyWithImpl(
+      _$TrackFilter_BitrateImpl _value,
+      $Res Function(_$TrackFilter_BitrateImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? min = freezed,
+    Object? max = freezed,
+  }) {
+    return _then(_$TrackFilter_BitrateImpl(
+      min: freezed == min
+          ? _value.min
+          : min // ignore: cast_nullable_to_non_nullable
+              as int?,
+      max: freezed == max
+          ? _value.max
+          : max // ignore: cast_nullable_to_non_nullable
+              as int?,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$TrackFilter_BitrateImpl implements TrackFilter_Bitrate {
+  const _$TrackFilter_BitrateImpl({this.min, this.max});
+
+  @override
+  final int? min;
+  @override
+  final int? max;
+
+  @override
+  String toString() {
+    return 'TrackFilter.bitrate(min: $min, max: $max)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$TrackFilter_BitrateImpl &&
+            (identical(other.min, min) || other.min == min) &&
+            (identical(other.max, max) || other.max == max));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, min, max);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$TrackFilter_BitrateImplCopyWith<_$TrackFilter_BitrateImpl> get copyWith =>
+      __$$TrackFilter_BitrateImplCopyWithImpl<_$TrackFilter_BitrateImpl>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int field0) artist,
+    required TResult Function(int field0) album,
+    required TResult Function(int field0) genre,
+    required TResult Function(int? min, int? max) year,
+    required TResult Function(int? min, int? max) durationMs,
+    required TResult Function(String field0) codec,
+    required TResult Function(String field0) fileExtension,
+    required TResult Function(bool field0) lossless,
+    required TResult Function(int? min, int? max) bitrate,
+    required TResult Function(int field0) minSampleRate,
+    required TResult Function(int field0) minBitDepth,
+    required TResult Function(int field0) channels,
+    required TResult Function(int field0) libraryRoot,
+    required TResult Function(TextField field, String text) text,
+    required TResult Function(TrackFilter field0) not,
+  }) {
+    return bitrate(min, max);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int field0)? artist,
+    TResult? Function(int field0)? album,
+    TResult? Function(int field0)? genre,
+    TResult? Function(int? min, int? max)? year,
+    TResult? Function(int? min, int? max)? durationMs,
+    TResult? Function(String field0)? codec,
+    TResult? Function(String field0)? fileExtension,
+    TResult? Function(bool field0)? lossless,
+    TResult? Function(int? min, int? max)? bitrate,
+    TResult? Function(int field0)? minSampleRate,
+    TResult? Function(int field0)? minBitDepth,
+    TResult? Function(int field0)? channels,
+    TResult? Function(int field0)? libraryRoot,
+    TResult? Function(TextField field, String text)? text,
+    TResult? Function(TrackFilter field0)? not,
+  }) {
+    return bitrate?.call(min, max);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int field0)? artist,
+    TResult Function(int field0)? album,
+    TResult Function(int field0)? genre,
+    TResult Function(int? min, int? max)? year,
+    TResult Function(int? min, int? max)? durationMs,
+    TResult Function(String field0)? codec,
+    TResult Function(String field0)? fileExtension,
+    TResult Function(bool field0)? lossless,
+    TResult Function(int? min, int? max)? bitrate,
+    TResult Function(int field0)? minSampleRate,
+    TResult Function(int field0)? minBitDepth,
+    TResult Function(int field0)? channels,
+    TResult Function(int field0)? libraryRoot,
+    TResult Function(TextField field, String text)? text,
+    TResult Function(TrackFilter field0)? not,
+    required TResult orElse(),
+  }) {
+    if (bitrate != null) {
+      return bitrate(min, max);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(TrackFilter_Artist value) artist,
+    required TResult Function(TrackFilter_Album value) album,
+    required TResult Function(TrackFilter_Genre value) genre,
+    required TResult Function(TrackFilter_Year value) year,
+    required TResult Function(TrackFilter_DurationMs value) durationMs,
+    required TResult Function(TrackFilter_Codec value) codec,
+    required TResult Function(TrackFilter_FileExtension value) fileExtension,
+    required TResult Function(TrackFilter_Lossless value) lossless,
+    required TResult Function(TrackFilter_Bitrate value) bitrate,
+    required TResult Function(TrackFilter_MinSampleRate value) minSampleRate,
+    required TResult Function(TrackFilter_MinBitDepth value) minBitDepth,
+    required TResult Function(TrackFilter_Channels value) channels,
+    required TResult Function(TrackFilter_LibraryRoot value) libraryRoot,
+    required TResult Function(TrackFilter_Text value) text,
+    required TResult Function(TrackFilter_Not value) not,
+  }) {
+    return bitrate(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(TrackFilter_Artist value)? artist,
+    TResult? Function(TrackFilter_Album value)? album,
+    TResult? Function(TrackFilter_Genre value)? genre,
+    TResult? Function(TrackFilter_Year value)? year,
+    TResult? Function(TrackFilter_DurationMs value)? durationMs,
+    TResult? Function(TrackFilter_Codec value)? codec,
+    TResult? Function(TrackFilter_FileExtension value)? fileExtension,
+    TResult? Function(TrackFilter_Lossless value)? lossless,
+    TResult? Function(TrackFilter_Bitrate value)? bitrate,
+    TResult? Function(TrackFilter_MinSampleRate value)? minSampleRate,
+    TResult? Function(TrackFilter_MinBitDepth value)? minBitDepth,
+    TResult? Function(TrackFilter_Channels value)? channels,
+    TResult? Function(TrackFilter_LibraryRoot value)? libraryRoot,
+    TResult? Function(TrackFilter_Text value)? text,
+    TResult? Function(TrackFilter_Not value)? not,
+  }) {
+    return bitrate?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(TrackFilter_Artist value)? artist,
+    TResult Function(TrackFilter_Album value)? album,
+    TResult Function(TrackFilter_Genre value)? genre,
+    TResult Function(TrackFilter_Year value)? year,
+    TResult Function(TrackFilter_DurationMs value)? durationMs,
+    TResult Function(TrackFilter_Codec value)? codec,
+    TResult Function(TrackFilter_FileExtension value)? fileExtension,
+    TResult Function(TrackFilter_Lossless value)? lossless,
+    TResult Function(TrackFilter_Bitrate value)? bitrate,
+    TResult Function(TrackFilter_MinSampleRate value)? minSampleRate,
+    TResult Function(TrackFilter_MinBitDepth value)? minBitDepth,
+    TResult Function(TrackFilter_Channels value)? channels,
+    TResult Function(TrackFilter_LibraryRoot value)? libraryRoot,
+    TResult Function(TrackFilter_Text value)? text,
+    TResult Function(TrackFilter_Not value)? not,
+    required TResult orElse(),
+  }) {
+    if (bitrate != null) {
+      return bitrate(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class TrackFilter_Bitrate implements TrackFilter {
+  const factory TrackFilter_Bitrate({final int? min, final int? max}) =
+      _$TrackFilter_BitrateImpl;
+
+  int? get min;
+  int? get max;
+  @JsonKey(ignore: true)
+  _$$TrackFilter_BitrateImplCopyWith<_$TrackFilter_BitrateImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$TrackFilter_MinSampleRateImplCopyWith<$Res> {
+  factory _$$TrackFilter_MinSampleRateImplCopyWith(
+          _$TrackFilter_MinSampleRateImpl value,
+          $Res Function(_$TrackFilter_MinSampleRateImpl) then) =
+      __$$TrackFilter_MinSampleRateImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({int field0});
+}
+
+/// @nodoc
+class __$$TrackFilter_MinSampleRateImplCopyWithImpl<$Res>
+    extends _$TrackFilterCopyWithImpl<$Res, _$TrackFilter_MinSampleRateImpl>
+    implements _$$TrackFilter_MinSampleRateImplCopyWith<$Res> {
+  __$$TrackFilter_MinSampleRateImplCopyWithImpl(
+      _$TrackFilter_MinSampleRateImpl _value,
+      $Res Function(_$TrackFilter_MinSampleRateImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$TrackFilter_MinSampleRateImpl(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$TrackFilter_MinSampleRateImpl implements TrackFilter_MinSampleRate {
+  const _$TrackFilter_MinSampleRateImpl(this.field0);
+
+  @override
+  final int field0;
+
+  @override
+  String toString() {
+    return 'TrackFilter.minSampleRate(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$TrackFilter_MinSampleRateImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$TrackFilter_MinSampleRateImplCopyWith<_$TrackFilter_MinSampleRateImpl>
+      get copyWith => __$$TrackFilter_MinSampleRateImplCopyWithImpl<
+          _$TrackFilter_MinSampleRateImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int field0) artist,
+    required TResult Function(int field0) album,
+    required TResult Function(int field0) genre,
+    required TResult Function(int? min, int? max) year,
+    required TResult Function(int? min, int? max) durationMs,
+    required TResult Function(String field0) codec,
+    required TResult Function(String field0) fileExtension,
+    required TResult Function(bool field0) lossless,
+    required TResult Function(int? min, int? max) bitrate,
+    required TResult Function(int field0) minSampleRate,
+    required TResult Function(int field0) minBitDepth,
+    required TResult Function(int field0) channels,
+    required TResult Function(int field0) libraryRoot,
+    required TResult Function(TextField field, String text) text,
+    required TResult Function(TrackFilter field0) not,
+  }) {
+    return minSampleRate(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int field0)? artist,
+    TResult? Function(int field0)? album,
+    TResult? Function(int field0)? genre,
+    TResult? Function(int? min, int? max)? year,
+    TResult? Function(int? min, int? max)? durationMs,
+    TResult? Function(String field0)? codec,
+    TResult? Function(String field0)? fileExtension,
+    TResult? Function(bool field0)? lossless,
+    TResult? Function(int? min, int? max)? bitrate,
+    TResult? Function(int field0)? minSampleRate,
+    TResult? Function(int field0)? minBitDepth,
+    TResult? Function(int field0)? channels,
+    TResult? Function(int field0)? libraryRoot,
+    TResult? Function(TextField field, String text)? text,
+    TResult? Function(TrackFilter field0)? not,
+  }) {
+    return minSampleRate?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int field0)? artist,
+    TResult Function(int field0)? album,
+    TResult Function(int field0)? genre,
+    TResult Function(int? min, int? max)? year,
+    TResult Function(int? min, int? max)? durationMs,
+    TResult Function(String field0)? codec,
+    TResult Function(String field0)? fileExtension,
+    TResult Function(bool field0)? lossless,
+    TResult Function(int? min, int? max)? bitrate,
+    TResult Function(int field0)? minSampleRate,
+    TResult Function(int field0)? minBitDepth,
+    TResult Function(int field0)? channels,
+    TResult Function(int field0)? libraryRoot,
+    TResult Function(TextField field, String text)? text,
+    TResult Function(TrackFilter field0)? not,
+    required TResult orElse(),
+  }) {
+    if (minSampleRate != null) {
+      return minSampleRate(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(TrackFilter_Artist value) artist,
+    required TResult Function(TrackFilter_Album value) album,
+    required TResult Function(TrackFilter_Genre value) genre,
+    required TResult Function(TrackFilter_Year value) year,
+    required TResult Function(TrackFilter_DurationMs value) durationMs,
+    required TResult Function(TrackFilter_Codec value) codec,
+    required TResult Function(TrackFilter_FileExtension value) fileExtension,
+    required TResult Function(TrackFilter_Lossless value) lossless,
+    required TResult Function(TrackFilter_Bitrate value) bitrate,
+    required TResult Function(TrackFilter_MinSampleRate value) minSampleRate,
+    required TResult Function(TrackFilter_MinBitDepth value) minBitDepth,
+    required TResult Function(TrackFilter_Channels value) channels,
+    required TResult Function(TrackFilter_LibraryRoot value) libraryRoot,
+    required TResult Function(TrackFilter_Text value) text,
+    required TResult Function(TrackFilter_Not value) not,
+  }) {
+    return minSampleRate(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(TrackFilter_Artist value)? artist,
+    TResult? Function(TrackFilter_Album value)? album,
+    TResult? Function(TrackFilter_Genre value)? genre,
+    TResult? Function(TrackFilter_Year value)? year,
+    TResult? Function(TrackFilter_DurationMs value)? durationMs,
+    TResult? Function(TrackFilter_Codec value)? codec,
+    TResult? Function(TrackFilter_FileExtension value)? fileExtension,
+    TResult? Function(TrackFilter_Lossless value)? lossless,
+    TResult? Function(TrackFilter_Bitrate value)? bitrate,
+    TResult? Function(TrackFilter_MinSampleRate value)? minSampleRate,
+    TResult? Function(TrackFilter_MinBitDepth value)? minBitDepth,
+    TResult? Function(TrackFilter_Channels value)? channels,
+    TResult? Function(TrackFilter_LibraryRoot value)? libraryRoot,
+    TResult? Function(TrackFilter_Text value)? text,
+    TResult? Function(TrackFilter_Not value)? not,
+  }) {
+    return minSampleRate?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(TrackFilter_Artist value)? artist,
+    TResult Function(TrackFilter_Album value)? album,
+    TResult Function(TrackFilter_Genre value)? genre,
+    TResult Function(TrackFilter_Year value)? year,
+    TResult Function(TrackFilter_DurationMs value)? durationMs,
+    TResult Function(TrackFilter_Codec value)? codec,
+    TResult Function(TrackFilter_FileExtension value)? fileExtension,
+    TResult Function(TrackFilter_Lossless value)? lossless,
+    TResult Function(TrackFilter_Bitrate value)? bitrate,
+    TResult Function(TrackFilter_MinSampleRate value)? minSampleRate,
+    TResult Function(TrackFilter_MinBitDepth value)? minBitDepth,
+    TResult Function(TrackFilter_Channels value)? channels,
+    TResult Function(TrackFilter_LibraryRoot value)? libraryRoot,
+    TResult Function(TrackFilter_Text value)? text,
+    TResult Function(TrackFilter_Not value)? not,
+    required TResult orElse(),
+  }) {
+    if (minSampleRate != null) {
+      return minSampleRate(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class TrackFilter_MinSampleRate implements TrackFilter {
+  const factory TrackFilter_MinSampleRate(final int field0) =
+      _$TrackFilter_MinSampleRateImpl;
+
+  int get field0;
+  @JsonKey(ignore: true)
+  _$$TrackFilter_MinSampleRateImplCopyWith<_$TrackFilter_MinSampleRateImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$TrackFilter_MinBitDepthImplCopyWith<$Res> {
+  factory _$$TrackFilter_MinBitDepthImplCopyWith(
+          _$TrackFilter_MinBitDepthImpl value,
+          $Res Function(_$TrackFilter_MinBitDepthImpl) then) =
+      __$$TrackFilter_MinBitDepthImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({int field0});
+}
+
+/// @nodoc
+class __$$TrackFilter_MinBitDepthImplCopyWithImpl<$Res>
+    extends _$TrackFilterCopyWithImpl<$Res, _$TrackFilter_MinBitDepthImpl>
+    implements _$$TrackFilter_MinBitDepthImplCopyWith<$Res> {
+  __$$TrackFilter_MinBitDepthImplCopyWithImpl(
+      _$TrackFilter_MinBitDepthImpl _value,
+      $Res Function(_$TrackFilter_MinBitDepthImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$TrackFilter_MinBitDepthImpl(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$TrackFilter_MinBitDepthImpl implements TrackFilter_MinBitDepth {
+  const _$TrackFilter_MinBitDepthImpl(this.field0);
+
+  @override
+  final int field0;
+
+  @override
+  String toString() {
+    return 'TrackFilter.minBitDepth(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$TrackFilter_MinBitDepthImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$TrackFilter_MinBitDepthImplCopyWith<_$TrackFilter_MinBitDepthImpl>
+      get copyWith => __$$TrackFilter_MinBitDepthImplCopyWithImpl<
+          _$TrackFilter_MinBitDepthImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int field0) artist,
+    required TResult Function(int field0) album,
+    required TResult Function(int field0) genre,
+    required TResult Function(int? min, int? max) year,
+    required TResult Function(int? min, int? max) durationMs,
+    required TResult Function(String field0) codec,
+    required TResult Function(String field0) fileExtension,
+    required TResult Function(bool field0) lossless,
+    required TResult Function(int? min, int? max) bitrate,
+    required TResult Function(int field0) minSampleRate,
+    required TResult Function(int field0) minBitDepth,
+    required TResult Function(int field0) channels,
+    required TResult Function(int field0) libraryRoot,
+    required TResult Function(TextField field, String text) text,
+    required TResult Function(TrackFilter field0) not,
+  }) {
+    return minBitDepth(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int field0)? artist,
+    TResult? Function(int field0)? album,
+    TResult? Function(int field0)? genre,
+    TResult? Function(int? min, int? max)? year,
+    TResult? Function(int? min, int? max)? durationMs,
+    TResult? Function(String field0)? codec,
+    TResult? Function(String field0)? fileExtension,
+    TResult? Function(bool field0)? lossless,
+    TResult? Function(int? min, int? max)? bitrate,
+    TResult? Function(int field0)? minSampleRate,
+    TResult? Function(int field0)? minBitDepth,
+    TResult? Function(int field0)? channels,
+    TResult? Function(int field0)? libraryRoot,
+    TResult? Function(TextField field, String text)? text,
+    TResult? Function(TrackFilter field0)? not,
+  }) {
+    return minBitDepth?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int field0)? artist,
+    TResult Function(int field0)? album,
+    TResult Function(int field0)? genre,
+    TResult Function(int? min, int? max)? year,
+    TResult Function(int? min, int? max)? durationMs,
+    TResult Function(String field0)? codec,
+    TResult Function(String field0)? fileExtension,
+    TResult Function(bool field0)? lossless,
+    TResult Function(int? min, int? max)? bitrate,
+    TResult Function(int field0)? minSampleRate,
+    TResult Function(int field0)? minBitDepth,
+    TResult Function(int field0)? channels,
+    TResult Function(int field0)? libraryRoot,
+    TResult Function(TextField field, String text)? text,
+    TResult Function(TrackFilter field0)? not,
+    required TResult orElse(),
+  }) {
+    if (minBitDepth != null) {
+      return minBitDepth(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(TrackFilter_Artist value) artist,
+    required TResult Function(TrackFilter_Album value) album,
+    required TResult Function(TrackFilter_Genre value) genre,
+    required TResult Function(TrackFilter_Year value) year,
+    required TResult Function(TrackFilter_DurationMs value) durationMs,
+    required TResult Function(TrackFilter_Codec value) codec,
+    required TResult Function(TrackFilter_FileExtension value) fileExtension,
+    required TResult Function(TrackFilter_Lossless value) lossless,
+    required TResult Function(TrackFilter_Bitrate value) bitrate,
+    required TResult Function(TrackFilter_MinSampleRate value) minSampleRate,
+    required TResult Function(TrackFilter_MinBitDepth value) minBitDepth,
+    required TResult Function(TrackFilter_Channels value) channels,
+    required TResult Function(TrackFilter_LibraryRoot value) libraryRoot,
+    required TResult Function(TrackFilter_Text value) text,
+    required TResult Function(TrackFilter_Not value) not,
+  }) {
+    return minBitDepth(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(TrackFilter_Artist value)? artist,
+    TResult? Function(TrackFilter_Album value)? album,
+    TResult? Function(TrackFilter_Genre value)? genre,
+    TResult? Function(TrackFilter_Year value)? year,
+    TResult? Function(TrackFilter_DurationMs value)? durationMs,
+    TResult? Function(TrackFilter_Codec value)? codec,
+    TResult? Function(TrackFilter_FileExtension value)? fileExtension,
+    TResult? Function(TrackFilter_Lossless value)? lossless,
+    TResult? Function(TrackFilter_Bitrate value)? bitrate,
+    TResult? Function(TrackFilter_MinSampleRate value)? minSampleRate,
+    TResult? Function(TrackFilter_MinBitDepth value)? minBitDepth,
+    TResult? Function(TrackFilter_Channels value)? channels,
+    TResult? Function(TrackFilter_LibraryRoot value)? libraryRoot,
+    TResult? Function(TrackFilter_Text value)? text,
+    TResult? Function(TrackFilter_Not value)? not,
+  }) {
+    return minBitDepth?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(TrackFilter_Artist value)? artist,
+    TResult Function(TrackFilter_Album value)? album,
+    TResult Function(TrackFilter_Genre value)? genre,
+    TResult Function(TrackFilter_Year value)? year,
+    TResult Function(TrackFilter_DurationMs value)? durationMs,
+    TResult Function(TrackFilter_Codec value)? codec,
+    TResult Function(TrackFilter_FileExtension value)? fileExtension,
+    TResult Function(TrackFilter_Lossless value)? lossless,
+    TResult Function(TrackFilter_Bitrate value)? bitrate,
+    TResult Function(TrackFilter_MinSampleRate value)? minSampleRate,
+    TResult Function(TrackFilter_MinBitDepth value)? minBitDepth,
+    TResult Function(TrackFilter_Channels value)? channels,
+    TResult Function(TrackFilter_LibraryRoot value)? libraryRoot,
+    TResult Function(TrackFilter_Text value)? text,
+    TResult Function(TrackFilter_Not value)? not,
+    required TResult orElse(),
+  }) {
+    if (minBitDepth != null) {
+      return minBitDepth(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class TrackFilter_MinBitDepth implements TrackFilter {
+  const factory TrackFilter_MinBitDepth(final int field0) =
+      _$TrackFilter_MinBitDepthImpl;
+
+  int get field0;
+  @JsonKey(ignore: true)
+  _$$TrackFilter_MinBitDepthImplCopyWith<_$TrackFilter_MinBitDepthImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$TrackFilter_ChannelsImplCopyWith<$Res> {
+  factory _$$TrackFilter_ChannelsImplCopyWith(
+          _$TrackFilter_ChannelsImpl value,
+          $Res Function(_$TrackFilter_ChannelsImpl) then) =
+      __$$TrackFilter_ChannelsImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({int field0});
+}
+
+/// @nodoc
+class __$$TrackFilter_ChannelsImplCopyWithImpl<$Res>
+    extends _$TrackFilterCopyWithImpl<$Res, _$TrackFilter_ChannelsImpl>
+    implements _$$TrackFilter_ChannelsImplCopyWith<$Res> {
+  __$$TrackFilter_ChannelsImplCopyWithImpl(
+      _$TrackFilter_ChannelsImpl _value,
+      $Res Function(_$TrackFilter_ChannelsImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$TrackFilter_ChannelsImpl(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$TrackFilter_ChannelsImpl implements TrackFilter_Channels {
+  const _$TrackFilter_ChannelsImpl(this.field0);
+
+  @override
+  final int field0;
+
+  @override
+  String toString() {
+    return 'TrackFilter.channels(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$TrackFilter_ChannelsImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$TrackFilter_ChannelsImplCopyWith<_$TrackFilter_ChannelsImpl>
+      get copyWith => __$$TrackFilter_ChannelsImplCopyWithImpl<
+          _$TrackFilter_ChannelsImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int field0) artist,
+    required TResult Function(int field0) album,
+    required TResult Function(int field0) genre,
+    required TResult Function(int? min, int? max) year,
+    required TResult Function(int? min, int? max) durationMs,
+    required TResult Function(String field0) codec,
+    required TResult Function(String field0) fileExtension,
+    required TResult Function(bool field0) lossless,
+    required TResult Function(int? min, int? max) bitrate,
+    required TResult Function(int field0) minSampleRate,
+    required TResult Function(int field0) minBitDepth,
+    required TResult Function(int field0) channels,
+    required TResult Function(int field0) libraryRoot,
+    required TResult Function(TextField field, String text) text,
+    required TResult Function(TrackFilter field0) not,
+  }) {
+    return channels(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int field0)? artist,
+    TResult? Function(int field0)? album,
+    TResult? Function(int field0)? genre,
+    TResult? Function(int? min, int? max)? year,
+    TResult? Function(int? min, int? max)? durationMs,
+    TResult? Function(String field0)? codec,
+    TResult? Function(String field0)? fileExtension,
+    TResult? Function(bool field0)? lossless,
+    TResult? Function(int? min, int? max)? bitrate,
+    TResult? Function(int field0)? minSampleRate,
+    TResult? Function(int field0)? minBitDepth,
+    TResult? Function(int field0)? channels,
+    TResult? Function(int field0)? libraryRoot,
+    TResult? Function(TextField field, String text)? text,
+    TResult? Function(TrackFilter field0)? not,
+  }) {
+    return channels?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int field0)? artist,
+    TResult Function(int field0)? album,
+    TResult Function(int field0)? genre,
+    TResult Function(int? min, int? max)? year,
+    TResult Function(int? min, int? max)? durationMs,
+    TResult Function(String field0)? codec,
+    TResult Function(String field0)? fileExtension,
+    TResult Function(bool field0)? lossless,
+    TResult Function(int? min, int? max)? bitrate,
+    TResult Function(int field0)? minSampleRate,
+    TResult Function(int field0)? minBitDepth,
+    TResult Function(int field0)? channels,
+    TResult Function(int field0)? libraryRoot,
+    TResult Function(TextField field, String text)? text,
+    TResult Function(TrackFilter field0)? not,
+    required TResult orElse(),
+  }) {
+    if (channels != null) {
+      return channels(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(TrackFilter_Artist value) artist,
+    required TResult Function(TrackFilter_Album value) album,
+    required TResult Function(TrackFilter_Genre value) genre,
+    required TResult Function(TrackFilter_Year value) year,
+    required TResult Function(TrackFilter_DurationMs value) durationMs,
+    required TResult Function(TrackFilter_Codec value) codec,
+    required TResult Function(TrackFilter_FileExtension value) fileExtension,
+    required TResult Function(TrackFilter_Lossless value) lossless,
+    required TResult Function(TrackFilter_Bitrate value) bitrate,
+    required TResult Function(TrackFilter_MinSampleRate value) minSampleRate,
+    required TResult Function(TrackFilter_MinBitDepth value) minBitDepth,
+    required TResult Function(TrackFilter_Channels value) channels,
+    required TResult Function(TrackFilter_LibraryRoot value) libraryRoot,
+    required TResult Function(TrackFilter_Text value) text,
+    required TResult Function(TrackFilter_Not value) not,
+  }) {
+    return channels(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(TrackFilter_Artist value)? artist,
+    TResult? Function(TrackFilter_Album value)? album,
+    TResult? Function(TrackFilter_Genre value)? genre,
+    TResult? Function(TrackFilter_Year value)? year,
+    TResult? Function(TrackFilter_DurationMs value)? durationMs,
+    TResult? Function(TrackFilter_Codec value)? codec,
+    TResult? Function(TrackFilter_FileExtension value)? fileExtension,
+    TResult? Function(TrackFilter_Lossless value)? lossless,
+    TResult? Function(TrackFilter_Bitrate value)? bitrate,
+    TResult? Function(TrackFilter_MinSampleRate value)? minSampleRate,
+    TResult? Function(TrackFilter_MinBitDepth value)? minBitDepth,
+    TResult? Function(TrackFilter_Channels value)? channels,
+    TResult? Function(TrackFilter_LibraryRoot value)? libraryRoot,
+    TResult? Function(TrackFilter_Text value)? text,
+    TResult? Function(TrackFilter_Not value)? not,
+  }) {
+    return channels?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(TrackFilter_Artist value)? artist,
+    TResult Function(TrackFilter_Album value)? album,
+    TResult Function(TrackFilter_Genre value)? genre,
+    TResult Function(TrackFilter_Year value)? year,
+    TResult Function(TrackFilter_DurationMs value)? durationMs,
+    TResult Function(TrackFilter_Codec value)? codec,
+    TResult Function(TrackFilter_FileExtension value)? fileExtension,
+    TResult Function(TrackFilter_Lossless value)? lossless,
+    TResult Function(TrackFilter_Bitrate value)? bitrate,
+    TResult Function(TrackFilter_MinSampleRate value)? minSampleRate,
+    TResult Function(TrackFilter_MinBitDepth value)? minBitDepth,
+    TResult Function(TrackFilter_Channels value)? channels,
+    TResult Function(TrackFilter_LibraryRoot value)? libraryRoot,
+    TResult Function(TrackFilter_Text value)? text,
+    TResult Function(TrackFilter_Not value)? not,
+    required TResult orElse(),
+  }) {
+    if (channels != null) {
+      return channels(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class TrackFilter_Channels implements TrackFilter {
+  const factory TrackFilter_Channels(final int field0) =
+      _$TrackFilter_ChannelsImpl;
+
+  int get field0;
+  @JsonKey(ignore: true)
+  _$$TrackFilter_ChannelsImplCopyWith<_$TrackFilter_ChannelsImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$TrackFilter_LibraryRootImplCopyWith<$Res> {
+  factory _$$TrackFilter_LibraryRootImplCopyWith(
+          _$TrackFilter_LibraryRootImpl value,
+          $Res Function(_$TrackFilter_LibraryRootImpl) then) =
+      __$$TrackFilter_LibraryRootImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({int field0});
+}
+
+/// @nodoc
+class __$$TrackFilter_LibraryRootImplCopyWithImpl<$Res>
+    extends _$TrackFilterCopyWithImpl<$Res, _$TrackFilter_LibraryRootImpl>
+    implements _$$TrackFilter_LibraryRootImplCopyWith<$Res> {
+  __$$TrackFilter_LibraryRootImplCopyWithImpl(
+      _$TrackFilter_LibraryRootImpl _value,
+      $Res Function(_$TrackFilter_LibraryRootImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$TrackFilter_LibraryRootImpl(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$TrackFilter_LibraryRootImpl implements TrackFilter_LibraryRoot {
+  const _$TrackFilter_LibraryRootImpl(this.field0);
+
+  @override
+  final int field0;
+
+  @override
+  String toString() {
+    return 'TrackFilter.libraryRoot(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$TrackFilter_LibraryRootImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$TrackFilter_LibraryRootImplCopyWith<_$TrackFilter_LibraryRootImpl>
+      get copyWith => __$$TrackFilter_LibraryRootImplCopyWithImpl<
+          _$TrackFilter_LibraryRootImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int field0) artist,
+    required TResult Function(int field0) album,
+    required TResult Function(int field0) genre,
+    required TResult Function(int? min, int? max) year,
+    required TResult Function(int? min, int? max) durationMs,
+    required TResult Function(String field0) codec,
+    required TResult Function(String field0) fileExtension,
+    required TResult Function(bool field0) lossless,
+    required TResult Function(int? min, int? max) bitrate,
+    required TResult Function(int field0) minSampleRate,
+    required TResult Function(int field0) minBitDepth,
+    required TResult Function(int field0) channels,
+    required TResult Function(int field0) libraryRoot,
+    required TResult Function(TextField field, String text) text,
+    required TResult Function(TrackFilter field0) not,
+  }) {
+    return libraryRoot(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int field0)? artist,
+    TResult? Function(int field0)? album,
+    TResult? Function(int field0)? genre,
+    TResult? Function(int? min, int? max)? year,
+    TResult? Function(int? min, int? max)? durationMs,
+    TResult? Function(String field0)? codec,
+    TResult? Function(String field0)? fileExtension,
+    TResult? Function(bool field0)? lossless,
+    TResult? Function(int? min, int? max)? bitrate,
+    TResult? Function(int field0)? minSampleRate,
+    TResult? Function(int field0)? minBitDepth,
+    TResult? Function(int field0)? channels,
+    TResult? Function(int field0)? libraryRoot,
+    TResult? Function(TextField field, String text)? text,
+    TResult? Function(TrackFilter field0)? not,
+  }) {
+    return libraryRoot?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int field0)? artist,
+    TResult Function(int field0)? album,
+    TResult Function(int field0)? genre,
+    TResult Function(int? min, int? max)? year,
+    TResult Function(int? min, int? max)? durationMs,
+    TResult Function(String field0)? codec,
+    TResult Function(String field0)? fileExtension,
+    TResult Function(bool field0)? lossless,
+    TResult Function(int? min, int? max)? bitrate,
+    TResult Function(int field0)? minSampleRate,
+    TResult Function(int field0)? minBitDepth,
+    TResult Function(int field0)? channels,
+    TResult Function(int field0)? libraryRoot,
+    TResult Function(TextField field, String text)? text,
+    TResult Function(TrackFilter field0)? not,
+    required TResult orElse(),
+  }) {
+    if (libraryRoot != null) {
+      return libraryRoot(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(TrackFilter_Artist value) artist,
+    required TResult Function(TrackFilter_Album value) album,
+    required TResult Function(TrackFilter_Genre value) genre,
+    required TResult Function(TrackFilter_Year value) year,
+    required TResult Function(TrackFilter_DurationMs value) durationMs,
+    required TResult Function(TrackFilter_Codec value) codec,
+    required TResult Function(TrackFilter_FileExtension value) fileExtension,
+    required TResult Function(TrackFilter_Lossless value) lossless,
+    required TResult Function(TrackFilter_Bitrate value) bitrate,
+    required TResult Function(TrackFilter_MinSampleRate value) minSampleRate,
+    required TResult Function(TrackFilter_MinBitDepth value) minBitDepth,
+    required TResult Function(TrackFilter_Channels value) channels,
+    required TResult Function(TrackFilter_LibraryRoot value) libraryRoot,
+    required TResult Function(TrackFilter_Text value) text,
+    required TResult Function(TrackFilter_Not value) not,
+  }) {
+    return libraryRoot(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(TrackFilter_Artist value)? artist,
+    TResult? Function(TrackFilter_Album value)? album,
+    TResult? Function(TrackFilter_Genre value)? genre,
+    TResult? Function(TrackFilter_Year value)? year,
+    TResult? Function(TrackFilter_DurationMs value)? durationMs,
+    TResult? Function(TrackFilter_Codec value)? codec,
+    TResult? Function(TrackFilter_FileExtension value)? fileExtension,
+    TResult? Function(TrackFilter_Lossless value)? lossless,
+    TResult? Function(TrackFilter_Bitrate value)? bitrate,
+    TResult? Function(TrackFilter_MinSampleRate value)? minSampleRate,
+    TResult? Function(TrackFilter_MinBitDepth value)? minBitDepth,
+    TResult? Function(TrackFilter_Channels value)? channels,
+    TResult? Function(TrackFilter_LibraryRoot value)? libraryRoot,
+    TResult? Function(TrackFilter_Text value)? text,
+    TResult? Function(TrackFilter_Not value)? not,
+  }) {
+    return libraryRoot?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(TrackFilter_Artist value)? artist,
+    TResult Function(TrackFilter_Album value)? album,
+    TResult Function(TrackFilter_Genre value)? genre,
+    TResult Function(TrackFilter_Year value)? year,
+    TResult Function(TrackFilter_DurationMs value)? durationMs,
+    TResult Function(TrackFilter_Codec value)? codec,
+    TResult Function(TrackFilter_FileExtension value)? fileExtension,
+    TResult Function(TrackFilter_Lossless value)? lossless,
+    TResult Function(TrackFilter_Bitrate value)? bitrate,
+    TResult Function(TrackFilter_MinSampleRate value)? minSampleRate,
+    TResult Function(TrackFilter_MinBitDepth value)? minBitDepth,
+    TResult Function(TrackFilter_Channels value)? channels,
+    TResult Function(TrackFilter_LibraryRoot value)? libraryRoot,
+    TResult Function(TrackFilter_Text value)? text,
+    TResult Function(TrackFilter_Not value)? not,
+    required TResult orElse(),
+  }) {
+    if (libraryRoot != null) {
+      return libraryRoot(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class TrackFilter_LibraryRoot implements TrackFilter {
+  const factory TrackFilter_LibraryRoot(final int field0) =
+      _$TrackFilter_LibraryRootImpl;
+
+  int get field0;
+  @JsonKey(ignore: true)
+  _$$TrackFilter_LibraryRootImplCopyWith<_$TrackFilter_LibraryRootImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$TrackFilter_TextImplCopyWith<$Res> {
+  factory _$$TrackFilter_TextImplCopyWith(
+          _$TrackFilter_TextImpl value,
+          $Res Function(_$TrackFilter_TextImpl) then) =
+      __$$TrackFilter_TextImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({TextField field, String text});
+}
+
+/// @nodoc
+class __$$TrackFilter_TextImplCopyWithImpl<$Res>
+    extends _$TrackFilterCopyWithImpl<$Res, _$TrackFilter_TextImpl>
+    implements _$$TrackFilter_TextImplCopyWith<$Res> {
+  __$$TrackFilter_TextImplCopyWithImpl(
+      _$TrackFilter_TextImpl _value,
+      $Res Function(_$TrackFilter_TextImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field = null,
+    Object? text = null,
+  }) {
+    return _then(_$TrackFilter_TextImpl(
+      field: null == field
+          ? _value.field
+          : field // ignore: cast_nullable_to_non_nullable
+              as TextField,
+      text: null == text
+          ? _value.text
+          : text // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$TrackFilter_TextImpl implements TrackFilter_Text {
+  const _$TrackFilter_TextImpl({required this.field, required this.text});
+
+  @override
+  final TextField field;
+  @override
+  final String text;
+
+  @override
+  String toString() {
+    return 'TrackFilter.text(field: $field, text: $text)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$TrackFilter_TextImpl &&
+            (identical(other.field, field) || other.field == field) &&
+            (identical(other.text, text) || other.text == text));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field, text);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$TrackFilter_TextImplCopyWith<_$TrackFilter_TextImpl> get copyWith =>
+      __$$TrackFilter_TextImplCopyWithImpl<_$TrackFilter_TextImpl>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int field0) artist,
+    required TResult Function(int field0) album,
+    required TResult Function(int field0) genre,
+    required TResult Function(int? min, int? max) year,
+    required TResult Function(int? min, int? max) durationMs,
+    required TResult Function(String field0) codec,
+    required TResult Function(String field0) fileExtension,
+    required TResult Function(bool field0) lossless,
+    required TResult Function(int? min, int? max) bitrate,
+    required TResult Function(int field0) minSampleRate,
+    required TResult Function(int field0) minBitDepth,
+    required TResult Function(int field0) channels,
+    required TResult Function(int field0) libraryRoot,
+    required TResult Function(TextField field, String text) text,
+    required TResult Function(TrackFilter field0) not,
+  }) {
+    return text(field, text);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int field0)? artist,
+    TResult? Function(int field0)? album,
+    TResult? Function(int field0)? genre,
+    TResult? Function(int? min, int? max)? year,
+    TResult? Function(int? min, int? max)? durationMs,
+    TResult? Function(String field0)? codec,
+    TResult? Function(String field0)? fileExtension,
+    TResult? Function(bool field0)? lossless,
+    TResult? Function(int? min, int? max)? bitrate,
+    TResult? Function(int field0)? minSampleRate,
+    TResult? Function(int field0)? minBitDepth,
+    TResult? Function(int field0)? channels,
+    TResult? Function(int field0)? libraryRoot,
+    TResult? Function(TextField field, String text)? text,
+    TResult? Function(TrackFilter field0)? not,
+  }) {
+    return text?.call(field, text);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int field0)? artist,
+    TResult Function(int field0)? album,
+    TResult Function(int field0)? genre,
+    TResult Function(int? min, int? max)? year,
+    TResult Function(int? min, int? max)? durationMs,
+    TResult Function(String field0)? codec,
+    TResult Function(String field0)? fileExtension,
+    TResult Function(bool field0)? lossless,
+    TResult Function(int? min, int? max)? bitrate,
+    TResult Function(int field0)? minSampleRate,
+    TResult Function(int field0)? minBitDepth,
+    TResult Function(int field0)? channels,
+    TResult Function(int field0)? libraryRoot,
+    TResult Function(TextField field, String text)? text,
+    TResult Function(TrackFilter field0)? not,
+    required TResult orElse(),
+  }) {
+    if (text != null) {
+      return text(field, text);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(TrackFilter_Artist value) artist,
+    required TResult Function(TrackFilter_Album value) album,
+    required TResult Function(TrackFilter_Genre value) genre,
+    required TResult Function(TrackFilter_Year value) year,
+    required TResult Function(TrackFilter_DurationMs value) durationMs,
+    required TResult Function(TrackFilter_Codec value) codec,
+    required TResult Function(TrackFilter_FileExtension value) fileExtension,
+    required TResult Function(TrackFilter_Lossless value) lossless,
+    required TResult Function(TrackFilter_Bitrate value) bitrate,
+    required TResult Function(TrackFilter_MinSampleRate value) minSampleRate,
+    required TResult Function(TrackFilter_MinBitDepth value) minBitDepth,
+    required TResult Function(TrackFilter_Channels value) channels,
+    required TResult Function(TrackFilter_LibraryRoot value) libraryRoot,
+    required TResult Function(TrackFilter_Text value) text,
+    required TResult Function(TrackFilter_Not value) not,
+  }) {
+    return text(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(TrackFilter_Artist value)? artist,
+    TResult? Function(TrackFilter_Album value)? album,
+    TResult? Function(TrackFilter_Genre value)? genre,
+    TResult? Function(TrackFilter_Year value)? year,
+    TResult? Function(TrackFilter_DurationMs value)? durationMs,
+    TResult? Function(TrackFilter_Codec value)? codec,
+    TResult? Function(TrackFilter_FileExtension value)? fileExtension,
+    TResult? Function(TrackFilter_Lossless value)? lossless,
+    TResult? Function(TrackFilter_Bitrate value)? bitrate,
+    TResult? Function(TrackFilter_MinSampleRate value)? minSampleRate,
+    TResult? Function(TrackFilter_MinBitDepth value)? minBitDepth,
+    TResult? Function(TrackFilter_Channels value)? channels,
+    TResult? Function(TrackFilter_LibraryRoot value)? libraryRoot,
+    TResult? Function(TrackFilter_Text value)? text,
+    TResult? Function(TrackFilter_Not value)? not,
+  }) {
+    return text?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(TrackFilter_Artist value)? artist,
+    TResult Function(TrackFilter_Album value)? album,
+    TResult Function(TrackFilter_Genre value)? genre,
+    TResult Function(TrackFilter_Year value)? year,
+    TResult Function(TrackFilter_DurationMs value)? durationMs,
+    TResult Function(TrackFilter_Codec value)? codec,
+    TResult Function(TrackFilter_FileExtension value)? fileExtension,
+    TResult Function(TrackFilter_Lossless value)? lossless,
+    TResult Function(TrackFilter_Bitrate value)? bitrate,
+    TResult Function(TrackFilter_MinSampleRate value)? minSampleRate,
+    TResult Function(TrackFilter_MinBitDepth value)? minBitDepth,
+    TResult Function(TrackFilter_Channels value)? channels,
+    TResult Function(TrackFilter_LibraryRoot value)? libraryRoot,
+    TResult Function(TrackFilter_Text value)? text,
+    TResult Function(TrackFilter_Not value)? not,
+    required TResult orElse(),
+  }) {
+    if (text != null) {
+      return text(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class TrackFilter_Text implements TrackFilter {
+  const factory TrackFilter_Text(
+          {required final TextField field, required final String text}) =
+      _$TrackFilter_TextImpl;
+
+  TextField get field;
+  String get text;
+  @JsonKey(ignore: true)
+  _$$TrackFilter_TextImplCopyWith<_$TrackFilter_TextImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$TrackFilter_NotImplCopyWith<$Res> {
+  factory _$$TrackFilter_NotImplCopyWith(
+          _$TrackFilter_NotImpl value,
+          $Res Function(_$TrackFilter_NotImpl) then) =
+      __$$TrackFilter_NotImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({TrackFilter field0});
+
+  $TrackFilterCopyWith<$Res> get field0;
+}
+
+/// @nodoc
+class __$$TrackFilter_NotImplCopyWithImpl<$Res>
+    extends _$TrackFilterCopyWithImpl<$Res, _$TrackFilter_NotImpl>
+    implements _$$TrackFilter_NotImplCopyWith<$Res> {
+  __$$TrackFilter_NotImplCopyWithImpl(
+      _$TrackFilter_NotImpl _value, $Res Function(_$TrackFilter_NotImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$TrackFilter_NotImpl(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as TrackFilter,
+    ));
+  }
+
+  @override
+  @pragma('vm:prefer-inline')
+  $TrackFilterCopyWith<$Res> get field0 {
+    return $TrackFilterCopyWith<$Res>(_value.field0, (value) {
+      return _then(_value.copyWith(field0: value));
+    });
+  }
+}
+
+/// @nodoc
+
+class _$TrackFilter_NotImpl implements TrackFilter_Not {
+  const _$TrackFilter_NotImpl(this.field0);
+
+  @override
+  final TrackFilter field0;
+
+  @override
+  String toString() {
+    return 'TrackFilter.not(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$TrackFilter_NotImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$TrackFilter_NotImplCopyWith<_$TrackFilter_NotImpl> get copyWith =>
+      __$$TrackFilter_NotImplCopyWithImpl<_$TrackFilter_NotImpl>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int field0) artist,
+    required TResult Function(int field0) album,
+    required TResult Function(int field0) genre,
+    required TResult Function(int? min, int? max) year,
+    required TResult Function(int? min, int? max) durationMs,
+    required TResult Function(String field0) codec,
+    required TResult Function(String field0) fileExtension,
+    required TResult Function(bool field0) lossless,
+    required TResult Function(int? min, int? max) bitrate,
+    required TResult Function(int field0) minSampleRate,
+    required TResult Function(int field0) minBitDepth,
+    required TResult Function(int field0) channels,
+    required TResult Function(int field0) libraryRoot,
+    required TResult Function(TextField field, String text) text,
+    required TResult Function(TrackFilter field0) not,
+  }) {
+    return not(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int field0)? artist,
+    TResult? Function(int field0)? album,
+    TResult? Function(int field0)? genre,
+    TResult? Function(int? min, int? max)? year,
+    TResult? Function(int? min, int? max)? durationMs,
+    TResult? Function(String field0)? codec,
+    TResult? Function(String field0)? fileExtension,
+    TResult? Function(bool field0)? lossless,
+    TResult? Function(int? min, int? max)? bitrate,
+    TResult? Function(int field0)? minSampleRate,
+    TResult? Function(int field0)? minBitDepth,
+    TResult? Function(int field0)? channels,
+    TResult? Function(int field0)? libraryRoot,
+    TResult? Function(TextField field, String text)? text,
+    TResult? Function(TrackFilter field0)? not,
+  }) {
+    return not?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int field0)? artist,
+    TResult Function(int field0)? album,
+    TResult Function(int field0)? genre,
+    TResult Function(int? min, int? max)? year,
+    TResult Function(int? min, int? max)? durationMs,
+    TResult Function(String field0)? codec,
+    TResult Function(String field0)? fileExtension,
+    TResult Function(bool field0)? lossless,
+    TResult Function(int? min, int? max)? bitrate,
+    TResult Function(int field0)? minSampleRate,
+    TResult Function(int field0)? minBitDepth,
+    TResult Function(int field0)? channels,
+    TResult Function(int field0)? libraryRoot,
+    TResult Function(TextField field, String text)? text,
+    TResult Function(TrackFilter field0)? not,
+    required TResult orElse(),
+  }) {
+    if (not != null) {
+      return not(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(TrackFilter_Artist value) artist,
+    required TResult Function(TrackFilter_Album value) album,
+    required TResult Function(TrackFilter_Genre value) genre,
+    required TResult Function(TrackFilter_Year value) year,
+    required TResult Function(TrackFilter_DurationMs value) durationMs,
+    required TResult Function(TrackFilter_Codec value) codec,
+    required TResult Function(TrackFilter_FileExtension value) fileExtension,
+    required TResult Function(TrackFilter_Lossless value) lossless,
+    required TResult Function(TrackFilter_Bitrate value) bitrate,
+    required TResult Function(TrackFilter_MinSampleRate value) minSampleRate,
+    required TResult Function(TrackFilter_MinBitDepth value) minBitDepth,
+    required TResult Function(TrackFilter_Channels value) channels,
+    required TResult Function(TrackFilter_LibraryRoot value) libraryRoot,
+    required TResult Function(TrackFilter_Text value) text,
+    required TResult Function(TrackFilter_Not value) not,
+  }) {
+    return not(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(TrackFilter_Artist value)? artist,
+    TResult? Function(TrackFilter_Album value)? album,
+    TResult? Function(TrackFilter_Genre value)? genre,
+    TResult? Function(TrackFilter_Year value)? year,
+    TResult? Function(TrackFilter_DurationMs value)? durationMs,
+    TResult? Function(TrackFilter_Codec value)? codec,
+    TResult? Function(TrackFilter_FileExtension value)? fileExtension,
+    TResult? Function(TrackFilter_Lossless value)? lossless,
+    TResult? Function(TrackFilter_Bitrate value)? bitrate,
+    TResult? Function(TrackFilter_MinSampleRate value)? minSampleRate,
+    TResult? Function(TrackFilter_MinBitDepth value)? minBitDepth,
+    TResult? Function(TrackFilter_Channels value)? channels,
+    TResult? Function(TrackFilter_LibraryRoot value)? libraryRoot,
+    TResult? Function(TrackFilter_Text value)? text,
+    TResult? Function(TrackFilter_Not value)? not,
+  }) {
+    return not?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(TrackFilter_Artist value)? artist,
+    TResult Function(TrackFilter_Album value)? album,
+    TResult Function(TrackFilter_Genre value)? genre,
+    TResult Function(TrackFilter_Year value)? year,
+    TResult Function(TrackFilter_DurationMs value)? durationMs,
+    TResult Function(TrackFilter_Codec value)? codec,
+    TResult Function(TrackFilter_FileExtension value)? fileExtension,
+    TResult Function(TrackFilter_Lossless value)? lossless,
+    TResult Function(TrackFilter_Bitrate value)? bitrate,
+    TResult Function(TrackFilter_MinSampleRate value)? minSampleRate,
+    TResult Function(TrackFilter_MinBitDepth value)? minBitDepth,
+    TResult Function(TrackFilter_Channels value)? channels,
+    TResult Function(TrackFilter_LibraryRoot value)? libraryRoot,
+    TResult Function(TrackFilter_Text value)? text,
+    TResult Function(TrackFilter_Not value)? not,
+    required TResult orElse(),
+  }) {
+    if (not != null) {
+      return not(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class TrackFilter_Not implements TrackFilter {
+  const factory TrackFilter_Not(final TrackFilter field0) =
+      _$TrackFilter_NotImpl;
+
+  TrackFilter get field0;
+  @JsonKey(ignore: true)
+  _$$TrackFilter_NotImplCopyWith<_$TrackFilter_NotImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
@@ -0,0 +1,59 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.28.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'query.dart';
+
+// The type `NumberKind` is not used by any `pub` functions, thus it is ignored.
+// The type `Parser` is not used by any `pub` functions, thus it is ignored.
+// The type `Spanned` is not used by any `pub` functions, thus it is ignored.
+
+/// Parses a search query into filters every matching track meets, for use in
+/// a [`super::query::TrackQuery`].
+///
+/// A query is a list of terms separated by spaces. A term is free text
+/// matched against title, artists, album, genres and composers, or
+/// `field:value` with one of these fields, other text before a `:` being
+/// free text too:
+///
+/// - `title`, `artist`, `album`, `genre`, `composer`: text in that field
+/// - `year`: a year or range, e.g. `2018`, `2018..2020`, `>=2018`
+/// - `duration`: a duration or range, e.g. `>5m`, `3:00..4:30`, `<90s`
+/// - `bitrate`: kbit/s or a range, e.g. `>=320`
+/// - `format`: a file extension, e.g. `flac`, `m4a`, `wav`
+/// - `codec`: e.g. `mp3`, `aac`, `alac`, `vorbis`, or `pcm` for WAV and AIFF
+/// - `lossless`: `yes` or `no`
+///
+/// Values with spaces are quoted, e.g. `artist:"Kenshi Yonezu"`, and a
+/// leading `-` excludes the tracks a term matches, e.g. `-live`.
+List<TrackFilter> parseTrackFilters({required String query, dynamic hint}) =>
+    RustLib.instance.api.parseTrackFilters(query: query, hint: hint);
+
+/// Why a query could not be parsed, with the span of the offending text in
+/// UTF-16 code units from the start of the query, as Dart indexes strings.
+class QueryParseError implements FrbException {
+  final String message;
+  final int start;
+  final int end;
+
+  const QueryParseError({
+    required this.message,
+    required this.start,
+    required this.end,
+  });
+
+  @override
+  int get hashCode => message.hashCode ^ start.hashCode ^ end.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is QueryParseError &&
+          runtimeType == other.runtimeType &&
+          message == other.message &&
+          start == other.start &&
+          end == other.end;
+}
@@ -0,0 +1,166 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.28.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import '../model.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'scan.freezed.dart';
+
+// The type `AlbumKey` is not used by any `pub` functions, thus it is ignored.
+// The type `ArtistCredit` is not used by any `pub` functions, thus it is ignored.
+// The type `FileStamp` is not used by any `pub` functions, thus it is ignored.
+// The type `MusicFileMatch` is not used by any `pub` functions, thus it is ignored.
+// The type `ParsedTrack` is not used by any `pub` functions, thus it is ignored.
+// The type `ScanFailure` is not used by any `pub` functions, thus it is ignored.
+// The type `ScannedFile` is not used by any `pub` functions, thus it is ignored.
+// The type `TrackWriter` is not used by any `pub` functions, thus it is ignored.
+
+/// Files that failed to import, most recent first.
+Future<List<ScanError>> getScanErrors({dynamic hint}) =>
+    RustLib.instance.api.getScanErrors(hint: hint);
+
+Future<void> clearScanErrors({dynamic hint}) =>
+    RustLib.instance.api.clearScanErrors(hint: hint);
+
+/// Scans `mount_point` like [`super::simple::sync_directory`], reporting
+/// progress, per-file errors and the final summary through `sink`.
+Stream<ScanEvent> scanDirectory(
+        {required String mountPoint,
+        required ScanCancelToken cancelToken,
+        dynamic hint}) =>
+    RustLib.instance.api.scanDirectory(
+        mountPoint: mountPoint, cancelToken: cancelToken, hint: hint);
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::rust_async::RwLock<ScanCancelToken>>
+@sealed
+class ScanCancelToken extends RustOpaque {
+  ScanCancelToken.dcoDecode(List<dynamic> wire)
+      : super.dcoDecode(wire, _kStaticData);
+
+  ScanCancelToken.sseDecode(int ptr, int externalSizeOnNative)
+      : super.sseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_ScanCancelToken,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_ScanCancelToken,
+    rustArcDecrementStrongCountPtr:
+        RustLib.instance.api.rust_arc_decrement_strong_count_ScanCancelTokenPtr,
+  );
+
+  void cancel({dynamic hint}) => RustLib.instance.api.scanCancelTokenCancel(
+        that: this,
+      );
+
+  bool isCancelled({dynamic hint}) =>
+      RustLib.instance.api.scanCancelTokenIsCancelled(
+        that: this,
+      );
+
+  factory ScanCancelToken({dynamic hint}) =>
+      RustLib.instance.api.scanCancelTokenNew(hint: hint);
+}
+
+@freezed
+sealed class ScanEvent with _$ScanEvent {
+  const factory ScanEvent.progress(
+    ScanProgress field0,
+  ) = ScanEvent_Progress;
+  /// A file could not be imported. `kind` is one of the values stored in
+  /// [`ScanError::kind`].
+  const factory ScanEvent.error({
+    required String path,
+    required String kind,
+    required String message,
+  }) = ScanEvent_Error;
+  const factory ScanEvent.finished(
+    SyncSummary field0,
+  ) = ScanEvent_Finished;
+}
+
+/// Running counters of a scan in progress.
+class ScanProgress {
+  /// Music files found so far by the directory walk.
+  final int discovered;
+  /// Files that have been checked against the library and parsed if needed.
+  final int parsed;
+  /// Parsed files that have been written to the database.
+  final int written;
+  /// The file handled most recently.
+  final String currentPath;
+
+  const ScanProgress({
+    required this.discovered,
+    required this.parsed,
+    required this.written,
+    required this.currentPath,
+  });
+
+  @override
+  int get hashCode =>
+      discovered.hashCode ^
+      parsed.hashCode ^
+      written.hashCode ^
+      currentPath.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ScanProgress &&
+          runtimeType == other.runtimeType &&
+          discovered == other.discovered &&
+          parsed == other.parsed &&
+          written == other.written &&
+          currentPath == other.currentPath;
+}
+
+/// Counts reported once a scan has finished.
+class SyncSummary {
+  /// Files that were not in the library before.
+  final int added;
+  /// Known files whose size or modification time changed and were re-parsed.
+  final int updated;
+  /// Known files that were skipped because they did not change.
+  final int unchanged;
+  /// Files that could not be parsed or written to the database.
+  final int failed;
+  /// Tracks whose files were no longer found under the mount point.
+  final int removed;
+  /// Whether the scan was stopped through its [`ScanCancelToken`] before
+  /// every file was written.
+  final bool cancelled;
+
+  const SyncSummary({
+    required this.added,
+    required this.updated,
+    required this.unchanged,
+    required this.failed,
+    required this.removed,
+    required this.cancelled,
+  });
+
+  @override
+  int get hashCode =>
+      added.hashCode ^
+      updated.hashCode ^
+      unchanged.hashCode ^
+      failed.hashCode ^
+      removed.hashCode ^
+      cancelled.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SyncSummary &&
+          runtimeType == other.runtimeType &&
+          added == other.added &&
+          updated == other.updated &&
+          unchanged == other.unchanged &&
+          failed == other.failed &&
+          removed == other.removed &&
+          cancelled == other.cancelled;
+}
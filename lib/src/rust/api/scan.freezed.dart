@@ -0,0 +1,529 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'scan.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+    'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models');
+
+/// @nodoc
+mixin _$ScanEvent {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(ScanProgress field0) progress,
+    required TResult Function(String path, String kind, String message) error,
+    required TResult Function(SyncSummary field0) finished,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(ScanProgress field0)? progress,
+    TResult? Function(String path, String kind, String message)? error,
+    TResult? Function(SyncSummary field0)? finished,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(ScanProgress field0)? progress,
+    TResult Function(String path, String kind, String message)? error,
+    TResult Function(SyncSummary field0)? finished,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(ScanEvent_Progress value) progress,
+    required TResult Function(ScanEvent_Error value) error,
+    required TResult Function(ScanEvent_Finished value) finished,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(ScanEvent_Progress value)? progress,
+    TResult? Function(ScanEvent_Error value)? error,
+    TResult? Function(ScanEvent_Finished value)? finished,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(ScanEvent_Progress value)? progress,
+    TResult Function(ScanEvent_Error value)? error,
+    TResult Function(ScanEvent_Finished value)? finished,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $ScanEventCopyWith<$Res> {
+  factory $ScanEventCopyWith(ScanEvent value, $Res Function(ScanEvent) then) =
+      _$ScanEventCopyWithImpl<$Res, ScanEvent>;
+}
+
+/// @nodoc
+class _$ScanEventCopyWithImpl<$Res, $Val extends ScanEvent>
+    implements $ScanEventCopyWith<$Res> {
+  _$ScanEventCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+}
+
+/// @nodoc
+abstract class _$$ScanEvent_ProgressImplCopyWith<$Res> {
+  factory _$$ScanEvent_ProgressImplCopyWith(
+          _$ScanEvent_ProgressImpl value,
+          $Res Function(_$ScanEvent_ProgressImpl) then) =
+      __$$ScanEvent_ProgressImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({ScanProgress field0});
+}
+
+/// @nodoc
+class __$$ScanEvent_ProgressImplCopyWithImpl<$Res>
+    extends _$ScanEventCopyWithImpl<$Res, _$ScanEvent_ProgressImpl>
+    implements _$$ScanEvent_ProgressImplCopyWith<$Res> {
+  __$$ScanEvent_ProgressImplCopyWithImpl(
+      _$ScanEvent_ProgressImpl _value,
+      $Res Function(_$ScanEvent_ProgressImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$ScanEvent_ProgressImpl(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as ScanProgress,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$ScanEvent_ProgressImpl implements ScanEvent_Progress {
+  const _$ScanEvent_ProgressImpl(this.field0);
+
+  @override
+  final ScanProgress field0;
+
+  @override
+  String toString() {
+    return 'ScanEvent.progress(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$ScanEvent_ProgressImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$ScanEvent_ProgressImplCopyWith<_$ScanEvent_ProgressImpl> get copyWith =>
+      __$$ScanEvent_ProgressImplCopyWithImpl<_$ScanEvent_ProgressImpl>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(ScanProgress field0) progress,
+    required TResult Function(String path, String kind, String message) error,
+    required TResult Function(SyncSummary field0) finished,
+  }) {
+    return progress(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(ScanProgress field0)? progress,
+    TResult? Function(String path, String kind, String message)? error,
+    TResult? Function(SyncSummary field0)? finished,
+  }) {
+    return progress?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(ScanProgress field0)? progress,
+    TResult Function(String path, String kind, String message)? error,
+    TResult Function(SyncSummary field0)? finished,
+    required TResult orElse(),
+  }) {
+    if (progress != null) {
+      return progress(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(ScanEvent_Progress value) progress,
+    required TResult Function(ScanEvent_Error value) error,
+    required TResult Function(ScanEvent_Finished value) finished,
+  }) {
+    return progress(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(ScanEvent_Progress value)? progress,
+    TResult? Function(ScanEvent_Error value)? error,
+    TResult? Function(ScanEvent_Finished value)? finished,
+  }) {
+    return progress?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(ScanEvent_Progress value)? progress,
+    TResult Function(ScanEvent_Error value)? error,
+    TResult Function(ScanEvent_Finished value)? finished,
+    required TResult orElse(),
+  }) {
+    if (progress != null) {
+      return progress(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class ScanEvent_Progress implements ScanEvent {
+  const factory ScanEvent_Progress(final ScanProgress field0) =
+      _$ScanEvent_ProgressImpl;
+
+  ScanProgress get field0;
+  @JsonKey(ignore: true)
+  _$$ScanEvent_ProgressImplCopyWith<_$ScanEvent_ProgressImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$ScanEvent_ErrorImplCopyWith<$Res> {
+  factory _$$ScanEvent_ErrorImplCopyWith(
+          _$ScanEvent_ErrorImpl value,
+          $Res Function(_$ScanEvent_ErrorImpl) then) =
+      __$$ScanEvent_ErrorImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String path, String kind, String message});
+}
+
+/// @nodoc
+class __$$ScanEvent_ErrorImplCopyWithImpl<$Res>
+    extends _$ScanEventCopyWithImpl<$Res, _$ScanEvent_ErrorImpl>
+    implements _$$ScanEvent_ErrorImplCopyWith<$Res> {
+  __$$ScanEvent_ErrorImplCopyWithImpl(
+      _$ScanEvent_ErrorImpl _value, $Res Function(_$ScanEvent_ErrorImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? path = null,
+    Object? kind = null,
+    Object? message = null,
+  }) {
+    return _then(_$ScanEvent_ErrorImpl(
+      path: null == path
+          ? _value.path
+          : path // ignore: cast_nullable_to_non_nullable
+              as String,
+      kind: null == kind
+          ? _value.kind
+          : kind // ignore: cast_nullable_to_non_nullable
+              as String,
+      message: null == message
+          ? _value.message
+          : message // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$ScanEvent_ErrorImpl implements ScanEvent_Error {
+  const _$ScanEvent_ErrorImpl(
+      {required this.path, required this.kind, required this.message});
+
+  @override
+  final String path;
+  @override
+  final String kind;
+  @override
+  final String message;
+
+  @override
+  String toString() {
+    return 'ScanEvent.error(path: $path, kind: $kind, message: $message)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$ScanEvent_ErrorImpl &&
+            (identical(other.path, path) || other.path == path) &&
+            (identical(other.kind, kind) || other.kind == kind) &&
+            (identical(other.message, message) || other.message == message));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, path, kind, message);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$ScanEvent_ErrorImplCopyWith<_$ScanEvent_ErrorImpl> get copyWith =>
+      __$$ScanEvent_ErrorImplCopyWithImpl<_$ScanEvent_ErrorImpl>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(ScanProgress field0) progress,
+    required TResult Function(String path, String kind, String message) error,
+    required TResult Function(SyncSummary field0) finished,
+  }) {
+    return error(path, kind, message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(ScanProgress field0)? progress,
+    TResult? Function(String path, String kind, String message)? error,
+    TResult? Function(SyncSummary field0)? finished,
+  }) {
+    return error?.call(path, kind, message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(ScanProgress field0)? progress,
+    TResult Function(String path, String kind, String message)? error,
+    TResult Function(SyncSummary field0)? finished,
+    required TResult orElse(),
+  }) {
+    if (error != null) {
+      return error(path, kind, message);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(ScanEvent_Progress value) progress,
+    required TResult Function(ScanEvent_Error value) error,
+    required TResult Function(ScanEvent_Finished value) finished,
+  }) {
+    return error(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(ScanEvent_Progress value)? progress,
+    TResult? Function(ScanEvent_Error value)? error,
+    TResult? Function(ScanEvent_Finished value)? finished,
+  }) {
+    return error?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(ScanEvent_Progress value)? progress,
+    TResult Function(ScanEvent_Error value)? error,
+    TResult Function(ScanEvent_Finished value)? finished,
+    required TResult orElse(),
+  }) {
+    if (error != null) {
+      return error(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class ScanEvent_Error implements ScanEvent {
+  const factory ScanEvent_Error(
+      {required final String path,
+      required final String kind,
+      required final String message}) = _$ScanEvent_ErrorImpl;
+
+  String get path;
+  String get kind;
+  String get message;
+  @JsonKey(ignore: true)
+  _$$ScanEvent_ErrorImplCopyWith<_$ScanEvent_ErrorImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$ScanEvent_FinishedImplCopyWith<$Res> {
+  factory _$$ScanEvent_FinishedImplCopyWith(
+          _$ScanEvent_FinishedImpl value,
+          $Res Function(_$ScanEvent_FinishedImpl) then) =
+      __$$ScanEvent_FinishedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({SyncSummary field0});
+}
+
+/// @nodoc
+class __$$ScanEvent_FinishedImplCopyWithImpl<$Res>
+    extends _$ScanEventCopyWithImpl<$Res, _$ScanEvent_FinishedImpl>
+    implements _$$ScanEvent_FinishedImplCopyWith<$Res> {
+  __$$ScanEvent_FinishedImplCopyWithImpl(
+      _$ScanEvent_FinishedImpl _value,
+      $Res Function(_$ScanEvent_FinishedImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$ScanEvent_FinishedImpl(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as SyncSummary,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$ScanEvent_FinishedImpl implements ScanEvent_Finished {
+  const _$ScanEvent_FinishedImpl(this.field0);
+
+  @override
+  final SyncSummary field0;
+
+  @override
+  String toString() {
+    return 'ScanEvent.finished(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$ScanEvent_FinishedImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$ScanEvent_FinishedImplCopyWith<_$ScanEvent_FinishedImpl> get copyWith =>
+      __$$ScanEvent_FinishedImplCopyWithImpl<_$ScanEvent_FinishedImpl>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(ScanProgress field0) progress,
+    required TResult Function(String path, String kind, String message) error,
+    required TResult Function(SyncSummary field0) finished,
+  }) {
+    return finished(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(ScanProgress field0)? progress,
+    TResult? Function(String path, String kind, String message)? error,
+    TResult? Function(SyncSummary field0)? finished,
+  }) {
+    return finished?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(ScanProgress field0)? progress,
+    TResult Function(String path, String kind, String message)? error,
+    TResult Function(SyncSummary field0)? finished,
+    required TResult orElse(),
+  }) {
+    if (finished != null) {
+      return finished(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(ScanEvent_Progress value) progress,
+    required TResult Function(ScanEvent_Error value) error,
+    required TResult Function(ScanEvent_Finished value) finished,
+  }) {
+    return finished(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(ScanEvent_Progress value)? progress,
+    TResult? Function(ScanEvent_Error value)? error,
+    TResult? Function(ScanEvent_Finished value)? finished,
+  }) {
+    return finished?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(ScanEvent_Progress value)? progress,
+    TResult Function(ScanEvent_Error value)? error,
+    TResult Function(ScanEvent_Finished value)? finished,
+    required TResult orElse(),
+  }) {
+    if (finished != null) {
+      return finished(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class ScanEvent_Finished implements ScanEvent {
+  const factory ScanEvent_Finished(final SyncSummary field0) =
+      _$ScanEvent_FinishedImpl;
+
+  SyncSummary get field0;
+  @JsonKey(ignore: true)
+  _$$ScanEvent_FinishedImplCopyWith<_$ScanEvent_FinishedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
@@ -0,0 +1,16 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.28.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// The type `TrackId` is not used by any `pub` functions, thus it is ignored.
+
+/// Ids of up to `limit` tracks whose title, artist, album, genre or composer
+/// contain words starting with every word of `query`, best matches first.
+/// Japanese titles and names also match by their romaji or hiragana reading.
+Future<Int32List> searchTracks(
+        {required String query, required int limit, dynamic hint}) =>
+    RustLib.instance.api.searchTracks(query: query, limit: limit, hint: hint);
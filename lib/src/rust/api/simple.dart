@@ -6,8 +6,7 @@
 import '../frb_generated.dart';
 import '../model.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
-
-// The type `ParsedTrack` is not used by any `pub` functions, thus it is ignored.
+import 'scan.dart';
 
 String getDbUrl({dynamic hint}) => RustLib.instance.api.getDbUrl(hint: hint);
 
@@ -26,70 +25,362 @@ void initializeApp({dynamic hint}) =>
 void initializeDb({dynamic hint}) =>
     RustLib.instance.api.initializeDb(hint: hint);
 
-Future<void> syncDirectory({required String mountPoint, dynamic hint}) =>
+/// Walks `mount_point` and imports its music files into the library. Fails
+/// if `mount_point` is inside a library root or contains one.
+///
+/// See [`super::scan::scan_directory`] for a version that reports progress and can be
+/// cancelled.
+Future<SyncSummary> syncDirectory({required String mountPoint, dynamic hint}) =>
     RustLib.instance.api.syncDirectory(mountPoint: mountPoint, hint: hint);
 
-Future<List<TrackDTO>> getAllTracks({dynamic hint}) =>
-    RustLib.instance.api.getAllTracks(hint: hint);
+Future<List<TrackDTO>> getAllTracks(
+        {required bool hideOffline, dynamic hint}) =>
+    RustLib.instance.api.getAllTracks(hideOffline: hideOffline, hint: hint);
+
+/// One page of the library in id order, `limit` tracks starting at `offset`.
+Future<TrackPage> getTrackPage(
+        {required int offset,
+        required int limit,
+        required bool hideOffline,
+        dynamic hint}) =>
+    RustLib.instance.api.getTrackPage(
+        offset: offset, limit: limit, hideOffline: hideOffline, hint: hint);
+
+/// The tracks with the given ids, in the order of `ids`. Ids of tracks that
+/// no longer exist are skipped.
+///
+/// Meant to be paired with the sorted id lists to load only the rows a list
+/// is showing.
+Future<List<TrackDTO>> getTracksByIds({required List<int> ids, dynamic hint}) =>
+    RustLib.instance.api.getTracksByIds(ids: ids, hint: hint);
+
+Future<Int32List> getAllTrackIdsSortedByTitle(
+        {required bool hideOffline, dynamic hint}) =>
+    RustLib.instance.api
+        .getAllTrackIdsSortedByTitle(hideOffline: hideOffline, hint: hint);
+
+/// Track ids grouped by album artist, falling back to the track artist for
+/// tracks without an album.
+Future<Int32List> getAllTrackIdsSortedByArtist(
+        {required bool hideOffline, dynamic hint}) =>
+    RustLib.instance.api
+        .getAllTrackIdsSortedByArtist(hideOffline: hideOffline, hint: hint);
 
-Future<Int32List> getAllTrackIdsSortedByTitle({dynamic hint}) =>
-    RustLib.instance.api.getAllTrackIdsSortedByTitle(hint: hint);
+Future<Int32List> getAllTrackIdsSortedByAlbum(
+        {required bool hideOffline, dynamic hint}) =>
+    RustLib.instance.api
+        .getAllTrackIdsSortedByAlbum(hideOffline: hideOffline, hint: hint);
 
-Future<Int32List> getAllTrackIdsSortedByArtist({dynamic hint}) =>
-    RustLib.instance.api.getAllTrackIdsSortedByArtist(hint: hint);
+Future<Int32List> getAllTrackIdsSortedByDuration(
+        {required bool hideOffline, dynamic hint}) =>
+    RustLib.instance.api
+        .getAllTrackIdsSortedByDuration(hideOffline: hideOffline, hint: hint);
 
-Future<Int32List> getAllTrackIdsSortedByAlbum({dynamic hint}) =>
-    RustLib.instance.api.getAllTrackIdsSortedByAlbum(hint: hint);
+/// Track ids matching `filter`, sorted by `sort_by`. Tracks whose property is
+/// unknown come last in either direction.
+Future<Int32List> getTrackIdsByAudioProperties(
+        {required AudioPropertiesFilter filter,
+        required AudioProperty sortBy,
+        required bool descending,
+        required bool hideOffline,
+        dynamic hint}) =>
+    RustLib.instance.api.getTrackIdsByAudioProperties(
+        filter: filter,
+        sortBy: sortBy,
+        descending: descending,
+        hideOffline: hideOffline,
+        hint: hint);
 
-Future<Int32List> getAllTrackIdsSortedByDuration({dynamic hint}) =>
-    RustLib.instance.api.getAllTrackIdsSortedByDuration(hint: hint);
+/// Counts one play of `track_id` and remembers when it happened.
+Future<void> recordTrackPlayed({required int trackId, dynamic hint}) =>
+    RustLib.instance.api.recordTrackPlayed(trackId: trackId, hint: hint);
 
 Future<void> deleteAllTracks({dynamic hint}) =>
     RustLib.instance.api.deleteAllTracks(hint: hint);
 
-Future<List<TrackDTO>> findTrackByAlbum({required int albumId, dynamic hint}) =>
-    RustLib.instance.api.findTrackByAlbum(albumId: albumId, hint: hint);
+Future<List<TrackDTO>> findTrackByAlbum(
+        {required int albumId, required bool hideOffline, dynamic hint}) =>
+    RustLib.instance.api.findTrackByAlbum(
+        albumId: albumId, hideOffline: hideOffline, hint: hint);
+
+/// Distinct release years in the library, newest first.
+Future<Int32List> getAllYears({required bool hideOffline, dynamic hint}) =>
+    RustLib.instance.api.getAllYears(hideOffline: hideOffline, hint: hint);
+
+Future<List<TrackDTO>> findTrackByYear(
+        {required int year, required bool hideOffline, dynamic hint}) =>
+    RustLib.instance.api
+        .findTrackByYear(year: year, hideOffline: hideOffline, hint: hint);
+
+/// Every track `artist_id` is credited on, in any role.
+Future<List<TrackDTO>> findTrackByArtist(
+        {required int artistId, required bool hideOffline, dynamic hint}) =>
+    RustLib.instance.api.findTrackByArtist(
+        artistId: artistId, hideOffline: hideOffline, hint: hint);
+
+/// Every genre with the number of tracks and albums tagged with it, by name.
+Future<List<GenreSummary>> getAllGenres(
+        {required bool hideOffline, dynamic hint}) =>
+    RustLib.instance.api.getAllGenres(hideOffline: hideOffline, hint: hint);
+
+Future<List<TrackDTO>> findTrackByGenre(
+        {required int genreId, required bool hideOffline, dynamic hint}) =>
+    RustLib.instance.api.findTrackByGenre(
+        genreId: genreId, hideOffline: hideOffline, hint: hint);
+
+/// Albums with at least one track tagged with `genre_id`, by the reading of
+/// their name.
+Future<List<Album>> findAlbumByGenre(
+        {required int genreId, required bool hideOffline, dynamic hint}) =>
+    RustLib.instance.api.findAlbumByGenre(
+        genreId: genreId, hideOffline: hideOffline, hint: hint);
 
 Future<String?> pickDirectory({dynamic hint}) =>
     RustLib.instance.api.pickDirectory(hint: hint);
 
+/// How an artist is credited on a track.
+enum ArtistRole {
+  main,
+  featured,
+  remixer,
+  composer,
+}
+
+/// Technical properties a track listing can be narrowed to. Every set
+/// field must match.
+class AudioPropertiesFilter {
+  final String? codec;
+  final bool? lossless;
+  final int? minBitrate;
+  final int? maxBitrate;
+  final int? minSampleRate;
+  final int? minBitDepth;
+  final int? channels;
+
+  const AudioPropertiesFilter({
+    this.codec,
+    this.lossless,
+    this.minBitrate,
+    this.maxBitrate,
+    this.minSampleRate,
+    this.minBitDepth,
+    this.channels,
+  });
+
+  @override
+  int get hashCode =>
+      codec.hashCode ^
+      lossless.hashCode ^
+      minBitrate.hashCode ^
+      maxBitrate.hashCode ^
+      minSampleRate.hashCode ^
+      minBitDepth.hashCode ^
+      channels.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AudioPropertiesFilter &&
+          runtimeType == other.runtimeType &&
+          codec == other.codec &&
+          lossless == other.lossless &&
+          minBitrate == other.minBitrate &&
+          maxBitrate == other.maxBitrate &&
+          minSampleRate == other.minSampleRate &&
+          minBitDepth == other.minBitDepth &&
+          channels == other.channels;
+}
+
+enum AudioProperty {
+  bitrate,
+  sampleRate,
+  bitDepth,
+  channels,
+  fileSize,
+}
+
+class GenreSummary {
+  final int id;
+  final String name;
+  final int trackCount;
+  final int albumCount;
+
+  const GenreSummary({
+    required this.id,
+    required this.name,
+    required this.trackCount,
+    required this.albumCount,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^ name.hashCode ^ trackCount.hashCode ^ albumCount.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GenreSummary &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          name == other.name &&
+          trackCount == other.trackCount &&
+          albumCount == other.albumCount;
+}
+
+class TrackArtistDTO {
+  final Artist artist;
+  final ArtistRole role;
+
+  const TrackArtistDTO({
+    required this.artist,
+    required this.role,
+  });
+
+  @override
+  int get hashCode => artist.hashCode ^ role.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TrackArtistDTO &&
+          runtimeType == other.runtimeType &&
+          artist == other.artist &&
+          role == other.role;
+}
+
 class TrackDTO {
   final int id;
   final String? title;
+  /// Romaji and hiragana readings of the title, computed when the track
+  /// was scanned.
+  final String? titleRomaji;
+  final String? titleHiragana;
   final Artist? artist;
   final Album? album;
+  /// The artist the album is credited to, which differs from `artist` on
+  /// compilations and tracks with guests.
+  final Artist? albumArtist;
+  /// Every artist credited on the track, in tag order. `artist` is the
+  /// first of the main artists.
+  final List<TrackArtistDTO> artists;
   final int? number;
   final int? disc;
   final int durationMs;
   final String location;
   final String mountPoint;
   final String? pictureId;
+  /// Whether the library root holding the track is currently reachable.
+  final bool available;
+  final int? year;
+  /// Release date as tagged, e.g. `2003` or `2003-07-21`.
+  final String? date;
+  final String? originalDate;
+  /// The track's genres, joined with `"; "`.
+  final String? genre;
+  final List<Genre> genres;
+  /// Every composer tag value, joined with `"; "`.
+  final String? composer;
+  final String? comment;
+  final int? trackTotal;
+  final int? discTotal;
+  final double? bpm;
+  final String? isrc;
+  final String? label;
+  /// Lowercase codec name, e.g. `flac`, `alac`, `mp3` or `pcm`.
+  final String? codec;
+  /// Audio bitrate in kbit/s.
+  final int? bitrate;
+  /// Sample rate in Hz.
+  final int? sampleRate;
+  final int? bitDepth;
+  final int? channels;
+  /// Size of the file in bytes.
+  final int fileSize;
+  final bool lossless;
+  /// Title of the track's disc, from the `DISCSUBTITLE` tag.
+  final String? discSubtitle;
+  final int playCount;
+  final DateTime? lastPlayedAt;
 
   const TrackDTO({
     required this.id,
     this.title,
+    this.titleRomaji,
+    this.titleHiragana,
     this.artist,
     this.album,
+    this.albumArtist,
+    required this.artists,
     this.number,
     this.disc,
     required this.durationMs,
     required this.location,
     required this.mountPoint,
     this.pictureId,
+    required this.available,
+    this.year,
+    this.date,
+    this.originalDate,
+    this.genre,
+    required this.genres,
+    this.composer,
+    this.comment,
+    this.trackTotal,
+    this.discTotal,
+    this.bpm,
+    this.isrc,
+    this.label,
+    this.codec,
+    this.bitrate,
+    this.sampleRate,
+    this.bitDepth,
+    this.channels,
+    required this.fileSize,
+    required this.lossless,
+    this.discSubtitle,
+    required this.playCount,
+    this.lastPlayedAt,
   });
 
   @override
   int get hashCode =>
       id.hashCode ^
       title.hashCode ^
+      titleRomaji.hashCode ^
+      titleHiragana.hashCode ^
       artist.hashCode ^
       album.hashCode ^
+      albumArtist.hashCode ^
+      artists.hashCode ^
       number.hashCode ^
       disc.hashCode ^
       durationMs.hashCode ^
       location.hashCode ^
       mountPoint.hashCode ^
-      pictureId.hashCode;
+      pictureId.hashCode ^
+      available.hashCode ^
+      year.hashCode ^
+      date.hashCode ^
+      originalDate.hashCode ^
+      genre.hashCode ^
+      genres.hashCode ^
+      composer.hashCode ^
+      comment.hashCode ^
+      trackTotal.hashCode ^
+      discTotal.hashCode ^
+      bpm.hashCode ^
+      isrc.hashCode ^
+      label.hashCode ^
+      codec.hashCode ^
+      bitrate.hashCode ^
+      sampleRate.hashCode ^
+      bitDepth.hashCode ^
+      channels.hashCode ^
+      fileSize.hashCode ^
+      lossless.hashCode ^
+      discSubtitle.hashCode ^
+      playCount.hashCode ^
+      lastPlayedAt.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -98,12 +389,61 @@ class TrackDTO {
           runtimeType == other.runtimeType &&
           id == other.id &&
           title == other.title &&
+          titleRomaji == other.titleRomaji &&
+          titleHiragana == other.titleHiragana &&
           artist == other.artist &&
           album == other.album &&
+          albumArtist == other.albumArtist &&
+          artists == other.artists &&
           number == other.number &&
           disc == other.disc &&
           durationMs == other.durationMs &&
           location == other.location &&
           mountPoint == other.mountPoint &&
-          pictureId == other.pictureId;
+          pictureId == other.pictureId &&
+          available == other.available &&
+          year == other.year &&
+          date == other.date &&
+          originalDate == other.originalDate &&
+          genre == other.genre &&
+          genres == other.genres &&
+          composer == other.composer &&
+          comment == other.comment &&
+          trackTotal == other.trackTotal &&
+          discTotal == other.discTotal &&
+          bpm == other.bpm &&
+          isrc == other.isrc &&
+          label == other.label &&
+          codec == other.codec &&
+          bitrate == other.bitrate &&
+          sampleRate == other.sampleRate &&
+          bitDepth == other.bitDepth &&
+          channels == other.channels &&
+          fileSize == other.fileSize &&
+          lossless == other.lossless &&
+          discSubtitle == other.discSubtitle &&
+          playCount == other.playCount &&
+          lastPlayedAt == other.lastPlayedAt;
+}
+
+class TrackPage {
+  final List<TrackDTO> tracks;
+  /// Number of tracks across all pages.
+  final int total;
+
+  const TrackPage({
+    required this.tracks,
+    required this.total,
+  });
+
+  @override
+  int get hashCode => tracks.hashCode ^ total.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TrackPage &&
+          runtimeType == other.runtimeType &&
+          tracks == other.tracks &&
+          total == other.total;
 }
@@ -8,6 +8,8 @@ import '../model.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'simple.dart';
 
+/// Whether the title, artist or album of `track` contains `query`, as
+/// written or by the readings stored when the track was scanned.
 bool trackQueryFilterCondition(
         {required String query, required TrackDTO track, dynamic hint}) =>
     RustLib.instance.api
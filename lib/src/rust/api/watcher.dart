@@ -0,0 +1,70 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.28.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// Starts watching every enabled library root and applies file creations,
+/// modifications, renames and deletions to the `track` table as they happen.
+/// Each applied batch is reported through `sink`.
+///
+/// Calling this again replaces the previous watcher, so it can be used to pick
+/// up roots that were added, removed, enabled or disabled.
+Stream<LibraryChangeEvent> watchLibrary({dynamic hint}) =>
+    RustLib.instance.api.watchLibrary(hint: hint);
+
+/// Stops the watcher started by [`watch_library`], if any.
+Future<void> unwatchLibrary({dynamic hint}) =>
+    RustLib.instance.api.unwatchLibrary(hint: hint);
+
+/// Changes applied to the library from one batch of filesystem events.
+class LibraryChangeEvent {
+  /// Locations of tracks that were added or re-parsed.
+  final List<String> upserted;
+  /// Locations of tracks that were removed.
+  final List<String> removed;
+  /// Tracks that kept their id but changed location.
+  final List<MovedTrack> moved;
+
+  const LibraryChangeEvent({
+    required this.upserted,
+    required this.removed,
+    required this.moved,
+  });
+
+  @override
+  int get hashCode => upserted.hashCode ^ removed.hashCode ^ moved.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LibraryChangeEvent &&
+          runtimeType == other.runtimeType &&
+          upserted == other.upserted &&
+          removed == other.removed &&
+          moved == other.moved;
+}
+
+/// A track whose file was renamed or moved while being watched.
+class MovedTrack {
+  final String from;
+  final String to;
+
+  const MovedTrack({
+    required this.from,
+    required this.to,
+  });
+
+  @override
+  int get hashCode => from.hashCode ^ to.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MovedTrack &&
+          runtimeType == other.runtimeType &&
+          from == other.from &&
+          to == other.to;
+}
@@ -3,8 +3,17 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/browse.dart';
+import 'api/config.dart';
+import 'api/fuzzy_search.dart';
+import 'api/library.dart';
+import 'api/query.dart';
+import 'api/query_language.dart';
+import 'api/scan.dart';
+import 'api/search.dart';
 import 'api/simple.dart';
 import 'api/utils.dart';
+import 'api/watcher.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.io.dart' if (dart.library.html) 'frb_generated.web.dart';
@@ -66,21 +75,112 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 }
 
 abstract class RustLibApi extends BaseApi {
+  Future<AlbumDetail?> getAlbumDetail(
+      {required int albumId, required bool hideOffline, dynamic hint});
+
+  Future<List<AlbumSummary>> getAllAlbums(
+      {required bool hideOffline, dynamic hint});
+
+  Future<List<ArtistSummary>> getAllArtists(
+      {required bool hideOffline, dynamic hint});
+
+  Future<ArtistDetail?> getArtistDetail(
+      {required int artistId, required bool hideOffline, dynamic hint});
+
+  Config getConfig({dynamic hint});
+
+  Config getDefaultConfig({dynamic hint});
+
+  Future<void> setConfig({required Config config, dynamic hint});
+
+  Future<List<TrackSearchResult>> fuzzySearchTracks(
+      {required String query,
+      required int limit,
+      required bool hideOffline,
+      dynamic hint});
+
+  Future<LibraryRoot> addLibraryRoot({required String path, dynamic hint});
+
+  Future<List<LibraryRootAvailability>> getLibraryRootAvailability(
+      {dynamic hint});
+
+  Future<List<LibraryRoot>> getLibraryRoots({dynamic hint});
+
+  Future<LibraryRoot> relocateLibraryRoot(
+      {required int id, required String newPath, dynamic hint});
+
+  Future<void> removeLibraryRoot({required int id, dynamic hint});
+
+  Future<SyncSummary> rescanLibraryRoot({required int id, dynamic hint});
+
+  Future<List<SyncSummary>> rescanLibraryRoots({dynamic hint});
+
+  Future<void> setLibraryRootEnabled(
+      {required int id, required bool enabled, dynamic hint});
+
+  Future<Int32List> queryTrackIds({required TrackQuery query, dynamic hint});
+
+  Future<List<TrackDTO>> queryTracks({required TrackQuery query, dynamic hint});
+
+  List<TrackFilter> parseTrackFilters({required String query, dynamic hint});
+
+  void scanCancelTokenCancel({required ScanCancelToken that, dynamic hint});
+
+  bool scanCancelTokenIsCancelled(
+      {required ScanCancelToken that, dynamic hint});
+
+  ScanCancelToken scanCancelTokenNew({dynamic hint});
+
+  Future<void> clearScanErrors({dynamic hint});
+
+  Future<List<ScanError>> getScanErrors({dynamic hint});
+
+  Stream<ScanEvent> scanDirectory(
+      {required String mountPoint,
+      required ScanCancelToken cancelToken,
+      dynamic hint});
+
+  Future<Int32List> searchTracks(
+      {required String query, required int limit, dynamic hint});
+
   Future<void> deleteAllTracks({dynamic hint});
 
-  Future<List<TrackDTO>> findTrackByAlbum({required int albumId, dynamic hint});
+  Future<List<Album>> findAlbumByGenre(
+      {required int genreId, required bool hideOffline, dynamic hint});
+
+  Future<List<TrackDTO>> findTrackByAlbum(
+      {required int albumId, required bool hideOffline, dynamic hint});
+
+  Future<List<TrackDTO>> findTrackByArtist(
+      {required int artistId, required bool hideOffline, dynamic hint});
+
+  Future<List<TrackDTO>> findTrackByGenre(
+      {required int genreId, required bool hideOffline, dynamic hint});
+
+  Future<List<TrackDTO>> findTrackByYear(
+      {required int year, required bool hideOffline, dynamic hint});
 
   Future<void> frbInit({dynamic hint});
 
-  Future<Int32List> getAllTrackIdsSortedByAlbum({dynamic hint});
+  Future<List<GenreSummary>> getAllGenres(
+      {required bool hideOffline, dynamic hint});
+
+  Future<Int32List> getAllTrackIdsSortedByAlbum(
+      {required bool hideOffline, dynamic hint});
 
-  Future<Int32List> getAllTrackIdsSortedByArtist({dynamic hint});
+  Future<Int32List> getAllTrackIdsSortedByArtist(
+      {required bool hideOffline, dynamic hint});
 
-  Future<Int32List> getAllTrackIdsSortedByDuration({dynamic hint});
+  Future<Int32List> getAllTrackIdsSortedByDuration(
+      {required bool hideOffline, dynamic hint});
 
-  Future<Int32List> getAllTrackIdsSortedByTitle({dynamic hint});
+  Future<Int32List> getAllTrackIdsSortedByTitle(
+      {required bool hideOffline, dynamic hint});
 
-  Future<List<TrackDTO>> getAllTracks({dynamic hint});
+  Future<List<TrackDTO>> getAllTracks(
+      {required bool hideOffline, dynamic hint});
+
+  Future<Int32List> getAllYears({required bool hideOffline, dynamic hint});
 
   String getCachePath({dynamic hint});
 
@@ -90,18 +190,48 @@ abstract class RustLibApi extends BaseApi {
 
   String getDbUrl({dynamic hint});
 
+  Future<Int32List> getTrackIdsByAudioProperties(
+      {required AudioPropertiesFilter filter,
+      required AudioProperty sortBy,
+      required bool descending,
+      required bool hideOffline,
+      dynamic hint});
+
+  Future<TrackPage> getTrackPage(
+      {required int offset,
+      required int limit,
+      required bool hideOffline,
+      dynamic hint});
+
+  Future<List<TrackDTO>> getTracksByIds({required List<int> ids, dynamic hint});
+
   void initializeApp({dynamic hint});
 
   void initializeDb({dynamic hint});
 
   Future<String?> pickDirectory({dynamic hint});
 
-  Future<void> syncDirectory({required String mountPoint, dynamic hint});
+  Future<void> recordTrackPlayed({required int trackId, dynamic hint});
+
+  Future<SyncSummary> syncDirectory({required String mountPoint, dynamic hint});
 
   String durationToString({required Duration duration, dynamic hint});
 
   bool trackQueryFilterCondition(
       {required String query, required TrackDTO track, dynamic hint});
+
+  Future<void> unwatchLibrary({dynamic hint});
+
+  Stream<LibraryChangeEvent> watchLibrary({dynamic hint});
+
+  RustArcIncrementStrongCountFnType
+      get rust_arc_increment_strong_count_ScanCancelToken;
+
+  RustArcDecrementStrongCountFnType
+      get rust_arc_decrement_strong_count_ScanCancelToken;
+
+  CrossPlatformFinalizerArg
+      get rust_arc_decrement_strong_count_ScanCancelTokenPtr;
 }
 
 class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
@@ -113,952 +243,4349 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   });
 
   @override
-  Future<void> deleteAllTracks({dynamic hint}) {
+  Future<AlbumDetail?> getAlbumDetail(
+      {required int albumId, required bool hideOffline, dynamic hint}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_i_32(albumId, serializer);
+        sse_encode_bool(hideOffline, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 14, port: port_);
+            funcId: 3, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData: sse_decode_opt_box_autoadd_album_detail,
         decodeErrorData: null,
       ),
-      constMeta: kDeleteAllTracksConstMeta,
-      argValues: [],
+      constMeta: kGetAlbumDetailConstMeta,
+      argValues: [albumId, hideOffline],
       apiImpl: this,
       hint: hint,
     ));
   }
 
-  TaskConstMeta get kDeleteAllTracksConstMeta => const TaskConstMeta(
-        debugName: "delete_all_tracks",
-        argNames: [],
+  TaskConstMeta get kGetAlbumDetailConstMeta => const TaskConstMeta(
+        debugName: "get_album_detail",
+        argNames: ["albumId", "hideOffline"],
       );
 
   @override
-  Future<List<TrackDTO>> findTrackByAlbum(
-      {required int albumId, dynamic hint}) {
+  Future<List<AlbumSummary>> getAllAlbums(
+      {required bool hideOffline, dynamic hint}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_i_32(albumId, serializer);
+        sse_encode_bool(hideOffline, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 15, port: port_);
+            funcId: 1, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_list_track_dto,
+        decodeSuccessData: sse_decode_list_album_summary,
         decodeErrorData: null,
       ),
-      constMeta: kFindTrackByAlbumConstMeta,
-      argValues: [albumId],
+      constMeta: kGetAllAlbumsConstMeta,
+      argValues: [hideOffline],
       apiImpl: this,
       hint: hint,
     ));
   }
 
-  TaskConstMeta get kFindTrackByAlbumConstMeta => const TaskConstMeta(
-        debugName: "find_track_by_album",
-        argNames: ["albumId"],
+  TaskConstMeta get kGetAllAlbumsConstMeta => const TaskConstMeta(
+        debugName: "get_all_albums",
+        argNames: ["hideOffline"],
       );
 
   @override
-  Future<void> frbInit({dynamic hint}) {
+  Future<List<ArtistSummary>> getAllArtists(
+      {required bool hideOffline, dynamic hint}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_bool(hideOffline, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 5, port: port_);
+            funcId: 2, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData: sse_decode_list_artist_summary,
         decodeErrorData: null,
       ),
-      constMeta: kFrbInitConstMeta,
-      argValues: [],
+      constMeta: kGetAllArtistsConstMeta,
+      argValues: [hideOffline],
       apiImpl: this,
       hint: hint,
     ));
   }
 
-  TaskConstMeta get kFrbInitConstMeta => const TaskConstMeta(
-        debugName: "frb_init",
-        argNames: [],
+  TaskConstMeta get kGetAllArtistsConstMeta => const TaskConstMeta(
+        debugName: "get_all_artists",
+        argNames: ["hideOffline"],
       );
 
   @override
-  Future<Int32List> getAllTrackIdsSortedByAlbum({dynamic hint}) {
+  Future<ArtistDetail?> getArtistDetail(
+      {required int artistId, required bool hideOffline, dynamic hint}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_i_32(artistId, serializer);
+        sse_encode_bool(hideOffline, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 12, port: port_);
+            funcId: 4, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_list_prim_i_32_strict,
+        decodeSuccessData: sse_decode_opt_box_autoadd_artist_detail,
         decodeErrorData: null,
       ),
-      constMeta: kGetAllTrackIdsSortedByAlbumConstMeta,
+      constMeta: kGetArtistDetailConstMeta,
+      argValues: [artistId, hideOffline],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kGetArtistDetailConstMeta => const TaskConstMeta(
+        debugName: "get_artist_detail",
+        argNames: ["artistId", "hideOffline"],
+      );
+
+  @override
+  Config getConfig({dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_config,
+        decodeErrorData: null,
+      ),
+      constMeta: kGetConfigConstMeta,
       argValues: [],
       apiImpl: this,
       hint: hint,
     ));
   }
 
-  TaskConstMeta get kGetAllTrackIdsSortedByAlbumConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_all_track_ids_sorted_by_album",
+  TaskConstMeta get kGetConfigConstMeta => const TaskConstMeta(
+        debugName: "get_config",
         argNames: [],
       );
 
   @override
-  Future<Int32List> getAllTrackIdsSortedByArtist({dynamic hint}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
+  Config getDefaultConfig({dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 11, port: port_);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_list_prim_i_32_strict,
+        decodeSuccessData: sse_decode_config,
         decodeErrorData: null,
       ),
-      constMeta: kGetAllTrackIdsSortedByArtistConstMeta,
+      constMeta: kGetDefaultConfigConstMeta,
       argValues: [],
       apiImpl: this,
       hint: hint,
     ));
   }
 
-  TaskConstMeta get kGetAllTrackIdsSortedByArtistConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_all_track_ids_sorted_by_artist",
+  TaskConstMeta get kGetDefaultConfigConstMeta => const TaskConstMeta(
+        debugName: "get_default_config",
         argNames: [],
       );
 
   @override
-  Future<Int32List> getAllTrackIdsSortedByDuration({dynamic hint}) {
+  Future<void> setConfig({required Config config, dynamic hint}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_config(config, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 13, port: port_);
+            funcId: 6, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_list_prim_i_32_strict,
-        decodeErrorData: null,
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
       ),
-      constMeta: kGetAllTrackIdsSortedByDurationConstMeta,
-      argValues: [],
+      constMeta: kSetConfigConstMeta,
+      argValues: [config],
       apiImpl: this,
       hint: hint,
     ));
   }
 
-  TaskConstMeta get kGetAllTrackIdsSortedByDurationConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_all_track_ids_sorted_by_duration",
-        argNames: [],
+  TaskConstMeta get kSetConfigConstMeta => const TaskConstMeta(
+        debugName: "set_config",
+        argNames: ["config"],
       );
 
   @override
-  Future<Int32List> getAllTrackIdsSortedByTitle({dynamic hint}) {
+  Future<List<TrackSearchResult>> fuzzySearchTracks(
+      {required String query,
+      required int limit,
+      required bool hideOffline,
+      dynamic hint}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(query, serializer);
+        sse_encode_u_32(limit, serializer);
+        sse_encode_bool(hideOffline, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 10, port: port_);
+            funcId: 8, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_list_prim_i_32_strict,
+        decodeSuccessData: sse_decode_list_track_search_result,
         decodeErrorData: null,
       ),
-      constMeta: kGetAllTrackIdsSortedByTitleConstMeta,
-      argValues: [],
+      constMeta: kFuzzySearchTracksConstMeta,
+      argValues: [query, limit, hideOffline],
       apiImpl: this,
       hint: hint,
     ));
   }
 
-  TaskConstMeta get kGetAllTrackIdsSortedByTitleConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_all_track_ids_sorted_by_title",
-        argNames: [],
+  TaskConstMeta get kFuzzySearchTracksConstMeta => const TaskConstMeta(
+        debugName: "fuzzy_search_tracks",
+        argNames: ["query", "limit", "hideOffline"],
       );
 
   @override
-  Future<List<TrackDTO>> getAllTracks({dynamic hint}) {
+  Future<LibraryRoot> addLibraryRoot({required String path, dynamic hint}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 9, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_list_track_dto,
-        decodeErrorData: null,
+        decodeSuccessData: sse_decode_library_root,
+        decodeErrorData: sse_decode_String,
       ),
-      constMeta: kGetAllTracksConstMeta,
-      argValues: [],
+      constMeta: kAddLibraryRootConstMeta,
+      argValues: [path],
       apiImpl: this,
       hint: hint,
     ));
   }
 
-  TaskConstMeta get kGetAllTracksConstMeta => const TaskConstMeta(
-        debugName: "get_all_tracks",
-        argNames: [],
+  TaskConstMeta get kAddLibraryRootConstMeta => const TaskConstMeta(
+        debugName: "add_library_root",
+        argNames: ["path"],
       );
 
   @override
-  String getCachePath({dynamic hint}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
+  Future<List<LibraryRootAvailability>> getLibraryRootAvailability(
+      {dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3)!;
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 11, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
+        decodeSuccessData: sse_decode_list_library_root_availability,
         decodeErrorData: null,
       ),
-      constMeta: kGetCachePathConstMeta,
+      constMeta: kGetLibraryRootAvailabilityConstMeta,
       argValues: [],
       apiImpl: this,
       hint: hint,
     ));
   }
 
-  TaskConstMeta get kGetCachePathConstMeta => const TaskConstMeta(
-        debugName: "get_cache_path",
+  TaskConstMeta get kGetLibraryRootAvailabilityConstMeta => const TaskConstMeta(
+        debugName: "get_library_root_availability",
         argNames: [],
       );
 
   @override
-  String getConfigPath({dynamic hint}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
+  Future<List<LibraryRoot>> getLibraryRoots({dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2)!;
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 10, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
+        decodeSuccessData: sse_decode_list_library_root,
         decodeErrorData: null,
       ),
-      constMeta: kGetConfigPathConstMeta,
+      constMeta: kGetLibraryRootsConstMeta,
       argValues: [],
       apiImpl: this,
       hint: hint,
     ));
   }
 
-  TaskConstMeta get kGetConfigPathConstMeta => const TaskConstMeta(
-        debugName: "get_config_path",
+  TaskConstMeta get kGetLibraryRootsConstMeta => const TaskConstMeta(
+        debugName: "get_library_roots",
         argNames: [],
       );
 
   @override
-  String getDataPath({dynamic hint}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
+  Future<LibraryRoot> relocateLibraryRoot(
+      {required int id, required String newPath, dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
+        sse_encode_i_32(id, serializer);
+        sse_encode_String(newPath, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 16, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: null,
+        decodeSuccessData: sse_decode_library_root,
+        decodeErrorData: sse_decode_String,
       ),
-      constMeta: kGetDataPathConstMeta,
-      argValues: [],
+      constMeta: kRelocateLibraryRootConstMeta,
+      argValues: [id, newPath],
       apiImpl: this,
       hint: hint,
     ));
   }
 
-  TaskConstMeta get kGetDataPathConstMeta => const TaskConstMeta(
-        debugName: "get_data_path",
-        argNames: [],
+  TaskConstMeta get kRelocateLibraryRootConstMeta => const TaskConstMeta(
+        debugName: "relocate_library_root",
+        argNames: ["id", "newPath"],
       );
 
   @override
-  String getDbUrl({dynamic hint}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
+  Future<void> removeLibraryRoot({required int id, dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1)!;
+        sse_encode_i_32(id, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 12, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: null,
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
       ),
-      constMeta: kGetDbUrlConstMeta,
-      argValues: [],
+      constMeta: kRemoveLibraryRootConstMeta,
+      argValues: [id],
       apiImpl: this,
       hint: hint,
     ));
   }
 
-  TaskConstMeta get kGetDbUrlConstMeta => const TaskConstMeta(
-        debugName: "get_db_url",
-        argNames: [],
+  TaskConstMeta get kRemoveLibraryRootConstMeta => const TaskConstMeta(
+        debugName: "remove_library_root",
+        argNames: ["id"],
       );
 
   @override
-  void initializeApp({dynamic hint}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
+  Future<SyncSummary> rescanLibraryRoot({required int id, dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
+        sse_encode_i_32(id, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 14, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData: sse_decode_sync_summary,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kRescanLibraryRootConstMeta,
+      argValues: [id],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kRescanLibraryRootConstMeta => const TaskConstMeta(
+        debugName: "rescan_library_root",
+        argNames: ["id"],
+      );
+
+  @override
+  Future<List<SyncSummary>> rescanLibraryRoots({dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 15, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_sync_summary,
         decodeErrorData: null,
       ),
-      constMeta: kInitializeAppConstMeta,
+      constMeta: kRescanLibraryRootsConstMeta,
       argValues: [],
       apiImpl: this,
       hint: hint,
     ));
   }
 
-  TaskConstMeta get kInitializeAppConstMeta => const TaskConstMeta(
-        debugName: "initialize_app",
+  TaskConstMeta get kRescanLibraryRootsConstMeta => const TaskConstMeta(
+        debugName: "rescan_library_roots",
         argNames: [],
       );
 
   @override
-  void initializeDb({dynamic hint}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
+  Future<void> setLibraryRootEnabled(
+      {required int id, required bool enabled, dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
+        sse_encode_i_32(id, serializer);
+        sse_encode_bool(enabled, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 13, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
         decodeErrorData: null,
       ),
-      constMeta: kInitializeDbConstMeta,
-      argValues: [],
+      constMeta: kSetLibraryRootEnabledConstMeta,
+      argValues: [id, enabled],
       apiImpl: this,
       hint: hint,
     ));
   }
 
-  TaskConstMeta get kInitializeDbConstMeta => const TaskConstMeta(
-        debugName: "initialize_db",
-        argNames: [],
+  TaskConstMeta get kSetLibraryRootEnabledConstMeta => const TaskConstMeta(
+        debugName: "set_library_root_enabled",
+        argNames: ["id", "enabled"],
       );
 
   @override
-  Future<String?> pickDirectory({dynamic hint}) {
+  Future<Int32List> queryTrackIds({required TrackQuery query, dynamic hint}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_track_query(query, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 16, port: port_);
+            funcId: 17, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_opt_String,
+        decodeSuccessData: sse_decode_list_prim_i_32_strict,
         decodeErrorData: null,
       ),
-      constMeta: kPickDirectoryConstMeta,
-      argValues: [],
+      constMeta: kQueryTrackIdsConstMeta,
+      argValues: [query],
       apiImpl: this,
       hint: hint,
     ));
   }
 
-  TaskConstMeta get kPickDirectoryConstMeta => const TaskConstMeta(
-        debugName: "pick_directory",
-        argNames: [],
+  TaskConstMeta get kQueryTrackIdsConstMeta => const TaskConstMeta(
+        debugName: "query_track_ids",
+        argNames: ["query"],
       );
 
   @override
-  Future<void> syncDirectory({required String mountPoint, dynamic hint}) {
+  Future<List<TrackDTO>> queryTracks(
+      {required TrackQuery query, dynamic hint}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(mountPoint, serializer);
+        sse_encode_box_autoadd_track_query(query, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 8, port: port_);
+            funcId: 18, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData: sse_decode_list_track_dto,
         decodeErrorData: null,
       ),
-      constMeta: kSyncDirectoryConstMeta,
-      argValues: [mountPoint],
+      constMeta: kQueryTracksConstMeta,
+      argValues: [query],
       apiImpl: this,
       hint: hint,
     ));
   }
 
-  TaskConstMeta get kSyncDirectoryConstMeta => const TaskConstMeta(
-        debugName: "sync_directory",
-        argNames: ["mountPoint"],
+  TaskConstMeta get kQueryTracksConstMeta => const TaskConstMeta(
+        debugName: "query_tracks",
+        argNames: ["query"],
       );
 
   @override
-  String durationToString({required Duration duration, dynamic hint}) {
+  List<TrackFilter> parseTrackFilters({required String query, dynamic hint}) {
     return handler.executeSync(SyncTask(
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Chrono_Duration(duration, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18)!;
+        sse_encode_String(query, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: null,
+        decodeSuccessData: sse_decode_list_track_filter,
+        decodeErrorData: sse_decode_query_parse_error,
       ),
-      constMeta: kDurationToStringConstMeta,
-      argValues: [duration],
+      constMeta: kParseTrackFiltersConstMeta,
+      argValues: [query],
       apiImpl: this,
       hint: hint,
     ));
   }
 
-  TaskConstMeta get kDurationToStringConstMeta => const TaskConstMeta(
-        debugName: "duration_to_string",
-        argNames: ["duration"],
+  TaskConstMeta get kParseTrackFiltersConstMeta => const TaskConstMeta(
+        debugName: "parse_track_filters",
+        argNames: ["query"],
       );
 
   @override
-  bool trackQueryFilterCondition(
-      {required String query, required TrackDTO track, dynamic hint}) {
+  void scanCancelTokenCancel({required ScanCancelToken that, dynamic hint}) {
     return handler.executeSync(SyncTask(
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(query, serializer);
-        sse_encode_box_autoadd_track_dto(track, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(
+            that, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
+        decodeSuccessData: sse_decode_unit,
         decodeErrorData: null,
       ),
-      constMeta: kTrackQueryFilterConditionConstMeta,
-      argValues: [query, track],
+      constMeta: kScanCancelTokenCancelConstMeta,
+      argValues: [that],
       apiImpl: this,
       hint: hint,
     ));
   }
 
-  TaskConstMeta get kTrackQueryFilterConditionConstMeta => const TaskConstMeta(
-        debugName: "track_query_filter_condition",
-        argNames: ["query", "track"],
+  TaskConstMeta get kScanCancelTokenCancelConstMeta => const TaskConstMeta(
+        debugName: "ScanCancelToken_cancel",
+        argNames: ["that"],
       );
 
-  @protected
+  @override
+  bool scanCancelTokenIsCancelled(
+      {required ScanCancelToken that, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(
+            that, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kScanCancelTokenIsCancelledConstMeta,
+      argValues: [that],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kScanCancelTokenIsCancelledConstMeta => const TaskConstMeta(
+        debugName: "ScanCancelToken_is_cancelled",
+        argNames: ["that"],
+      );
+
+  @override
+  ScanCancelToken scanCancelTokenNew({dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData:
+            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken,
+        decodeErrorData: null,
+      ),
+      constMeta: kScanCancelTokenNewConstMeta,
+      argValues: [],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kScanCancelTokenNewConstMeta => const TaskConstMeta(
+        debugName: "ScanCancelToken_new",
+        argNames: [],
+      );
+
+  @override
+  Future<void> clearScanErrors({dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 21, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kClearScanErrorsConstMeta,
+      argValues: [],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kClearScanErrorsConstMeta => const TaskConstMeta(
+        debugName: "clear_scan_errors",
+        argNames: [],
+      );
+
+  @override
+  Future<List<ScanError>> getScanErrors({dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 20, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_scan_error,
+        decodeErrorData: null,
+      ),
+      constMeta: kGetScanErrorsConstMeta,
+      argValues: [],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kGetScanErrorsConstMeta => const TaskConstMeta(
+        debugName: "get_scan_errors",
+        argNames: [],
+      );
+
+  @override
+  Stream<ScanEvent> scanDirectory(
+      {required String mountPoint,
+      required ScanCancelToken cancelToken,
+      dynamic hint}) {
+    return handler.executeStream(StreamTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(mountPoint, serializer);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(
+            cancelToken, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 22, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_scan_event,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kScanDirectoryConstMeta,
+      argValues: [mountPoint, cancelToken],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kScanDirectoryConstMeta => const TaskConstMeta(
+        debugName: "scan_directory",
+        argNames: ["mountPoint", "cancelToken"],
+      );
+
+  @override
+  Future<Int32List> searchTracks(
+      {required String query, required int limit, dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(query, serializer);
+        sse_encode_u_32(limit, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 26, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_prim_i_32_strict,
+        decodeErrorData: null,
+      ),
+      constMeta: kSearchTracksConstMeta,
+      argValues: [query, limit],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kSearchTracksConstMeta => const TaskConstMeta(
+        debugName: "search_tracks",
+        argNames: ["query", "limit"],
+      );
+
+  @override
+  Future<void> deleteAllTracks({dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 44, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kDeleteAllTracksConstMeta,
+      argValues: [],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kDeleteAllTracksConstMeta => const TaskConstMeta(
+        debugName: "delete_all_tracks",
+        argNames: [],
+      );
+
+  @override
+  Future<List<Album>> findAlbumByGenre(
+      {required int genreId, required bool hideOffline, dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_i_32(genreId, serializer);
+        sse_encode_bool(hideOffline, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 51, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_album,
+        decodeErrorData: null,
+      ),
+      constMeta: kFindAlbumByGenreConstMeta,
+      argValues: [genreId, hideOffline],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kFindAlbumByGenreConstMeta => const TaskConstMeta(
+        debugName: "find_album_by_genre",
+        argNames: ["genreId", "hideOffline"],
+      );
+
+  @override
+  Future<List<TrackDTO>> findTrackByAlbum(
+      {required int albumId, required bool hideOffline, dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_i_32(albumId, serializer);
+        sse_encode_bool(hideOffline, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 45, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_track_dto,
+        decodeErrorData: null,
+      ),
+      constMeta: kFindTrackByAlbumConstMeta,
+      argValues: [albumId, hideOffline],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kFindTrackByAlbumConstMeta => const TaskConstMeta(
+        debugName: "find_track_by_album",
+        argNames: ["albumId", "hideOffline"],
+      );
+
+  @override
+  Future<List<TrackDTO>> findTrackByArtist(
+      {required int artistId, required bool hideOffline, dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_i_32(artistId, serializer);
+        sse_encode_bool(hideOffline, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 48, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_track_dto,
+        decodeErrorData: null,
+      ),
+      constMeta: kFindTrackByArtistConstMeta,
+      argValues: [artistId, hideOffline],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kFindTrackByArtistConstMeta => const TaskConstMeta(
+        debugName: "find_track_by_artist",
+        argNames: ["artistId", "hideOffline"],
+      );
+
+  @override
+  Future<List<TrackDTO>> findTrackByGenre(
+      {required int genreId, required bool hideOffline, dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_i_32(genreId, serializer);
+        sse_encode_bool(hideOffline, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 50, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_track_dto,
+        decodeErrorData: null,
+      ),
+      constMeta: kFindTrackByGenreConstMeta,
+      argValues: [genreId, hideOffline],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kFindTrackByGenreConstMeta => const TaskConstMeta(
+        debugName: "find_track_by_genre",
+        argNames: ["genreId", "hideOffline"],
+      );
+
+  @override
+  Future<List<TrackDTO>> findTrackByYear(
+      {required int year, required bool hideOffline, dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_i_32(year, serializer);
+        sse_encode_bool(hideOffline, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 47, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_track_dto,
+        decodeErrorData: null,
+      ),
+      constMeta: kFindTrackByYearConstMeta,
+      argValues: [year, hideOffline],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kFindTrackByYearConstMeta => const TaskConstMeta(
+        debugName: "find_track_by_year",
+        argNames: ["year", "hideOffline"],
+      );
+
+  @override
+  Future<void> frbInit({dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 31, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kFrbInitConstMeta,
+      argValues: [],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kFrbInitConstMeta => const TaskConstMeta(
+        debugName: "frb_init",
+        argNames: [],
+      );
+
+  @override
+  Future<List<GenreSummary>> getAllGenres(
+      {required bool hideOffline, dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_bool(hideOffline, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 49, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_genre_summary,
+        decodeErrorData: null,
+      ),
+      constMeta: kGetAllGenresConstMeta,
+      argValues: [hideOffline],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kGetAllGenresConstMeta => const TaskConstMeta(
+        debugName: "get_all_genres",
+        argNames: ["hideOffline"],
+      );
+
+  @override
+  Future<Int32List> getAllTrackIdsSortedByAlbum(
+      {required bool hideOffline, dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_bool(hideOffline, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 40, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_prim_i_32_strict,
+        decodeErrorData: null,
+      ),
+      constMeta: kGetAllTrackIdsSortedByAlbumConstMeta,
+      argValues: [hideOffline],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kGetAllTrackIdsSortedByAlbumConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_all_track_ids_sorted_by_album",
+        argNames: ["hideOffline"],
+      );
+
+  @override
+  Future<Int32List> getAllTrackIdsSortedByArtist(
+      {required bool hideOffline, dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_bool(hideOffline, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 39, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_prim_i_32_strict,
+        decodeErrorData: null,
+      ),
+      constMeta: kGetAllTrackIdsSortedByArtistConstMeta,
+      argValues: [hideOffline],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kGetAllTrackIdsSortedByArtistConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_all_track_ids_sorted_by_artist",
+        argNames: ["hideOffline"],
+      );
+
+  @override
+  Future<Int32List> getAllTrackIdsSortedByDuration(
+      {required bool hideOffline, dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_bool(hideOffline, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 41, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_prim_i_32_strict,
+        decodeErrorData: null,
+      ),
+      constMeta: kGetAllTrackIdsSortedByDurationConstMeta,
+      argValues: [hideOffline],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kGetAllTrackIdsSortedByDurationConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_all_track_ids_sorted_by_duration",
+        argNames: ["hideOffline"],
+      );
+
+  @override
+  Future<Int32List> getAllTrackIdsSortedByTitle(
+      {required bool hideOffline, dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_bool(hideOffline, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 38, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_prim_i_32_strict,
+        decodeErrorData: null,
+      ),
+      constMeta: kGetAllTrackIdsSortedByTitleConstMeta,
+      argValues: [hideOffline],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kGetAllTrackIdsSortedByTitleConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_all_track_ids_sorted_by_title",
+        argNames: ["hideOffline"],
+      );
+
+  @override
+  Future<List<TrackDTO>> getAllTracks(
+      {required bool hideOffline, dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_bool(hideOffline, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 35, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_track_dto,
+        decodeErrorData: null,
+      ),
+      constMeta: kGetAllTracksConstMeta,
+      argValues: [hideOffline],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kGetAllTracksConstMeta => const TaskConstMeta(
+        debugName: "get_all_tracks",
+        argNames: ["hideOffline"],
+      );
+
+  @override
+  Future<Int32List> getAllYears({required bool hideOffline, dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_bool(hideOffline, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 46, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_prim_i_32_strict,
+        decodeErrorData: null,
+      ),
+      constMeta: kGetAllYearsConstMeta,
+      argValues: [hideOffline],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kGetAllYearsConstMeta => const TaskConstMeta(
+        debugName: "get_all_years",
+        argNames: ["hideOffline"],
+      );
+
+  @override
+  String getCachePath({dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kGetCachePathConstMeta,
+      argValues: [],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kGetCachePathConstMeta => const TaskConstMeta(
+        debugName: "get_cache_path",
+        argNames: [],
+      );
+
+  @override
+  String getConfigPath({dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kGetConfigPathConstMeta,
+      argValues: [],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kGetConfigPathConstMeta => const TaskConstMeta(
+        debugName: "get_config_path",
+        argNames: [],
+      );
+
+  @override
+  String getDataPath({dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kGetDataPathConstMeta,
+      argValues: [],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kGetDataPathConstMeta => const TaskConstMeta(
+        debugName: "get_data_path",
+        argNames: [],
+      );
+
+  @override
+  String getDbUrl({dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kGetDbUrlConstMeta,
+      argValues: [],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kGetDbUrlConstMeta => const TaskConstMeta(
+        debugName: "get_db_url",
+        argNames: [],
+      );
+
+  @override
+  Future<Int32List> getTrackIdsByAudioProperties(
+      {required AudioPropertiesFilter filter,
+      required AudioProperty sortBy,
+      required bool descending,
+      required bool hideOffline,
+      dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_audio_properties_filter(filter, serializer);
+        sse_encode_audio_property(sortBy, serializer);
+        sse_encode_bool(descending, serializer);
+        sse_encode_bool(hideOffline, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 42, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_prim_i_32_strict,
+        decodeErrorData: null,
+      ),
+      constMeta: kGetTrackIdsByAudioPropertiesConstMeta,
+      argValues: [filter, sortBy, descending, hideOffline],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kGetTrackIdsByAudioPropertiesConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_track_ids_by_audio_properties",
+        argNames: ["filter", "sortBy", "descending", "hideOffline"],
+      );
+
+  @override
+  Future<TrackPage> getTrackPage(
+      {required int offset,
+      required int limit,
+      required bool hideOffline,
+      dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(offset, serializer);
+        sse_encode_u_32(limit, serializer);
+        sse_encode_bool(hideOffline, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 36, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_track_page,
+        decodeErrorData: null,
+      ),
+      constMeta: kGetTrackPageConstMeta,
+      argValues: [offset, limit, hideOffline],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kGetTrackPageConstMeta => const TaskConstMeta(
+        debugName: "get_track_page",
+        argNames: ["offset", "limit", "hideOffline"],
+      );
+
+  @override
+  Future<List<TrackDTO>> getTracksByIds(
+      {required List<int> ids, dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_prim_i_32_loose(ids, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 37, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_track_dto,
+        decodeErrorData: null,
+      ),
+      constMeta: kGetTracksByIdsConstMeta,
+      argValues: [ids],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kGetTracksByIdsConstMeta => const TaskConstMeta(
+        debugName: "get_tracks_by_ids",
+        argNames: ["ids"],
+      );
+
+  @override
+  void initializeApp({dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kInitializeAppConstMeta,
+      argValues: [],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kInitializeAppConstMeta => const TaskConstMeta(
+        debugName: "initialize_app",
+        argNames: [],
+      );
+
+  @override
+  void initializeDb({dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kInitializeDbConstMeta,
+      argValues: [],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kInitializeDbConstMeta => const TaskConstMeta(
+        debugName: "initialize_db",
+        argNames: [],
+      );
+
+  @override
+  Future<String?> pickDirectory({dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 52, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kPickDirectoryConstMeta,
+      argValues: [],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kPickDirectoryConstMeta => const TaskConstMeta(
+        debugName: "pick_directory",
+        argNames: [],
+      );
+
+  @override
+  Future<void> recordTrackPlayed({required int trackId, dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_i_32(trackId, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 43, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kRecordTrackPlayedConstMeta,
+      argValues: [trackId],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kRecordTrackPlayedConstMeta => const TaskConstMeta(
+        debugName: "record_track_played",
+        argNames: ["trackId"],
+      );
+
+  @override
+  Future<SyncSummary> syncDirectory(
+      {required String mountPoint, dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(mountPoint, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 34, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_sync_summary,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kSyncDirectoryConstMeta,
+      argValues: [mountPoint],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kSyncDirectoryConstMeta => const TaskConstMeta(
+        debugName: "sync_directory",
+        argNames: ["mountPoint"],
+      );
+
+  @override
+  String durationToString({required Duration duration, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Chrono_Duration(duration, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 54)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kDurationToStringConstMeta,
+      argValues: [duration],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kDurationToStringConstMeta => const TaskConstMeta(
+        debugName: "duration_to_string",
+        argNames: ["duration"],
+      );
+
+  @override
+  bool trackQueryFilterCondition(
+      {required String query, required TrackDTO track, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(query, serializer);
+        sse_encode_box_autoadd_track_dto(track, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 53)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kTrackQueryFilterConditionConstMeta,
+      argValues: [query, track],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kTrackQueryFilterConditionConstMeta => const TaskConstMeta(
+        debugName: "track_query_filter_condition",
+        argNames: ["query", "track"],
+      );
+
+  @override
+  Future<void> unwatchLibrary({dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 56, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kUnwatchLibraryConstMeta,
+      argValues: [],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kUnwatchLibraryConstMeta => const TaskConstMeta(
+        debugName: "unwatch_library",
+        argNames: [],
+      );
+
+  @override
+  Stream<LibraryChangeEvent> watchLibrary({dynamic hint}) {
+    return handler.executeStream(StreamTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 55, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_library_change_event,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kWatchLibraryConstMeta,
+      argValues: [],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kWatchLibraryConstMeta => const TaskConstMeta(
+        debugName: "watch_library",
+        argNames: [],
+      );
+
+  RustArcIncrementStrongCountFnType
+      get rust_arc_increment_strong_count_ScanCancelToken => wire
+          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken;
+
+  RustArcDecrementStrongCountFnType
+      get rust_arc_decrement_strong_count_ScanCancelToken => wire
+          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken;
+
+  @protected
+  ScanCancelToken
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ScanCancelToken.dcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  ScanCancelToken
+      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ScanCancelToken.dcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
   Duration dco_decode_Chrono_Duration(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeDuration(dco_decode_i_64(raw).toInt());
+    return dcoDecodeDuration(dco_decode_i_64(raw).toInt());
+  }
+
+  @protected
+  DateTime dco_decode_Chrono_Naive(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeTimestamp(ts: dco_decode_i_64(raw).toInt(), isUtc: true);
+  }
+
+  @protected
+  ScanCancelToken
+      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ScanCancelToken.dcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  Album dco_decode_album(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return Album(
+      id: dco_decode_i_32(arr[0]),
+      name: dco_decode_String(arr[1]),
+      artistId: dco_decode_opt_box_autoadd_i_32(arr[2]),
+      createdAt: dco_decode_Chrono_Naive(arr[3]),
+      musicbrainzReleaseId: dco_decode_opt_String(arr[4]),
+      releaseType: dco_decode_opt_String(arr[5]),
+      nameRomaji: dco_decode_opt_String(arr[6]),
+      nameHiragana: dco_decode_opt_String(arr[7]),
+    );
+  }
+
+  @protected
+  AlbumDetail dco_decode_album_detail(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return AlbumDetail(
+      album: dco_decode_album_summary(arr[0]),
+      discs: dco_decode_list_disc_group(arr[1]),
+    );
+  }
+
+  @protected
+  AlbumSummary dco_decode_album_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return AlbumSummary(
+      id: dco_decode_i_32(arr[0]),
+      name: dco_decode_String(arr[1]),
+      artist: dco_decode_opt_box_autoadd_artist(arr[2]),
+      releaseType: dco_decode_opt_String(arr[3]),
+      year: dco_decode_opt_box_autoadd_i_32(arr[4]),
+      trackCount: dco_decode_i_32(arr[5]),
+      durationMs: dco_decode_i_64(arr[6]),
+      pictureId: dco_decode_opt_String(arr[7]),
+    );
+  }
+
+  @protected
+  Artist dco_decode_artist(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return Artist(
+      id: dco_decode_i_32(arr[0]),
+      name: dco_decode_String(arr[1]),
+      createdAt: dco_decode_Chrono_Naive(arr[2]),
+      nameRomaji: dco_decode_opt_String(arr[3]),
+      nameHiragana: dco_decode_opt_String(arr[4]),
+    );
+  }
+
+  @protected
+  ArtistDetail dco_decode_artist_detail(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ArtistDetail(
+      artist: dco_decode_artist_summary(arr[0]),
+      releaseGroups: dco_decode_list_release_group(arr[1]),
+      appearsOn: dco_decode_list_album_summary(arr[2]),
+    );
+  }
+
+  @protected
+  ArtistRole dco_decode_artist_role(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ArtistRole.values[raw as int];
+  }
+
+  @protected
+  ArtistSummary dco_decode_artist_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return ArtistSummary(
+      id: dco_decode_i_32(arr[0]),
+      name: dco_decode_String(arr[1]),
+      trackCount: dco_decode_i_32(arr[2]),
+      albumCount: dco_decode_i_32(arr[3]),
+      durationMs: dco_decode_i_64(arr[4]),
+      pictureId: dco_decode_opt_String(arr[5]),
+    );
+  }
+
+  @protected
+  AudioPropertiesFilter dco_decode_audio_properties_filter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return AudioPropertiesFilter(
+      codec: dco_decode_opt_String(arr[0]),
+      lossless: dco_decode_opt_box_autoadd_bool(arr[1]),
+      minBitrate: dco_decode_opt_box_autoadd_i_32(arr[2]),
+      maxBitrate: dco_decode_opt_box_autoadd_i_32(arr[3]),
+      minSampleRate: dco_decode_opt_box_autoadd_i_32(arr[4]),
+      minBitDepth: dco_decode_opt_box_autoadd_i_32(arr[5]),
+      channels: dco_decode_opt_box_autoadd_i_32(arr[6]),
+    );
+  }
+
+  @protected
+  AudioProperty dco_decode_audio_property(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AudioProperty.values[raw as int];
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  DateTime dco_decode_box_autoadd_Chrono_Naive(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_Chrono_Naive(raw);
+  }
+
+  @protected
+  Album dco_decode_box_autoadd_album(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_album(raw);
+  }
+
+  @protected
+  AlbumDetail dco_decode_box_autoadd_album_detail(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_album_detail(raw);
+  }
+
+  @protected
+  Artist dco_decode_box_autoadd_artist(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_artist(raw);
+  }
+
+  @protected
+  ArtistDetail dco_decode_box_autoadd_artist_detail(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_artist_detail(raw);
+  }
+
+  @protected
+  AudioPropertiesFilter dco_decode_box_autoadd_audio_properties_filter(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_audio_properties_filter(raw);
+  }
+
+  @protected
+  bool dco_decode_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  Config dco_decode_box_autoadd_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_config(raw);
+  }
+
+  @protected
+  double dco_decode_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  int dco_decode_box_autoadd_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  ScanProgress dco_decode_box_autoadd_scan_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_scan_progress(raw);
+  }
+
+  @protected
+  SyncSummary dco_decode_box_autoadd_sync_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_sync_summary(raw);
+  }
+
+  @protected
+  TrackDTO dco_decode_box_autoadd_track_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_track_dto(raw);
+  }
+
+  @protected
+  TrackQuery dco_decode_box_autoadd_track_query(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_track_query(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  TrackFilter dco_decode_box_track_filter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_track_filter(raw);
+  }
+
+  @protected
+  Config dco_decode_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return Config(
+      extensions: dco_decode_list_String(arr[0]),
+      detectByContent: dco_decode_bool(arr[1]),
+      sniffedExtensions: dco_decode_list_String(arr[2]),
+      artistSeparators: dco_decode_list_String(arr[3]),
+      genreSeparators: dco_decode_list_String(arr[4]),
+    );
+  }
+
+  @protected
+  DiscGroup dco_decode_disc_group(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return DiscGroup(
+      disc: dco_decode_opt_box_autoadd_i_32(arr[0]),
+      subtitle: dco_decode_opt_String(arr[1]),
+      tracks: dco_decode_list_track_dto(arr[2]),
+    );
+  }
+
+  @protected
+  double dco_decode_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  Genre dco_decode_genre(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return Genre(
+      id: dco_decode_i_32(arr[0]),
+      name: dco_decode_String(arr[1]),
+      createdAt: dco_decode_Chrono_Naive(arr[2]),
+    );
+  }
+
+  @protected
+  GenreSummary dco_decode_genre_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return GenreSummary(
+      id: dco_decode_i_32(arr[0]),
+      name: dco_decode_String(arr[1]),
+      trackCount: dco_decode_i_32(arr[2]),
+      albumCount: dco_decode_i_32(arr[3]),
+    );
+  }
+
+  @protected
+  HighlightRange dco_decode_highlight_range(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return HighlightRange(
+      start: dco_decode_u_32(arr[0]),
+      end: dco_decode_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  int dco_decode_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  int dco_decode_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeI64OrU64(raw);
+  }
+
+  @protected
+  LibraryChangeEvent dco_decode_library_change_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return LibraryChangeEvent(
+      upserted: dco_decode_list_String(arr[0]),
+      removed: dco_decode_list_String(arr[1]),
+      moved: dco_decode_list_moved_track(arr[2]),
+    );
+  }
+
+  @protected
+  LibraryRoot dco_decode_library_root(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return LibraryRoot(
+      id: dco_decode_i_32(arr[0]),
+      path: dco_decode_String(arr[1]),
+      enabled: dco_decode_bool(arr[2]),
+      trackCount: dco_decode_i_32(arr[3]),
+      lastScannedAt: dco_decode_opt_box_autoadd_Chrono_Naive(arr[4]),
+      createdAt: dco_decode_Chrono_Naive(arr[5]),
+    );
+  }
+
+  @protected
+  LibraryRootAvailability dco_decode_library_root_availability(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return LibraryRootAvailability(
+      id: dco_decode_i_32(arr[0]),
+      path: dco_decode_String(arr[1]),
+      available: dco_decode_bool(arr[2]),
+    );
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<Album> dco_decode_list_album(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_album).toList();
+  }
+
+  @protected
+  List<AlbumSummary> dco_decode_list_album_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_album_summary).toList();
+  }
+
+  @protected
+  List<ArtistSummary> dco_decode_list_artist_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_artist_summary).toList();
+  }
+
+  @protected
+  List<DiscGroup> dco_decode_list_disc_group(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_disc_group).toList();
+  }
+
+  @protected
+  List<Genre> dco_decode_list_genre(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_genre).toList();
+  }
+
+  @protected
+  List<GenreSummary> dco_decode_list_genre_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_genre_summary).toList();
+  }
+
+  @protected
+  List<HighlightRange> dco_decode_list_highlight_range(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_highlight_range).toList();
+  }
+
+  @protected
+  List<LibraryRoot> dco_decode_list_library_root(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_library_root).toList();
+  }
+
+  @protected
+  List<LibraryRootAvailability> dco_decode_list_library_root_availability(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_library_root_availability)
+        .toList();
+  }
+
+  @protected
+  List<MovedTrack> dco_decode_list_moved_track(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_moved_track).toList();
+  }
+
+  @protected
+  List<int> dco_decode_list_prim_i_32_loose(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as List<int>;
+  }
+
+  @protected
+  Int32List dco_decode_list_prim_i_32_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Int32List;
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
+  }
+
+  @protected
+  List<ReleaseGroup> dco_decode_list_release_group(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_release_group).toList();
+  }
+
+  @protected
+  List<ScanError> dco_decode_list_scan_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_scan_error).toList();
+  }
+
+  @protected
+  List<SortKey> dco_decode_list_sort_key(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_sort_key).toList();
+  }
+
+  @protected
+  List<SyncSummary> dco_decode_list_sync_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_sync_summary).toList();
+  }
+
+  @protected
+  List<TrackArtistDTO> dco_decode_list_track_artist_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_track_artist_dto).toList();
+  }
+
+  @protected
+  List<TrackDTO> dco_decode_list_track_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_track_dto).toList();
+  }
+
+  @protected
+  List<TrackFilter> dco_decode_list_track_filter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_track_filter).toList();
+  }
+
+  @protected
+  List<TrackSearchResult> dco_decode_list_track_search_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_track_search_result).toList();
+  }
+
+  @protected
+  MovedTrack dco_decode_moved_track(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return MovedTrack(
+      from: dco_decode_String(arr[0]),
+      to: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  DateTime? dco_decode_opt_box_autoadd_Chrono_Naive(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_Chrono_Naive(raw);
+  }
+
+  @protected
+  Album? dco_decode_opt_box_autoadd_album(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_album(raw);
+  }
+
+  @protected
+  AlbumDetail? dco_decode_opt_box_autoadd_album_detail(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_album_detail(raw);
+  }
+
+  @protected
+  Artist? dco_decode_opt_box_autoadd_artist(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_artist(raw);
+  }
+
+  @protected
+  ArtistDetail? dco_decode_opt_box_autoadd_artist_detail(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_artist_detail(raw);
+  }
+
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_bool(raw);
+  }
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_f_64(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_i_32(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  QueryParseError dco_decode_query_parse_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return QueryParseError(
+      message: dco_decode_String(arr[0]),
+      start: dco_decode_u_32(arr[1]),
+      end: dco_decode_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  ReleaseGroup dco_decode_release_group(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ReleaseGroup(
+      releaseType: dco_decode_opt_String(arr[0]),
+      albums: dco_decode_list_album_summary(arr[1]),
+    );
+  }
+
+  @protected
+  ScanError dco_decode_scan_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return ScanError(
+      id: dco_decode_i_32(arr[0]),
+      location: dco_decode_String(arr[1]),
+      mountPoint: dco_decode_String(arr[2]),
+      kind: dco_decode_String(arr[3]),
+      message: dco_decode_String(arr[4]),
+      occurredAt: dco_decode_Chrono_Naive(arr[5]),
+    );
+  }
+
+  @protected
+  ScanEvent dco_decode_scan_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return ScanEvent_Progress(
+          dco_decode_box_autoadd_scan_progress(raw[1]),
+        );
+      case 1:
+        return ScanEvent_Error(
+          path: dco_decode_String(raw[1]),
+          kind: dco_decode_String(raw[2]),
+          message: dco_decode_String(raw[3]),
+        );
+      case 2:
+        return ScanEvent_Finished(
+          dco_decode_box_autoadd_sync_summary(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  ScanProgress dco_decode_scan_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ScanProgress(
+      discovered: dco_decode_u_32(arr[0]),
+      parsed: dco_decode_u_32(arr[1]),
+      written: dco_decode_u_32(arr[2]),
+      currentPath: dco_decode_String(arr[3]),
+    );
+  }
+
+  @protected
+  SortField dco_decode_sort_field(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SortField.values[raw as int];
+  }
+
+  @protected
+  SortKey dco_decode_sort_key(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return SortKey(
+      field: dco_decode_sort_field(arr[0]),
+      descending: dco_decode_bool(arr[1]),
+    );
+  }
+
+  @protected
+  SyncSummary dco_decode_sync_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return SyncSummary(
+      added: dco_decode_u_32(arr[0]),
+      updated: dco_decode_u_32(arr[1]),
+      unchanged: dco_decode_u_32(arr[2]),
+      failed: dco_decode_u_32(arr[3]),
+      removed: dco_decode_u_32(arr[4]),
+      cancelled: dco_decode_bool(arr[5]),
+    );
+  }
+
+  @protected
+  TextField dco_decode_text_field(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return TextField.values[raw as int];
+  }
+
+  @protected
+  TrackArtistDTO dco_decode_track_artist_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return TrackArtistDTO(
+      artist: dco_decode_artist(arr[0]),
+      role: dco_decode_artist_role(arr[1]),
+    );
+  }
+
+  @protected
+  TrackDTO dco_decode_track_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 37)
+      throw Exception('unexpected arr length: expect 37 but see ${arr.length}');
+    return TrackDTO(
+      id: dco_decode_i_32(arr[0]),
+      title: dco_decode_opt_String(arr[1]),
+      titleRomaji: dco_decode_opt_String(arr[2]),
+      titleHiragana: dco_decode_opt_String(arr[3]),
+      artist: dco_decode_opt_box_autoadd_artist(arr[4]),
+      album: dco_decode_opt_box_autoadd_album(arr[5]),
+      albumArtist: dco_decode_opt_box_autoadd_artist(arr[6]),
+      artists: dco_decode_list_track_artist_dto(arr[7]),
+      number: dco_decode_opt_box_autoadd_i_32(arr[8]),
+      disc: dco_decode_opt_box_autoadd_i_32(arr[9]),
+      durationMs: dco_decode_i_32(arr[10]),
+      location: dco_decode_String(arr[11]),
+      mountPoint: dco_decode_String(arr[12]),
+      pictureId: dco_decode_opt_String(arr[13]),
+      available: dco_decode_bool(arr[14]),
+      year: dco_decode_opt_box_autoadd_i_32(arr[15]),
+      date: dco_decode_opt_String(arr[16]),
+      originalDate: dco_decode_opt_String(arr[17]),
+      genre: dco_decode_opt_String(arr[18]),
+      genres: dco_decode_list_genre(arr[19]),
+      composer: dco_decode_opt_String(arr[20]),
+      comment: dco_decode_opt_String(arr[21]),
+      trackTotal: dco_decode_opt_box_autoadd_i_32(arr[22]),
+      discTotal: dco_decode_opt_box_autoadd_i_32(arr[23]),
+      bpm: dco_decode_opt_box_autoadd_f_64(arr[24]),
+      isrc: dco_decode_opt_String(arr[25]),
+      label: dco_decode_opt_String(arr[26]),
+      codec: dco_decode_opt_String(arr[27]),
+      bitrate: dco_decode_opt_box_autoadd_i_32(arr[28]),
+      sampleRate: dco_decode_opt_box_autoadd_i_32(arr[29]),
+      bitDepth: dco_decode_opt_box_autoadd_i_32(arr[30]),
+      channels: dco_decode_opt_box_autoadd_i_32(arr[31]),
+      fileSize: dco_decode_i_64(arr[32]),
+      lossless: dco_decode_bool(arr[33]),
+      discSubtitle: dco_decode_opt_String(arr[34]),
+      playCount: dco_decode_i_32(arr[35]),
+      lastPlayedAt: dco_decode_opt_box_autoadd_Chrono_Naive(arr[36]),
+    );
+  }
+
+  @protected
+  TrackFilter dco_decode_track_filter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return TrackFilter_Artist(
+          dco_decode_i_32(raw[1]),
+        );
+      case 1:
+        return TrackFilter_Album(
+          dco_decode_i_32(raw[1]),
+        );
+      case 2:
+        return TrackFilter_Genre(
+          dco_decode_i_32(raw[1]),
+        );
+      case 3:
+        return TrackFilter_Year(
+          min: dco_decode_opt_box_autoadd_i_32(raw[1]),
+          max: dco_decode_opt_box_autoadd_i_32(raw[2]),
+        );
+      case 4:
+        return TrackFilter_DurationMs(
+          min: dco_decode_opt_box_autoadd_i_32(raw[1]),
+          max: dco_decode_opt_box_autoadd_i_32(raw[2]),
+        );
+      case 5:
+        return TrackFilter_Codec(
+          dco_decode_String(raw[1]),
+        );
+      case 6:
+        return TrackFilter_FileExtension(
+          dco_decode_String(raw[1]),
+        );
+      case 7:
+        return TrackFilter_Lossless(
+          dco_decode_bool(raw[1]),
+        );
+      case 8:
+        return TrackFilter_Bitrate(
+          min: dco_decode_opt_box_autoadd_i_32(raw[1]),
+          max: dco_decode_opt_box_autoadd_i_32(raw[2]),
+        );
+      case 9:
+        return TrackFilter_MinSampleRate(
+          dco_decode_i_32(raw[1]),
+        );
+      case 10:
+        return TrackFilter_MinBitDepth(
+          dco_decode_i_32(raw[1]),
+        );
+      case 11:
+        return TrackFilter_Channels(
+          dco_decode_i_32(raw[1]),
+        );
+      case 12:
+        return TrackFilter_LibraryRoot(
+          dco_decode_i_32(raw[1]),
+        );
+      case 13:
+        return TrackFilter_Text(
+          field: dco_decode_text_field(raw[1]),
+          text: dco_decode_String(raw[2]),
+        );
+      case 14:
+        return TrackFilter_Not(
+          dco_decode_box_track_filter(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  TrackPage dco_decode_track_page(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return TrackPage(
+      tracks: dco_decode_list_track_dto(arr[0]),
+      total: dco_decode_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  TrackQuery dco_decode_track_query(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return TrackQuery(
+      sort: dco_decode_list_sort_key(arr[0]),
+      filters: dco_decode_list_track_filter(arr[1]),
+      hideOffline: dco_decode_bool(arr[2]),
+      offset: dco_decode_u_32(arr[3]),
+      limit: dco_decode_opt_box_autoadd_u_32(arr[4]),
+    );
+  }
+
+  @protected
+  TrackSearchResult dco_decode_track_search_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return TrackSearchResult(
+      track: dco_decode_track_dto(arr[0]),
+      score: dco_decode_f_64(arr[1]),
+      titleRanges: dco_decode_list_highlight_range(arr[2]),
+      albumRanges: dco_decode_list_highlight_range(arr[3]),
+      artistRanges: dco_decode_list_highlight_range(arr[4]),
+    );
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  int dco_decode_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeI64OrU64(raw);
+  }
+
+  @protected
+  ScanCancelToken
+      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return ScanCancelToken.sseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  ScanCancelToken
+      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return ScanCancelToken.sseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  Duration sse_decode_Chrono_Duration(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_64(deserializer);
+    return Duration(microseconds: inner);
+  }
+
+  @protected
+  DateTime sse_decode_Chrono_Naive(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_64(deserializer);
+    return DateTime.fromMicrosecondsSinceEpoch(inner, isUtc: true);
+  }
+
+  @protected
+  ScanCancelToken
+      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return ScanCancelToken.sseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  Album sse_decode_album(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_i_32(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_artistId = sse_decode_opt_box_autoadd_i_32(deserializer);
+    var var_createdAt = sse_decode_Chrono_Naive(deserializer);
+    var var_musicbrainzReleaseId = sse_decode_opt_String(deserializer);
+    var var_releaseType = sse_decode_opt_String(deserializer);
+    var var_nameRomaji = sse_decode_opt_String(deserializer);
+    var var_nameHiragana = sse_decode_opt_String(deserializer);
+    return Album(
+        id: var_id,
+        name: var_name,
+        artistId: var_artistId,
+        createdAt: var_createdAt,
+        musicbrainzReleaseId: var_musicbrainzReleaseId,
+        releaseType: var_releaseType,
+        nameRomaji: var_nameRomaji,
+        nameHiragana: var_nameHiragana);
+  }
+
+  @protected
+  AlbumDetail sse_decode_album_detail(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_album = sse_decode_album_summary(deserializer);
+    var var_discs = sse_decode_list_disc_group(deserializer);
+    return AlbumDetail(album: var_album, discs: var_discs);
+  }
+
+  @protected
+  AlbumSummary sse_decode_album_summary(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_i_32(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_artist = sse_decode_opt_box_autoadd_artist(deserializer);
+    var var_releaseType = sse_decode_opt_String(deserializer);
+    var var_year = sse_decode_opt_box_autoadd_i_32(deserializer);
+    var var_trackCount = sse_decode_i_32(deserializer);
+    var var_durationMs = sse_decode_i_64(deserializer);
+    var var_pictureId = sse_decode_opt_String(deserializer);
+    return AlbumSummary(
+        id: var_id,
+        name: var_name,
+        artist: var_artist,
+        releaseType: var_releaseType,
+        year: var_year,
+        trackCount: var_trackCount,
+        durationMs: var_durationMs,
+        pictureId: var_pictureId);
+  }
+
+  @protected
+  Artist sse_decode_artist(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_i_32(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_createdAt = sse_decode_Chrono_Naive(deserializer);
+    var var_nameRomaji = sse_decode_opt_String(deserializer);
+    var var_nameHiragana = sse_decode_opt_String(deserializer);
+    return Artist(
+        id: var_id,
+        name: var_name,
+        createdAt: var_createdAt,
+        nameRomaji: var_nameRomaji,
+        nameHiragana: var_nameHiragana);
+  }
+
+  @protected
+  ArtistDetail sse_decode_artist_detail(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_artist = sse_decode_artist_summary(deserializer);
+    var var_releaseGroups = sse_decode_list_release_group(deserializer);
+    var var_appearsOn = sse_decode_list_album_summary(deserializer);
+    return ArtistDetail(
+        artist: var_artist,
+        releaseGroups: var_releaseGroups,
+        appearsOn: var_appearsOn);
+  }
+
+  @protected
+  ArtistRole sse_decode_artist_role(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ArtistRole.values[inner];
+  }
+
+  @protected
+  ArtistSummary sse_decode_artist_summary(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_i_32(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_trackCount = sse_decode_i_32(deserializer);
+    var var_albumCount = sse_decode_i_32(deserializer);
+    var var_durationMs = sse_decode_i_64(deserializer);
+    var var_pictureId = sse_decode_opt_String(deserializer);
+    return ArtistSummary(
+        id: var_id,
+        name: var_name,
+        trackCount: var_trackCount,
+        albumCount: var_albumCount,
+        durationMs: var_durationMs,
+        pictureId: var_pictureId);
+  }
+
+  @protected
+  AudioPropertiesFilter sse_decode_audio_properties_filter(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_codec = sse_decode_opt_String(deserializer);
+    var var_lossless = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_minBitrate = sse_decode_opt_box_autoadd_i_32(deserializer);
+    var var_maxBitrate = sse_decode_opt_box_autoadd_i_32(deserializer);
+    var var_minSampleRate = sse_decode_opt_box_autoadd_i_32(deserializer);
+    var var_minBitDepth = sse_decode_opt_box_autoadd_i_32(deserializer);
+    var var_channels = sse_decode_opt_box_autoadd_i_32(deserializer);
+    return AudioPropertiesFilter(
+        codec: var_codec,
+        lossless: var_lossless,
+        minBitrate: var_minBitrate,
+        maxBitrate: var_maxBitrate,
+        minSampleRate: var_minSampleRate,
+        minBitDepth: var_minBitDepth,
+        channels: var_channels);
+  }
+
+  @protected
+  AudioProperty sse_decode_audio_property(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return AudioProperty.values[inner];
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  DateTime sse_decode_box_autoadd_Chrono_Naive(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_Chrono_Naive(deserializer));
+  }
+
+  @protected
+  Album sse_decode_box_autoadd_album(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_album(deserializer));
+  }
+
+  @protected
+  AlbumDetail sse_decode_box_autoadd_album_detail(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_album_detail(deserializer));
+  }
+
+  @protected
+  Artist sse_decode_box_autoadd_artist(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_artist(deserializer));
+  }
+
+  @protected
+  ArtistDetail sse_decode_box_autoadd_artist_detail(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_artist_detail(deserializer));
+  }
+
+  @protected
+  AudioPropertiesFilter sse_decode_box_autoadd_audio_properties_filter(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_audio_properties_filter(deserializer));
+  }
+
+  @protected
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_bool(deserializer));
+  }
+
+  @protected
+  Config sse_decode_box_autoadd_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_config(deserializer));
+  }
+
+  @protected
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_f_64(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  ScanProgress sse_decode_box_autoadd_scan_progress(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_scan_progress(deserializer));
+  }
+
+  @protected
+  SyncSummary sse_decode_box_autoadd_sync_summary(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_sync_summary(deserializer));
+  }
+
+  @protected
+  TrackDTO sse_decode_box_autoadd_track_dto(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_track_dto(deserializer));
+  }
+
+  @protected
+  TrackQuery sse_decode_box_autoadd_track_query(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_track_query(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
+  @protected
+  TrackFilter sse_decode_box_track_filter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_track_filter(deserializer));
+  }
+
+  @protected
+  Config sse_decode_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_extensions = sse_decode_list_String(deserializer);
+    var var_detectByContent = sse_decode_bool(deserializer);
+    var var_sniffedExtensions = sse_decode_list_String(deserializer);
+    var var_artistSeparators = sse_decode_list_String(deserializer);
+    var var_genreSeparators = sse_decode_list_String(deserializer);
+    return Config(
+        extensions: var_extensions,
+        detectByContent: var_detectByContent,
+        sniffedExtensions: var_sniffedExtensions,
+        artistSeparators: var_artistSeparators,
+        genreSeparators: var_genreSeparators);
+  }
+
+  @protected
+  DiscGroup sse_decode_disc_group(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_disc = sse_decode_opt_box_autoadd_i_32(deserializer);
+    var var_subtitle = sse_decode_opt_String(deserializer);
+    var var_tracks = sse_decode_list_track_dto(deserializer);
+    return DiscGroup(
+        disc: var_disc, subtitle: var_subtitle, tracks: var_tracks);
+  }
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat64();
+  }
+
+  @protected
+  Genre sse_decode_genre(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_i_32(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_createdAt = sse_decode_Chrono_Naive(deserializer);
+    return Genre(id: var_id, name: var_name, createdAt: var_createdAt);
+  }
+
+  @protected
+  GenreSummary sse_decode_genre_summary(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_i_32(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_trackCount = sse_decode_i_32(deserializer);
+    var var_albumCount = sse_decode_i_32(deserializer);
+    return GenreSummary(
+        id: var_id,
+        name: var_name,
+        trackCount: var_trackCount,
+        albumCount: var_albumCount);
+  }
+
+  @protected
+  HighlightRange sse_decode_highlight_range(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_start = sse_decode_u_32(deserializer);
+    var var_end = sse_decode_u_32(deserializer);
+    return HighlightRange(start: var_start, end: var_end);
+  }
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
+  @protected
+  int sse_decode_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt64();
+  }
+
+  @protected
+  LibraryChangeEvent sse_decode_library_change_event(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_upserted = sse_decode_list_String(deserializer);
+    var var_removed = sse_decode_list_String(deserializer);
+    var var_moved = sse_decode_list_moved_track(deserializer);
+    return LibraryChangeEvent(
+        upserted: var_upserted, removed: var_removed, moved: var_moved);
+  }
+
+  @protected
+  LibraryRoot sse_decode_library_root(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_i_32(deserializer);
+    var var_path = sse_decode_String(deserializer);
+    var var_enabled = sse_decode_bool(deserializer);
+    var var_trackCount = sse_decode_i_32(deserializer);
+    var var_lastScannedAt =
+        sse_decode_opt_box_autoadd_Chrono_Naive(deserializer);
+    var var_createdAt = sse_decode_Chrono_Naive(deserializer);
+    return LibraryRoot(
+        id: var_id,
+        path: var_path,
+        enabled: var_enabled,
+        trackCount: var_trackCount,
+        lastScannedAt: var_lastScannedAt,
+        createdAt: var_createdAt);
+  }
+
+  @protected
+  LibraryRootAvailability sse_decode_library_root_availability(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_i_32(deserializer);
+    var var_path = sse_decode_String(deserializer);
+    var var_available = sse_decode_bool(deserializer);
+    return LibraryRootAvailability(
+        id: var_id, path: var_path, available: var_available);
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Album> sse_decode_list_album(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Album>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_album(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<AlbumSummary> sse_decode_list_album_summary(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <AlbumSummary>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_album_summary(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ArtistSummary> sse_decode_list_artist_summary(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ArtistSummary>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_artist_summary(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<DiscGroup> sse_decode_list_disc_group(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <DiscGroup>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_disc_group(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Genre> sse_decode_list_genre(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Genre>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_genre(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<GenreSummary> sse_decode_list_genre_summary(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <GenreSummary>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_genre_summary(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<HighlightRange> sse_decode_list_highlight_range(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <HighlightRange>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_highlight_range(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<LibraryRoot> sse_decode_list_library_root(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <LibraryRoot>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_library_root(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<LibraryRootAvailability> sse_decode_list_library_root_availability(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <LibraryRootAvailability>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_library_root_availability(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MovedTrack> sse_decode_list_moved_track(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MovedTrack>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_moved_track(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<int> sse_decode_list_prim_i_32_loose(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getInt32List(len_);
+  }
+
+  @protected
+  Int32List sse_decode_list_prim_i_32_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getInt32List(len_);
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<ReleaseGroup> sse_decode_list_release_group(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ReleaseGroup>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_release_group(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ScanError> sse_decode_list_scan_error(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ScanError>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_scan_error(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<SortKey> sse_decode_list_sort_key(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SortKey>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_sort_key(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<SyncSummary> sse_decode_list_sync_summary(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SyncSummary>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_sync_summary(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TrackArtistDTO> sse_decode_list_track_artist_dto(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TrackArtistDTO>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_track_artist_dto(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TrackDTO> sse_decode_list_track_dto(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TrackDTO>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_track_dto(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TrackFilter> sse_decode_list_track_filter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TrackFilter>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_track_filter(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TrackSearchResult> sse_decode_list_track_search_result(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TrackSearchResult>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_track_search_result(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  MovedTrack sse_decode_moved_track(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_from = sse_decode_String(deserializer);
+    var var_to = sse_decode_String(deserializer);
+    return MovedTrack(from: var_from, to: var_to);
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  DateTime? sse_decode_opt_box_autoadd_Chrono_Naive(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_Chrono_Naive(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  Album? sse_decode_opt_box_autoadd_album(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_album(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  AlbumDetail? sse_decode_opt_box_autoadd_album_detail(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_album_detail(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  Artist? sse_decode_opt_box_autoadd_artist(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_artist(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ArtistDetail? sse_decode_opt_box_autoadd_artist_detail(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_artist_detail(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_bool(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_f_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_i_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  QueryParseError sse_decode_query_parse_error(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_message = sse_decode_String(deserializer);
+    var var_start = sse_decode_u_32(deserializer);
+    var var_end = sse_decode_u_32(deserializer);
+    return QueryParseError(
+        message: var_message, start: var_start, end: var_end);
+  }
+
+  @protected
+  ReleaseGroup sse_decode_release_group(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_releaseType = sse_decode_opt_String(deserializer);
+    var var_albums = sse_decode_list_album_summary(deserializer);
+    return ReleaseGroup(releaseType: var_releaseType, albums: var_albums);
+  }
+
+  @protected
+  ScanError sse_decode_scan_error(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_i_32(deserializer);
+    var var_location = sse_decode_String(deserializer);
+    var var_mountPoint = sse_decode_String(deserializer);
+    var var_kind = sse_decode_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_occurredAt = sse_decode_Chrono_Naive(deserializer);
+    return ScanError(
+        id: var_id,
+        location: var_location,
+        mountPoint: var_mountPoint,
+        kind: var_kind,
+        message: var_message,
+        occurredAt: var_occurredAt);
+  }
+
+  @protected
+  ScanEvent sse_decode_scan_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_field0 = sse_decode_box_autoadd_scan_progress(deserializer);
+        return ScanEvent_Progress(var_field0);
+      case 1:
+        var var_path = sse_decode_String(deserializer);
+        var var_kind = sse_decode_String(deserializer);
+        var var_message = sse_decode_String(deserializer);
+        return ScanEvent_Error(
+            path: var_path, kind: var_kind, message: var_message);
+      case 2:
+        var var_field0 = sse_decode_box_autoadd_sync_summary(deserializer);
+        return ScanEvent_Finished(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  ScanProgress sse_decode_scan_progress(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_discovered = sse_decode_u_32(deserializer);
+    var var_parsed = sse_decode_u_32(deserializer);
+    var var_written = sse_decode_u_32(deserializer);
+    var var_currentPath = sse_decode_String(deserializer);
+    return ScanProgress(
+        discovered: var_discovered,
+        parsed: var_parsed,
+        written: var_written,
+        currentPath: var_currentPath);
+  }
+
+  @protected
+  SortField sse_decode_sort_field(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SortField.values[inner];
+  }
+
+  @protected
+  SortKey sse_decode_sort_key(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field = sse_decode_sort_field(deserializer);
+    var var_descending = sse_decode_bool(deserializer);
+    return SortKey(field: var_field, descending: var_descending);
+  }
+
+  @protected
+  SyncSummary sse_decode_sync_summary(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_added = sse_decode_u_32(deserializer);
+    var var_updated = sse_decode_u_32(deserializer);
+    var var_unchanged = sse_decode_u_32(deserializer);
+    var var_failed = sse_decode_u_32(deserializer);
+    var var_removed = sse_decode_u_32(deserializer);
+    var var_cancelled = sse_decode_bool(deserializer);
+    return SyncSummary(
+        added: var_added,
+        updated: var_updated,
+        unchanged: var_unchanged,
+        failed: var_failed,
+        removed: var_removed,
+        cancelled: var_cancelled);
+  }
+
+  @protected
+  TextField sse_decode_text_field(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return TextField.values[inner];
+  }
+
+  @protected
+  TrackArtistDTO sse_decode_track_artist_dto(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_artist = sse_decode_artist(deserializer);
+    var var_role = sse_decode_artist_role(deserializer);
+    return TrackArtistDTO(artist: var_artist, role: var_role);
+  }
+
+  @protected
+  TrackDTO sse_decode_track_dto(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_i_32(deserializer);
+    var var_title = sse_decode_opt_String(deserializer);
+    var var_titleRomaji = sse_decode_opt_String(deserializer);
+    var var_titleHiragana = sse_decode_opt_String(deserializer);
+    var var_artist = sse_decode_opt_box_autoadd_artist(deserializer);
+    var var_album = sse_decode_opt_box_autoadd_album(deserializer);
+    var var_albumArtist = sse_decode_opt_box_autoadd_artist(deserializer);
+    var var_artists = sse_decode_list_track_artist_dto(deserializer);
+    var var_number = sse_decode_opt_box_autoadd_i_32(deserializer);
+    var var_disc = sse_decode_opt_box_autoadd_i_32(deserializer);
+    var var_durationMs = sse_decode_i_32(deserializer);
+    var var_location = sse_decode_String(deserializer);
+    var var_mountPoint = sse_decode_String(deserializer);
+    var var_pictureId = sse_decode_opt_String(deserializer);
+    var var_available = sse_decode_bool(deserializer);
+    var var_year = sse_decode_opt_box_autoadd_i_32(deserializer);
+    var var_date = sse_decode_opt_String(deserializer);
+    var var_originalDate = sse_decode_opt_String(deserializer);
+    var var_genre = sse_decode_opt_String(deserializer);
+    var var_genres = sse_decode_list_genre(deserializer);
+    var var_composer = sse_decode_opt_String(deserializer);
+    var var_comment = sse_decode_opt_String(deserializer);
+    var var_trackTotal = sse_decode_opt_box_autoadd_i_32(deserializer);
+    var var_discTotal = sse_decode_opt_box_autoadd_i_32(deserializer);
+    var var_bpm = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_isrc = sse_decode_opt_String(deserializer);
+    var var_label = sse_decode_opt_String(deserializer);
+    var var_codec = sse_decode_opt_String(deserializer);
+    var var_bitrate = sse_decode_opt_box_autoadd_i_32(deserializer);
+    var var_sampleRate = sse_decode_opt_box_autoadd_i_32(deserializer);
+    var var_bitDepth = sse_decode_opt_box_autoadd_i_32(deserializer);
+    var var_channels = sse_decode_opt_box_autoadd_i_32(deserializer);
+    var var_fileSize = sse_decode_i_64(deserializer);
+    var var_lossless = sse_decode_bool(deserializer);
+    var var_discSubtitle = sse_decode_opt_String(deserializer);
+    var var_playCount = sse_decode_i_32(deserializer);
+    var var_lastPlayedAt =
+        sse_decode_opt_box_autoadd_Chrono_Naive(deserializer);
+    return TrackDTO(
+        id: var_id,
+        title: var_title,
+        titleRomaji: var_titleRomaji,
+        titleHiragana: var_titleHiragana,
+        artist: var_artist,
+        album: var_album,
+        albumArtist: var_albumArtist,
+        artists: var_artists,
+        number: var_number,
+        disc: var_disc,
+        durationMs: var_durationMs,
+        location: var_location,
+        mountPoint: var_mountPoint,
+        pictureId: var_pictureId,
+        available: var_available,
+        year: var_year,
+        date: var_date,
+        originalDate: var_originalDate,
+        genre: var_genre,
+        genres: var_genres,
+        composer: var_composer,
+        comment: var_comment,
+        trackTotal: var_trackTotal,
+        discTotal: var_discTotal,
+        bpm: var_bpm,
+        isrc: var_isrc,
+        label: var_label,
+        codec: var_codec,
+        bitrate: var_bitrate,
+        sampleRate: var_sampleRate,
+        bitDepth: var_bitDepth,
+        channels: var_channels,
+        fileSize: var_fileSize,
+        lossless: var_lossless,
+        discSubtitle: var_discSubtitle,
+        playCount: var_playCount,
+        lastPlayedAt: var_lastPlayedAt);
+  }
+
+  @protected
+  TrackFilter sse_decode_track_filter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_field0 = sse_decode_i_32(deserializer);
+        return TrackFilter_Artist(var_field0);
+      case 1:
+        var var_field0 = sse_decode_i_32(deserializer);
+        return TrackFilter_Album(var_field0);
+      case 2:
+        var var_field0 = sse_decode_i_32(deserializer);
+        return TrackFilter_Genre(var_field0);
+      case 3:
+        var var_min = sse_decode_opt_box_autoadd_i_32(deserializer);
+        var var_max = sse_decode_opt_box_autoadd_i_32(deserializer);
+        return TrackFilter_Year(min: var_min, max: var_max);
+      case 4:
+        var var_min = sse_decode_opt_box_autoadd_i_32(deserializer);
+        var var_max = sse_decode_opt_box_autoadd_i_32(deserializer);
+        return TrackFilter_DurationMs(min: var_min, max: var_max);
+      case 5:
+        var var_field0 = sse_decode_String(deserializer);
+        return TrackFilter_Codec(var_field0);
+      case 6:
+        var var_field0 = sse_decode_String(deserializer);
+        return TrackFilter_FileExtension(var_field0);
+      case 7:
+        var var_field0 = sse_decode_bool(deserializer);
+        return TrackFilter_Lossless(var_field0);
+      case 8:
+        var var_min = sse_decode_opt_box_autoadd_i_32(deserializer);
+        var var_max = sse_decode_opt_box_autoadd_i_32(deserializer);
+        return TrackFilter_Bitrate(min: var_min, max: var_max);
+      case 9:
+        var var_field0 = sse_decode_i_32(deserializer);
+        return TrackFilter_MinSampleRate(var_field0);
+      case 10:
+        var var_field0 = sse_decode_i_32(deserializer);
+        return TrackFilter_MinBitDepth(var_field0);
+      case 11:
+        var var_field0 = sse_decode_i_32(deserializer);
+        return TrackFilter_Channels(var_field0);
+      case 12:
+        var var_field0 = sse_decode_i_32(deserializer);
+        return TrackFilter_LibraryRoot(var_field0);
+      case 13:
+        var var_field = sse_decode_text_field(deserializer);
+        var var_text = sse_decode_String(deserializer);
+        return TrackFilter_Text(field: var_field, text: var_text);
+      case 14:
+        var var_field0 = sse_decode_box_track_filter(deserializer);
+        return TrackFilter_Not(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  TrackPage sse_decode_track_page(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_tracks = sse_decode_list_track_dto(deserializer);
+    var var_total = sse_decode_u_32(deserializer);
+    return TrackPage(tracks: var_tracks, total: var_total);
+  }
+
+  @protected
+  TrackQuery sse_decode_track_query(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_sort = sse_decode_list_sort_key(deserializer);
+    var var_filters = sse_decode_list_track_filter(deserializer);
+    var var_hideOffline = sse_decode_bool(deserializer);
+    var var_offset = sse_decode_u_32(deserializer);
+    var var_limit = sse_decode_opt_box_autoadd_u_32(deserializer);
+    return TrackQuery(
+        sort: var_sort,
+        filters: var_filters,
+        hideOffline: var_hideOffline,
+        offset: var_offset,
+        limit: var_limit);
+  }
+
+  @protected
+  TrackSearchResult sse_decode_track_search_result(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_track = sse_decode_track_dto(deserializer);
+    var var_score = sse_decode_f_64(deserializer);
+    var var_titleRanges = sse_decode_list_highlight_range(deserializer);
+    var var_albumRanges = sse_decode_list_highlight_range(deserializer);
+    var var_artistRanges = sse_decode_list_highlight_range(deserializer);
+    return TrackSearchResult(
+        track: var_track,
+        score: var_score,
+        titleRanges: var_titleRanges,
+        albumRanges: var_albumRanges,
+        artistRanges: var_artistRanges);
+  }
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
+  }
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8();
+  }
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+  }
+
+  @protected
+  int sse_decode_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint64();
+  }
+
+  @protected
+  void
+      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(
+          ScanCancelToken self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(self.sseEncode(move: true), serializer);
+  }
+
+  @protected
+  void
+      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(
+          ScanCancelToken self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(self.sseEncode(move: false), serializer);
+  }
+
+  @protected
+  void sse_encode_Chrono_Duration(Duration self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self.inMicroseconds, serializer);
+  }
+
+  @protected
+  void sse_encode_Chrono_Naive(DateTime self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self.microsecondsSinceEpoch, serializer);
+  }
+
+  @protected
+  void
+      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(
+          ScanCancelToken self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(self.sseEncode(move: null), serializer);
+  }
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+  }
+
+  @protected
+  void sse_encode_album(Album self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.id, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_opt_box_autoadd_i_32(self.artistId, serializer);
+    sse_encode_Chrono_Naive(self.createdAt, serializer);
+    sse_encode_opt_String(self.musicbrainzReleaseId, serializer);
+    sse_encode_opt_String(self.releaseType, serializer);
+    sse_encode_opt_String(self.nameRomaji, serializer);
+    sse_encode_opt_String(self.nameHiragana, serializer);
+  }
+
+  @protected
+  void sse_encode_album_detail(AlbumDetail self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_album_summary(self.album, serializer);
+    sse_encode_list_disc_group(self.discs, serializer);
+  }
+
+  @protected
+  void sse_encode_album_summary(AlbumSummary self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.id, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_opt_box_autoadd_artist(self.artist, serializer);
+    sse_encode_opt_String(self.releaseType, serializer);
+    sse_encode_opt_box_autoadd_i_32(self.year, serializer);
+    sse_encode_i_32(self.trackCount, serializer);
+    sse_encode_i_64(self.durationMs, serializer);
+    sse_encode_opt_String(self.pictureId, serializer);
+  }
+
+  @protected
+  void sse_encode_artist(Artist self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.id, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_Chrono_Naive(self.createdAt, serializer);
+    sse_encode_opt_String(self.nameRomaji, serializer);
+    sse_encode_opt_String(self.nameHiragana, serializer);
+  }
+
+  @protected
+  void sse_encode_artist_detail(ArtistDetail self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_artist_summary(self.artist, serializer);
+    sse_encode_list_release_group(self.releaseGroups, serializer);
+    sse_encode_list_album_summary(self.appearsOn, serializer);
   }
 
   @protected
-  DateTime dco_decode_Chrono_Naive(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeTimestamp(ts: dco_decode_i_64(raw).toInt(), isUtc: true);
+  void sse_encode_artist_role(ArtistRole self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
+  void sse_encode_artist_summary(ArtistSummary self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.id, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_i_32(self.trackCount, serializer);
+    sse_encode_i_32(self.albumCount, serializer);
+    sse_encode_i_64(self.durationMs, serializer);
+    sse_encode_opt_String(self.pictureId, serializer);
   }
 
   @protected
-  Album dco_decode_album(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return Album(
-      id: dco_decode_i_32(arr[0]),
-      name: dco_decode_String(arr[1]),
-      artistId: dco_decode_opt_box_autoadd_i_32(arr[2]),
-      createdAt: dco_decode_Chrono_Naive(arr[3]),
-    );
+  void sse_encode_audio_properties_filter(
+      AudioPropertiesFilter self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.codec, serializer);
+    sse_encode_opt_box_autoadd_bool(self.lossless, serializer);
+    sse_encode_opt_box_autoadd_i_32(self.minBitrate, serializer);
+    sse_encode_opt_box_autoadd_i_32(self.maxBitrate, serializer);
+    sse_encode_opt_box_autoadd_i_32(self.minSampleRate, serializer);
+    sse_encode_opt_box_autoadd_i_32(self.minBitDepth, serializer);
+    sse_encode_opt_box_autoadd_i_32(self.channels, serializer);
   }
 
   @protected
-  Artist dco_decode_artist(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return Artist(
-      id: dco_decode_i_32(arr[0]),
-      name: dco_decode_String(arr[1]),
-      createdAt: dco_decode_Chrono_Naive(arr[2]),
-    );
+  void sse_encode_audio_property(AudioProperty self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
+  void sse_encode_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self ? 1 : 0);
   }
 
   @protected
-  Album dco_decode_box_autoadd_album(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_album(raw);
+  void sse_encode_box_autoadd_Chrono_Naive(
+      DateTime self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Chrono_Naive(self, serializer);
   }
 
   @protected
-  Artist dco_decode_box_autoadd_artist(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_artist(raw);
+  void sse_encode_box_autoadd_album(Album self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_album(self, serializer);
   }
 
   @protected
-  int dco_decode_box_autoadd_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_box_autoadd_album_detail(
+      AlbumDetail self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_album_detail(self, serializer);
   }
 
   @protected
-  TrackDTO dco_decode_box_autoadd_track_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_track_dto(raw);
+  void sse_encode_box_autoadd_artist(Artist self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_artist(self, serializer);
   }
 
   @protected
-  int dco_decode_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_box_autoadd_artist_detail(
+      ArtistDetail self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_artist_detail(self, serializer);
   }
 
   @protected
-  int dco_decode_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeI64OrU64(raw);
+  void sse_encode_box_autoadd_audio_properties_filter(
+      AudioPropertiesFilter self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_audio_properties_filter(self, serializer);
   }
 
   @protected
-  Int32List dco_decode_list_prim_i_32_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Int32List;
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self, serializer);
   }
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
+  void sse_encode_box_autoadd_config(Config self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_config(self, serializer);
   }
 
   @protected
-  List<TrackDTO> dco_decode_list_track_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_track_dto).toList();
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_64(self, serializer);
   }
 
   @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
+  void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self, serializer);
   }
 
   @protected
-  Album? dco_decode_opt_box_autoadd_album(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_album(raw);
+  void sse_encode_box_autoadd_scan_progress(
+      ScanProgress self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_scan_progress(self, serializer);
   }
 
   @protected
-  Artist? dco_decode_opt_box_autoadd_artist(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_artist(raw);
+  void sse_encode_box_autoadd_sync_summary(
+      SyncSummary self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_sync_summary(self, serializer);
   }
 
   @protected
-  int? dco_decode_opt_box_autoadd_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_i_32(raw);
+  void sse_encode_box_autoadd_track_dto(
+      TrackDTO self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_track_dto(self, serializer);
   }
 
   @protected
-  TrackDTO dco_decode_track_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 10)
-      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
-    return TrackDTO(
-      id: dco_decode_i_32(arr[0]),
-      title: dco_decode_opt_String(arr[1]),
-      artist: dco_decode_opt_box_autoadd_artist(arr[2]),
-      album: dco_decode_opt_box_autoadd_album(arr[3]),
-      number: dco_decode_opt_box_autoadd_i_32(arr[4]),
-      disc: dco_decode_opt_box_autoadd_i_32(arr[5]),
-      durationMs: dco_decode_i_32(arr[6]),
-      location: dco_decode_String(arr[7]),
-      mountPoint: dco_decode_String(arr[8]),
-      pictureId: dco_decode_opt_String(arr[9]),
-    );
+  void sse_encode_box_autoadd_track_query(
+      TrackQuery self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_track_query(self, serializer);
   }
 
   @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self, serializer);
   }
 
   @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+  void sse_encode_box_track_filter(TrackFilter self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_track_filter(self, serializer);
   }
 
   @protected
-  Duration sse_decode_Chrono_Duration(SseDeserializer deserializer) {
+  void sse_encode_config(Config self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_64(deserializer);
-    return Duration(microseconds: inner);
+    sse_encode_list_String(self.extensions, serializer);
+    sse_encode_bool(self.detectByContent, serializer);
+    sse_encode_list_String(self.sniffedExtensions, serializer);
+    sse_encode_list_String(self.artistSeparators, serializer);
+    sse_encode_list_String(self.genreSeparators, serializer);
   }
 
   @protected
-  DateTime sse_decode_Chrono_Naive(SseDeserializer deserializer) {
+  void sse_encode_disc_group(DiscGroup self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_64(deserializer);
-    return DateTime.fromMicrosecondsSinceEpoch(inner, isUtc: true);
+    sse_encode_opt_box_autoadd_i_32(self.disc, serializer);
+    sse_encode_opt_String(self.subtitle, serializer);
+    sse_encode_list_track_dto(self.tracks, serializer);
   }
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer) {
+  void sse_encode_f_64(double self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+    serializer.buffer.putFloat64(self);
   }
 
   @protected
-  Album sse_decode_album(SseDeserializer deserializer) {
+  void sse_encode_genre(Genre self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_i_32(deserializer);
-    var var_name = sse_decode_String(deserializer);
-    var var_artistId = sse_decode_opt_box_autoadd_i_32(deserializer);
-    var var_createdAt = sse_decode_Chrono_Naive(deserializer);
-    return Album(
-        id: var_id,
-        name: var_name,
-        artistId: var_artistId,
-        createdAt: var_createdAt);
+    sse_encode_i_32(self.id, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_Chrono_Naive(self.createdAt, serializer);
+  }
+
+  @protected
+  void sse_encode_genre_summary(GenreSummary self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.id, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_i_32(self.trackCount, serializer);
+    sse_encode_i_32(self.albumCount, serializer);
+  }
+
+  @protected
+  void sse_encode_highlight_range(
+      HighlightRange self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.start, serializer);
+    sse_encode_u_32(self.end, serializer);
+  }
+
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putInt32(self);
+  }
+
+  @protected
+  void sse_encode_i_64(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putInt64(self);
+  }
+
+  @protected
+  void sse_encode_library_change_event(
+      LibraryChangeEvent self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_String(self.upserted, serializer);
+    sse_encode_list_String(self.removed, serializer);
+    sse_encode_list_moved_track(self.moved, serializer);
+  }
+
+  @protected
+  void sse_encode_library_root(LibraryRoot self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.id, serializer);
+    sse_encode_String(self.path, serializer);
+    sse_encode_bool(self.enabled, serializer);
+    sse_encode_i_32(self.trackCount, serializer);
+    sse_encode_opt_box_autoadd_Chrono_Naive(self.lastScannedAt, serializer);
+    sse_encode_Chrono_Naive(self.createdAt, serializer);
+  }
+
+  @protected
+  void sse_encode_library_root_availability(
+      LibraryRootAvailability self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.id, serializer);
+    sse_encode_String(self.path, serializer);
+    sse_encode_bool(self.available, serializer);
+  }
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_album(List<Album> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_album(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_album_summary(
+      List<AlbumSummary> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_album_summary(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_artist_summary(
+      List<ArtistSummary> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_artist_summary(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_disc_group(
+      List<DiscGroup> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_disc_group(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_genre(List<Genre> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_genre(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_genre_summary(
+      List<GenreSummary> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_genre_summary(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_highlight_range(
+      List<HighlightRange> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_highlight_range(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_library_root(
+      List<LibraryRoot> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_library_root(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_library_root_availability(
+      List<LibraryRootAvailability> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_library_root_availability(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_moved_track(
+      List<MovedTrack> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_moved_track(item, serializer);
+    }
   }
 
   @protected
-  Artist sse_decode_artist(SseDeserializer deserializer) {
+  void sse_encode_list_prim_i_32_loose(
+      List<int> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_i_32(deserializer);
-    var var_name = sse_decode_String(deserializer);
-    var var_createdAt = sse_decode_Chrono_Naive(deserializer);
-    return Artist(id: var_id, name: var_name, createdAt: var_createdAt);
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer
+        .putInt32List(self is Int32List ? self : Int32List.fromList(self));
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  void sse_encode_list_prim_i_32_strict(
+      Int32List self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putInt32List(self);
   }
 
   @protected
-  Album sse_decode_box_autoadd_album(SseDeserializer deserializer) {
+  void sse_encode_list_prim_u_8_strict(
+      Uint8List self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_album(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(self);
   }
 
   @protected
-  Artist sse_decode_box_autoadd_artist(SseDeserializer deserializer) {
+  void sse_encode_list_release_group(
+      List<ReleaseGroup> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_artist(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_release_group(item, serializer);
+    }
   }
 
   @protected
-  int sse_decode_box_autoadd_i_32(SseDeserializer deserializer) {
+  void sse_encode_list_scan_error(
+      List<ScanError> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_i_32(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_scan_error(item, serializer);
+    }
   }
 
   @protected
-  TrackDTO sse_decode_box_autoadd_track_dto(SseDeserializer deserializer) {
+  void sse_encode_list_sort_key(List<SortKey> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_track_dto(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_sort_key(item, serializer);
+    }
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  void sse_encode_list_sync_summary(
+      List<SyncSummary> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_sync_summary(item, serializer);
+    }
   }
 
   @protected
-  int sse_decode_i_64(SseDeserializer deserializer) {
+  void sse_encode_list_track_artist_dto(
+      List<TrackArtistDTO> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt64();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_track_artist_dto(item, serializer);
+    }
   }
 
   @protected
-  Int32List sse_decode_list_prim_i_32_strict(SseDeserializer deserializer) {
+  void sse_encode_list_track_dto(
+      List<TrackDTO> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getInt32List(len_);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_track_dto(item, serializer);
+    }
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void sse_encode_list_track_filter(
+      List<TrackFilter> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_track_filter(item, serializer);
+    }
   }
 
   @protected
-  List<TrackDTO> sse_decode_list_track_dto(SseDeserializer deserializer) {
+  void sse_encode_list_track_search_result(
+      List<TrackSearchResult> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <TrackDTO>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_track_dto(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_track_search_result(item, serializer);
     }
-    return ans_;
   }
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
+  void sse_encode_moved_track(MovedTrack self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
-    }
+    sse_encode_String(self.from, serializer);
+    sse_encode_String(self.to, serializer);
   }
 
   @protected
-  Album? sse_decode_opt_box_autoadd_album(SseDeserializer deserializer) {
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_album(deserializer));
-    } else {
-      return null;
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
     }
   }
 
   @protected
-  Artist? sse_decode_opt_box_autoadd_artist(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_Chrono_Naive(
+      DateTime? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_artist(deserializer));
-    } else {
-      return null;
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_Chrono_Naive(self, serializer);
     }
   }
 
   @protected
-  int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_album(Album? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_i_32(deserializer));
-    } else {
-      return null;
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_album(self, serializer);
     }
   }
 
   @protected
-  TrackDTO sse_decode_track_dto(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_album_detail(
+      AlbumDetail? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_i_32(deserializer);
-    var var_title = sse_decode_opt_String(deserializer);
-    var var_artist = sse_decode_opt_box_autoadd_artist(deserializer);
-    var var_album = sse_decode_opt_box_autoadd_album(deserializer);
-    var var_number = sse_decode_opt_box_autoadd_i_32(deserializer);
-    var var_disc = sse_decode_opt_box_autoadd_i_32(deserializer);
-    var var_durationMs = sse_decode_i_32(deserializer);
-    var var_location = sse_decode_String(deserializer);
-    var var_mountPoint = sse_decode_String(deserializer);
-    var var_pictureId = sse_decode_opt_String(deserializer);
-    return TrackDTO(
-        id: var_id,
-        title: var_title,
-        artist: var_artist,
-        album: var_album,
-        number: var_number,
-        disc: var_disc,
-        durationMs: var_durationMs,
-        location: var_location,
-        mountPoint: var_mountPoint,
-        pictureId: var_pictureId);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_album_detail(self, serializer);
+    }
   }
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_artist(
+      Artist? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_artist(self, serializer);
+    }
   }
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_artist_detail(
+      ArtistDetail? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_artist_detail(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_Chrono_Duration(Duration self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_64(self.inMicroseconds, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_bool(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_Chrono_Naive(DateTime self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_64(self.microsecondsSinceEpoch, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_f_64(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_i_32(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_album(Album self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.id, serializer);
-    sse_encode_String(self.name, serializer);
-    sse_encode_opt_box_autoadd_i_32(self.artistId, serializer);
-    sse_encode_Chrono_Naive(self.createdAt, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_32(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_artist(Artist self, SseSerializer serializer) {
+  void sse_encode_query_parse_error(
+      QueryParseError self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.id, serializer);
-    sse_encode_String(self.name, serializer);
-    sse_encode_Chrono_Naive(self.createdAt, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_u_32(self.start, serializer);
+    sse_encode_u_32(self.end, serializer);
   }
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
+  void sse_encode_release_group(ReleaseGroup self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+    sse_encode_opt_String(self.releaseType, serializer);
+    sse_encode_list_album_summary(self.albums, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_album(Album self, SseSerializer serializer) {
+  void sse_encode_scan_error(ScanError self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_album(self, serializer);
+    sse_encode_i_32(self.id, serializer);
+    sse_encode_String(self.location, serializer);
+    sse_encode_String(self.mountPoint, serializer);
+    sse_encode_String(self.kind, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_Chrono_Naive(self.occurredAt, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_artist(Artist self, SseSerializer serializer) {
+  void sse_encode_scan_event(ScanEvent self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_artist(self, serializer);
+    switch (self) {
+      case ScanEvent_Progress(field0: final field0):
+        sse_encode_i_32(0, serializer);
+        sse_encode_box_autoadd_scan_progress(field0, serializer);
+      case ScanEvent_Error(
+          path: final path,
+          kind: final kind,
+          message: final message
+        ):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(path, serializer);
+        sse_encode_String(kind, serializer);
+        sse_encode_String(message, serializer);
+      case ScanEvent_Finished(field0: final field0):
+        sse_encode_i_32(2, serializer);
+        sse_encode_box_autoadd_sync_summary(field0, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer) {
+  void sse_encode_scan_progress(ScanProgress self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self, serializer);
+    sse_encode_u_32(self.discovered, serializer);
+    sse_encode_u_32(self.parsed, serializer);
+    sse_encode_u_32(self.written, serializer);
+    sse_encode_String(self.currentPath, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_track_dto(
-      TrackDTO self, SseSerializer serializer) {
+  void sse_encode_sort_field(SortField self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_track_dto(self, serializer);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
+  void sse_encode_sort_key(SortKey self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
+    sse_encode_sort_field(self.field, serializer);
+    sse_encode_bool(self.descending, serializer);
   }
 
   @protected
-  void sse_encode_i_64(int self, SseSerializer serializer) {
+  void sse_encode_sync_summary(SyncSummary self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt64(self);
+    sse_encode_u_32(self.added, serializer);
+    sse_encode_u_32(self.updated, serializer);
+    sse_encode_u_32(self.unchanged, serializer);
+    sse_encode_u_32(self.failed, serializer);
+    sse_encode_u_32(self.removed, serializer);
+    sse_encode_bool(self.cancelled, serializer);
   }
 
   @protected
-  void sse_encode_list_prim_i_32_strict(
-      Int32List self, SseSerializer serializer) {
+  void sse_encode_text_field(TextField self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putInt32List(self);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-      Uint8List self, SseSerializer serializer) {
+  void sse_encode_track_artist_dto(
+      TrackArtistDTO self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
+    sse_encode_artist(self.artist, serializer);
+    sse_encode_artist_role(self.role, serializer);
   }
 
   @protected
-  void sse_encode_list_track_dto(
-      List<TrackDTO> self, SseSerializer serializer) {
+  void sse_encode_track_dto(TrackDTO self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_track_dto(item, serializer);
-    }
+    sse_encode_i_32(self.id, serializer);
+    sse_encode_opt_String(self.title, serializer);
+    sse_encode_opt_String(self.titleRomaji, serializer);
+    sse_encode_opt_String(self.titleHiragana, serializer);
+    sse_encode_opt_box_autoadd_artist(self.artist, serializer);
+    sse_encode_opt_box_autoadd_album(self.album, serializer);
+    sse_encode_opt_box_autoadd_artist(self.albumArtist, serializer);
+    sse_encode_list_track_artist_dto(self.artists, serializer);
+    sse_encode_opt_box_autoadd_i_32(self.number, serializer);
+    sse_encode_opt_box_autoadd_i_32(self.disc, serializer);
+    sse_encode_i_32(self.durationMs, serializer);
+    sse_encode_String(self.location, serializer);
+    sse_encode_String(self.mountPoint, serializer);
+    sse_encode_opt_String(self.pictureId, serializer);
+    sse_encode_bool(self.available, serializer);
+    sse_encode_opt_box_autoadd_i_32(self.year, serializer);
+    sse_encode_opt_String(self.date, serializer);
+    sse_encode_opt_String(self.originalDate, serializer);
+    sse_encode_opt_String(self.genre, serializer);
+    sse_encode_list_genre(self.genres, serializer);
+    sse_encode_opt_String(self.composer, serializer);
+    sse_encode_opt_String(self.comment, serializer);
+    sse_encode_opt_box_autoadd_i_32(self.trackTotal, serializer);
+    sse_encode_opt_box_autoadd_i_32(self.discTotal, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.bpm, serializer);
+    sse_encode_opt_String(self.isrc, serializer);
+    sse_encode_opt_String(self.label, serializer);
+    sse_encode_opt_String(self.codec, serializer);
+    sse_encode_opt_box_autoadd_i_32(self.bitrate, serializer);
+    sse_encode_opt_box_autoadd_i_32(self.sampleRate, serializer);
+    sse_encode_opt_box_autoadd_i_32(self.bitDepth, serializer);
+    sse_encode_opt_box_autoadd_i_32(self.channels, serializer);
+    sse_encode_i_64(self.fileSize, serializer);
+    sse_encode_bool(self.lossless, serializer);
+    sse_encode_opt_String(self.discSubtitle, serializer);
+    sse_encode_i_32(self.playCount, serializer);
+    sse_encode_opt_box_autoadd_Chrono_Naive(self.lastPlayedAt, serializer);
   }
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+  void sse_encode_track_filter(TrackFilter self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_String(self, serializer);
+    switch (self) {
+      case TrackFilter_Artist(field0: final field0):
+        sse_encode_i_32(0, serializer);
+        sse_encode_i_32(field0, serializer);
+      case TrackFilter_Album(field0: final field0):
+        sse_encode_i_32(1, serializer);
+        sse_encode_i_32(field0, serializer);
+      case TrackFilter_Genre(field0: final field0):
+        sse_encode_i_32(2, serializer);
+        sse_encode_i_32(field0, serializer);
+      case TrackFilter_Year(min: final min, max: final max):
+        sse_encode_i_32(3, serializer);
+        sse_encode_opt_box_autoadd_i_32(min, serializer);
+        sse_encode_opt_box_autoadd_i_32(max, serializer);
+      case TrackFilter_DurationMs(min: final min, max: final max):
+        sse_encode_i_32(4, serializer);
+        sse_encode_opt_box_autoadd_i_32(min, serializer);
+        sse_encode_opt_box_autoadd_i_32(max, serializer);
+      case TrackFilter_Codec(field0: final field0):
+        sse_encode_i_32(5, serializer);
+        sse_encode_String(field0, serializer);
+      case TrackFilter_FileExtension(field0: final field0):
+        sse_encode_i_32(6, serializer);
+        sse_encode_String(field0, serializer);
+      case TrackFilter_Lossless(field0: final field0):
+        sse_encode_i_32(7, serializer);
+        sse_encode_bool(field0, serializer);
+      case TrackFilter_Bitrate(min: final min, max: final max):
+        sse_encode_i_32(8, serializer);
+        sse_encode_opt_box_autoadd_i_32(min, serializer);
+        sse_encode_opt_box_autoadd_i_32(max, serializer);
+      case TrackFilter_MinSampleRate(field0: final field0):
+        sse_encode_i_32(9, serializer);
+        sse_encode_i_32(field0, serializer);
+      case TrackFilter_MinBitDepth(field0: final field0):
+        sse_encode_i_32(10, serializer);
+        sse_encode_i_32(field0, serializer);
+      case TrackFilter_Channels(field0: final field0):
+        sse_encode_i_32(11, serializer);
+        sse_encode_i_32(field0, serializer);
+      case TrackFilter_LibraryRoot(field0: final field0):
+        sse_encode_i_32(12, serializer);
+        sse_encode_i_32(field0, serializer);
+      case TrackFilter_Text(field: final field, text: final text):
+        sse_encode_i_32(13, serializer);
+        sse_encode_text_field(field, serializer);
+        sse_encode_String(text, serializer);
+      case TrackFilter_Not(field0: final field0):
+        sse_encode_i_32(14, serializer);
+        sse_encode_box_track_filter(field0, serializer);
     }
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_album(Album? self, SseSerializer serializer) {
+  void sse_encode_track_page(TrackPage self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_album(self, serializer);
-    }
+    sse_encode_list_track_dto(self.tracks, serializer);
+    sse_encode_u_32(self.total, serializer);
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_artist(
-      Artist? self, SseSerializer serializer) {
+  void sse_encode_track_query(TrackQuery self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_artist(self, serializer);
-    }
+    sse_encode_list_sort_key(self.sort, serializer);
+    sse_encode_list_track_filter(self.filters, serializer);
+    sse_encode_bool(self.hideOffline, serializer);
+    sse_encode_u_32(self.offset, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.limit, serializer);
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer) {
+  void sse_encode_track_search_result(
+      TrackSearchResult self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_i_32(self, serializer);
-    }
+    sse_encode_track_dto(self.track, serializer);
+    sse_encode_f_64(self.score, serializer);
+    sse_encode_list_highlight_range(self.titleRanges, serializer);
+    sse_encode_list_highlight_range(self.albumRanges, serializer);
+    sse_encode_list_highlight_range(self.artistRanges, serializer);
   }
 
   @protected
-  void sse_encode_track_dto(TrackDTO self, SseSerializer serializer) {
+  void sse_encode_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.id, serializer);
-    sse_encode_opt_String(self.title, serializer);
-    sse_encode_opt_box_autoadd_artist(self.artist, serializer);
-    sse_encode_opt_box_autoadd_album(self.album, serializer);
-    sse_encode_opt_box_autoadd_i_32(self.number, serializer);
-    sse_encode_opt_box_autoadd_i_32(self.disc, serializer);
-    sse_encode_i_32(self.durationMs, serializer);
-    sse_encode_String(self.location, serializer);
-    sse_encode_String(self.mountPoint, serializer);
-    sse_encode_opt_String(self.pictureId, serializer);
+    serializer.buffer.putUint32(self);
   }
 
   @protected
@@ -1071,4 +4598,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   void sse_encode_unit(void self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
   }
+
+  @protected
+  void sse_encode_usize(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint64(self);
+  }
 }
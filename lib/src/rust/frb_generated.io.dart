@@ -3,8 +3,17 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/browse.dart';
+import 'api/config.dart';
+import 'api/fuzzy_search.dart';
+import 'api/library.dart';
+import 'api/query.dart';
+import 'api/query_language.dart';
+import 'api/scan.dart';
+import 'api/search.dart';
 import 'api/simple.dart';
 import 'api/utils.dart';
+import 'api/watcher.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'dart:ffi' as ffi;
@@ -20,175 +29,804 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     required super.portManager,
   });
 
+  CrossPlatformFinalizerArg
+      get rust_arc_decrement_strong_count_ScanCancelTokenPtr => wire
+          ._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelTokenPtr;
+
+  @protected
+  ScanCancelToken
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(
+          dynamic raw);
+
+  @protected
+  ScanCancelToken
+      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(
+          dynamic raw);
+
   @protected
   Duration dco_decode_Chrono_Duration(dynamic raw);
 
   @protected
   DateTime dco_decode_Chrono_Naive(dynamic raw);
 
+  @protected
+  ScanCancelToken
+      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(
+          dynamic raw);
+
   @protected
   String dco_decode_String(dynamic raw);
 
   @protected
   Album dco_decode_album(dynamic raw);
 
+  @protected
+  AlbumDetail dco_decode_album_detail(dynamic raw);
+
+  @protected
+  AlbumSummary dco_decode_album_summary(dynamic raw);
+
   @protected
   Artist dco_decode_artist(dynamic raw);
 
+  @protected
+  ArtistDetail dco_decode_artist_detail(dynamic raw);
+
+  @protected
+  ArtistRole dco_decode_artist_role(dynamic raw);
+
+  @protected
+  ArtistSummary dco_decode_artist_summary(dynamic raw);
+
+  @protected
+  AudioPropertiesFilter dco_decode_audio_properties_filter(dynamic raw);
+
+  @protected
+  AudioProperty dco_decode_audio_property(dynamic raw);
+
   @protected
   bool dco_decode_bool(dynamic raw);
 
+  @protected
+  DateTime dco_decode_box_autoadd_Chrono_Naive(dynamic raw);
+
   @protected
   Album dco_decode_box_autoadd_album(dynamic raw);
 
+  @protected
+  AlbumDetail dco_decode_box_autoadd_album_detail(dynamic raw);
+
   @protected
   Artist dco_decode_box_autoadd_artist(dynamic raw);
 
+  @protected
+  ArtistDetail dco_decode_box_autoadd_artist_detail(dynamic raw);
+
+  @protected
+  AudioPropertiesFilter dco_decode_box_autoadd_audio_properties_filter(
+      dynamic raw);
+
+  @protected
+  bool dco_decode_box_autoadd_bool(dynamic raw);
+
+  @protected
+  Config dco_decode_box_autoadd_config(dynamic raw);
+
+  @protected
+  double dco_decode_box_autoadd_f_64(dynamic raw);
+
   @protected
   int dco_decode_box_autoadd_i_32(dynamic raw);
 
+  @protected
+  ScanProgress dco_decode_box_autoadd_scan_progress(dynamic raw);
+
+  @protected
+  SyncSummary dco_decode_box_autoadd_sync_summary(dynamic raw);
+
   @protected
   TrackDTO dco_decode_box_autoadd_track_dto(dynamic raw);
 
+  @protected
+  TrackQuery dco_decode_box_autoadd_track_query(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  TrackFilter dco_decode_box_track_filter(dynamic raw);
+
+  @protected
+  Config dco_decode_config(dynamic raw);
+
+  @protected
+  DiscGroup dco_decode_disc_group(dynamic raw);
+
+  @protected
+  double dco_decode_f_64(dynamic raw);
+
+  @protected
+  Genre dco_decode_genre(dynamic raw);
+
+  @protected
+  GenreSummary dco_decode_genre_summary(dynamic raw);
+
+  @protected
+  HighlightRange dco_decode_highlight_range(dynamic raw);
+
   @protected
   int dco_decode_i_32(dynamic raw);
 
   @protected
   int dco_decode_i_64(dynamic raw);
 
+  @protected
+  LibraryChangeEvent dco_decode_library_change_event(dynamic raw);
+
+  @protected
+  LibraryRoot dco_decode_library_root(dynamic raw);
+
+  @protected
+  LibraryRootAvailability dco_decode_library_root_availability(dynamic raw);
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw);
+
+  @protected
+  List<Album> dco_decode_list_album(dynamic raw);
+
+  @protected
+  List<AlbumSummary> dco_decode_list_album_summary(dynamic raw);
+
+  @protected
+  List<ArtistSummary> dco_decode_list_artist_summary(dynamic raw);
+
+  @protected
+  List<DiscGroup> dco_decode_list_disc_group(dynamic raw);
+
+  @protected
+  List<Genre> dco_decode_list_genre(dynamic raw);
+
+  @protected
+  List<GenreSummary> dco_decode_list_genre_summary(dynamic raw);
+
+  @protected
+  List<HighlightRange> dco_decode_list_highlight_range(dynamic raw);
+
+  @protected
+  List<LibraryRoot> dco_decode_list_library_root(dynamic raw);
+
+  @protected
+  List<LibraryRootAvailability> dco_decode_list_library_root_availability(
+      dynamic raw);
+
+  @protected
+  List<MovedTrack> dco_decode_list_moved_track(dynamic raw);
+
+  @protected
+  List<int> dco_decode_list_prim_i_32_loose(dynamic raw);
+
   @protected
   Int32List dco_decode_list_prim_i_32_strict(dynamic raw);
 
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
+  @protected
+  List<ReleaseGroup> dco_decode_list_release_group(dynamic raw);
+
+  @protected
+  List<ScanError> dco_decode_list_scan_error(dynamic raw);
+
+  @protected
+  List<SortKey> dco_decode_list_sort_key(dynamic raw);
+
+  @protected
+  List<SyncSummary> dco_decode_list_sync_summary(dynamic raw);
+
+  @protected
+  List<TrackArtistDTO> dco_decode_list_track_artist_dto(dynamic raw);
+
   @protected
   List<TrackDTO> dco_decode_list_track_dto(dynamic raw);
 
+  @protected
+  List<TrackFilter> dco_decode_list_track_filter(dynamic raw);
+
+  @protected
+  List<TrackSearchResult> dco_decode_list_track_search_result(dynamic raw);
+
+  @protected
+  MovedTrack dco_decode_moved_track(dynamic raw);
+
   @protected
   String? dco_decode_opt_String(dynamic raw);
 
+  @protected
+  DateTime? dco_decode_opt_box_autoadd_Chrono_Naive(dynamic raw);
+
   @protected
   Album? dco_decode_opt_box_autoadd_album(dynamic raw);
 
+  @protected
+  AlbumDetail? dco_decode_opt_box_autoadd_album_detail(dynamic raw);
+
   @protected
   Artist? dco_decode_opt_box_autoadd_artist(dynamic raw);
 
+  @protected
+  ArtistDetail? dco_decode_opt_box_autoadd_artist_detail(dynamic raw);
+
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
+
   @protected
   int? dco_decode_opt_box_autoadd_i_32(dynamic raw);
 
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  QueryParseError dco_decode_query_parse_error(dynamic raw);
+
+  @protected
+  ReleaseGroup dco_decode_release_group(dynamic raw);
+
+  @protected
+  ScanError dco_decode_scan_error(dynamic raw);
+
+  @protected
+  ScanEvent dco_decode_scan_event(dynamic raw);
+
+  @protected
+  ScanProgress dco_decode_scan_progress(dynamic raw);
+
+  @protected
+  SortField dco_decode_sort_field(dynamic raw);
+
+  @protected
+  SortKey dco_decode_sort_key(dynamic raw);
+
+  @protected
+  SyncSummary dco_decode_sync_summary(dynamic raw);
+
+  @protected
+  TextField dco_decode_text_field(dynamic raw);
+
+  @protected
+  TrackArtistDTO dco_decode_track_artist_dto(dynamic raw);
+
   @protected
   TrackDTO dco_decode_track_dto(dynamic raw);
 
+  @protected
+  TrackFilter dco_decode_track_filter(dynamic raw);
+
+  @protected
+  TrackPage dco_decode_track_page(dynamic raw);
+
+  @protected
+  TrackQuery dco_decode_track_query(dynamic raw);
+
+  @protected
+  TrackSearchResult dco_decode_track_search_result(dynamic raw);
+
+  @protected
+  int dco_decode_u_32(dynamic raw);
+
   @protected
   int dco_decode_u_8(dynamic raw);
 
   @protected
   void dco_decode_unit(dynamic raw);
 
+  @protected
+  int dco_decode_usize(dynamic raw);
+
+  @protected
+  ScanCancelToken
+      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(
+          SseDeserializer deserializer);
+
+  @protected
+  ScanCancelToken
+      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(
+          SseDeserializer deserializer);
+
   @protected
   Duration sse_decode_Chrono_Duration(SseDeserializer deserializer);
 
   @protected
   DateTime sse_decode_Chrono_Naive(SseDeserializer deserializer);
 
+  @protected
+  ScanCancelToken
+      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(
+          SseDeserializer deserializer);
+
   @protected
   String sse_decode_String(SseDeserializer deserializer);
 
   @protected
   Album sse_decode_album(SseDeserializer deserializer);
 
+  @protected
+  AlbumDetail sse_decode_album_detail(SseDeserializer deserializer);
+
+  @protected
+  AlbumSummary sse_decode_album_summary(SseDeserializer deserializer);
+
   @protected
   Artist sse_decode_artist(SseDeserializer deserializer);
 
+  @protected
+  ArtistDetail sse_decode_artist_detail(SseDeserializer deserializer);
+
+  @protected
+  ArtistRole sse_decode_artist_role(SseDeserializer deserializer);
+
+  @protected
+  ArtistSummary sse_decode_artist_summary(SseDeserializer deserializer);
+
+  @protected
+  AudioPropertiesFilter sse_decode_audio_properties_filter(
+      SseDeserializer deserializer);
+
+  @protected
+  AudioProperty sse_decode_audio_property(SseDeserializer deserializer);
+
   @protected
   bool sse_decode_bool(SseDeserializer deserializer);
 
+  @protected
+  DateTime sse_decode_box_autoadd_Chrono_Naive(SseDeserializer deserializer);
+
   @protected
   Album sse_decode_box_autoadd_album(SseDeserializer deserializer);
 
+  @protected
+  AlbumDetail sse_decode_box_autoadd_album_detail(SseDeserializer deserializer);
+
   @protected
   Artist sse_decode_box_autoadd_artist(SseDeserializer deserializer);
 
+  @protected
+  ArtistDetail sse_decode_box_autoadd_artist_detail(
+      SseDeserializer deserializer);
+
+  @protected
+  AudioPropertiesFilter sse_decode_box_autoadd_audio_properties_filter(
+      SseDeserializer deserializer);
+
+  @protected
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
+
+  @protected
+  Config sse_decode_box_autoadd_config(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
+
   @protected
   int sse_decode_box_autoadd_i_32(SseDeserializer deserializer);
 
+  @protected
+  ScanProgress sse_decode_box_autoadd_scan_progress(
+      SseDeserializer deserializer);
+
+  @protected
+  SyncSummary sse_decode_box_autoadd_sync_summary(SseDeserializer deserializer);
+
   @protected
   TrackDTO sse_decode_box_autoadd_track_dto(SseDeserializer deserializer);
 
+  @protected
+  TrackQuery sse_decode_box_autoadd_track_query(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  TrackFilter sse_decode_box_track_filter(SseDeserializer deserializer);
+
+  @protected
+  Config sse_decode_config(SseDeserializer deserializer);
+
+  @protected
+  DiscGroup sse_decode_disc_group(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer);
+
+  @protected
+  Genre sse_decode_genre(SseDeserializer deserializer);
+
+  @protected
+  GenreSummary sse_decode_genre_summary(SseDeserializer deserializer);
+
+  @protected
+  HighlightRange sse_decode_highlight_range(SseDeserializer deserializer);
+
   @protected
   int sse_decode_i_32(SseDeserializer deserializer);
 
   @protected
   int sse_decode_i_64(SseDeserializer deserializer);
 
+  @protected
+  LibraryChangeEvent sse_decode_library_change_event(
+      SseDeserializer deserializer);
+
+  @protected
+  LibraryRoot sse_decode_library_root(SseDeserializer deserializer);
+
+  @protected
+  LibraryRootAvailability sse_decode_library_root_availability(
+      SseDeserializer deserializer);
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<Album> sse_decode_list_album(SseDeserializer deserializer);
+
+  @protected
+  List<AlbumSummary> sse_decode_list_album_summary(
+      SseDeserializer deserializer);
+
+  @protected
+  List<ArtistSummary> sse_decode_list_artist_summary(
+      SseDeserializer deserializer);
+
+  @protected
+  List<DiscGroup> sse_decode_list_disc_group(SseDeserializer deserializer);
+
+  @protected
+  List<Genre> sse_decode_list_genre(SseDeserializer deserializer);
+
+  @protected
+  List<GenreSummary> sse_decode_list_genre_summary(
+      SseDeserializer deserializer);
+
+  @protected
+  List<HighlightRange> sse_decode_list_highlight_range(
+      SseDeserializer deserializer);
+
+  @protected
+  List<LibraryRoot> sse_decode_list_library_root(SseDeserializer deserializer);
+
+  @protected
+  List<LibraryRootAvailability> sse_decode_list_library_root_availability(
+      SseDeserializer deserializer);
+
+  @protected
+  List<MovedTrack> sse_decode_list_moved_track(SseDeserializer deserializer);
+
+  @protected
+  List<int> sse_decode_list_prim_i_32_loose(SseDeserializer deserializer);
+
   @protected
   Int32List sse_decode_list_prim_i_32_strict(SseDeserializer deserializer);
 
   @protected
   Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
+  @protected
+  List<ReleaseGroup> sse_decode_list_release_group(
+      SseDeserializer deserializer);
+
+  @protected
+  List<ScanError> sse_decode_list_scan_error(SseDeserializer deserializer);
+
+  @protected
+  List<SortKey> sse_decode_list_sort_key(SseDeserializer deserializer);
+
+  @protected
+  List<SyncSummary> sse_decode_list_sync_summary(SseDeserializer deserializer);
+
+  @protected
+  List<TrackArtistDTO> sse_decode_list_track_artist_dto(
+      SseDeserializer deserializer);
+
   @protected
   List<TrackDTO> sse_decode_list_track_dto(SseDeserializer deserializer);
 
+  @protected
+  List<TrackFilter> sse_decode_list_track_filter(SseDeserializer deserializer);
+
+  @protected
+  List<TrackSearchResult> sse_decode_list_track_search_result(
+      SseDeserializer deserializer);
+
+  @protected
+  MovedTrack sse_decode_moved_track(SseDeserializer deserializer);
+
   @protected
   String? sse_decode_opt_String(SseDeserializer deserializer);
 
+  @protected
+  DateTime? sse_decode_opt_box_autoadd_Chrono_Naive(
+      SseDeserializer deserializer);
+
   @protected
   Album? sse_decode_opt_box_autoadd_album(SseDeserializer deserializer);
 
+  @protected
+  AlbumDetail? sse_decode_opt_box_autoadd_album_detail(
+      SseDeserializer deserializer);
+
   @protected
   Artist? sse_decode_opt_box_autoadd_artist(SseDeserializer deserializer);
 
+  @protected
+  ArtistDetail? sse_decode_opt_box_autoadd_artist_detail(
+      SseDeserializer deserializer);
+
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
+
   @protected
   int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer);
 
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  QueryParseError sse_decode_query_parse_error(SseDeserializer deserializer);
+
+  @protected
+  ReleaseGroup sse_decode_release_group(SseDeserializer deserializer);
+
+  @protected
+  ScanError sse_decode_scan_error(SseDeserializer deserializer);
+
+  @protected
+  ScanEvent sse_decode_scan_event(SseDeserializer deserializer);
+
+  @protected
+  ScanProgress sse_decode_scan_progress(SseDeserializer deserializer);
+
+  @protected
+  SortField sse_decode_sort_field(SseDeserializer deserializer);
+
+  @protected
+  SortKey sse_decode_sort_key(SseDeserializer deserializer);
+
+  @protected
+  SyncSummary sse_decode_sync_summary(SseDeserializer deserializer);
+
+  @protected
+  TextField sse_decode_text_field(SseDeserializer deserializer);
+
+  @protected
+  TrackArtistDTO sse_decode_track_artist_dto(SseDeserializer deserializer);
+
   @protected
   TrackDTO sse_decode_track_dto(SseDeserializer deserializer);
 
+  @protected
+  TrackFilter sse_decode_track_filter(SseDeserializer deserializer);
+
+  @protected
+  TrackPage sse_decode_track_page(SseDeserializer deserializer);
+
+  @protected
+  TrackQuery sse_decode_track_query(SseDeserializer deserializer);
+
+  @protected
+  TrackSearchResult sse_decode_track_search_result(
+      SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
   @protected
   int sse_decode_u_8(SseDeserializer deserializer);
 
   @protected
   void sse_decode_unit(SseDeserializer deserializer);
 
+  @protected
+  int sse_decode_usize(SseDeserializer deserializer);
+
+  @protected
+  void
+      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(
+          ScanCancelToken self, SseSerializer serializer);
+
+  @protected
+  void
+      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(
+          ScanCancelToken self, SseSerializer serializer);
+
   @protected
   void sse_encode_Chrono_Duration(Duration self, SseSerializer serializer);
 
   @protected
   void sse_encode_Chrono_Naive(DateTime self, SseSerializer serializer);
 
+  @protected
+  void
+      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(
+          ScanCancelToken self, SseSerializer serializer);
+
   @protected
   void sse_encode_String(String self, SseSerializer serializer);
 
   @protected
   void sse_encode_album(Album self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_album_detail(AlbumDetail self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_album_summary(AlbumSummary self, SseSerializer serializer);
+
   @protected
   void sse_encode_artist(Artist self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_artist_detail(ArtistDetail self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_artist_role(ArtistRole self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_artist_summary(ArtistSummary self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_audio_properties_filter(
+      AudioPropertiesFilter self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_audio_property(AudioProperty self, SseSerializer serializer);
+
   @protected
   void sse_encode_bool(bool self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_Chrono_Naive(
+      DateTime self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_album(Album self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_album_detail(
+      AlbumDetail self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_artist(Artist self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_artist_detail(
+      ArtistDetail self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_audio_properties_filter(
+      AudioPropertiesFilter self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_config(Config self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_scan_progress(
+      ScanProgress self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_sync_summary(
+      SyncSummary self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_track_dto(
       TrackDTO self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_track_query(
+      TrackQuery self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_track_filter(TrackFilter self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_config(Config self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_disc_group(DiscGroup self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_f_64(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_genre(Genre self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_genre_summary(GenreSummary self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_highlight_range(
+      HighlightRange self, SseSerializer serializer);
+
   @protected
   void sse_encode_i_32(int self, SseSerializer serializer);
 
   @protected
   void sse_encode_i_64(int self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_library_change_event(
+      LibraryChangeEvent self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_library_root(LibraryRoot self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_library_root_availability(
+      LibraryRootAvailability self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_album(List<Album> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_album_summary(
+      List<AlbumSummary> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_artist_summary(
+      List<ArtistSummary> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_disc_group(
+      List<DiscGroup> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_genre(List<Genre> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_genre_summary(
+      List<GenreSummary> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_highlight_range(
+      List<HighlightRange> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_library_root(
+      List<LibraryRoot> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_library_root_availability(
+      List<LibraryRootAvailability> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_moved_track(
+      List<MovedTrack> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_prim_i_32_loose(
+      List<int> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_prim_i_32_strict(
       Int32List self, SseSerializer serializer);
@@ -197,30 +835,132 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_list_prim_u_8_strict(
       Uint8List self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_release_group(
+      List<ReleaseGroup> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_scan_error(
+      List<ScanError> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_sort_key(List<SortKey> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_sync_summary(
+      List<SyncSummary> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_track_artist_dto(
+      List<TrackArtistDTO> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_track_dto(List<TrackDTO> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_track_filter(
+      List<TrackFilter> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_track_search_result(
+      List<TrackSearchResult> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_moved_track(MovedTrack self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_String(String? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_Chrono_Naive(
+      DateTime? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_album(Album? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_album_detail(
+      AlbumDetail? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_artist(
       Artist? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_artist_detail(
+      ArtistDetail? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_query_parse_error(
+      QueryParseError self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_release_group(ReleaseGroup self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_scan_error(ScanError self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_scan_event(ScanEvent self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_scan_progress(ScanProgress self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_sort_field(SortField self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_sort_key(SortKey self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_sync_summary(SyncSummary self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_text_field(TextField self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_track_artist_dto(
+      TrackArtistDTO self, SseSerializer serializer);
+
   @protected
   void sse_encode_track_dto(TrackDTO self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_track_filter(TrackFilter self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_track_page(TrackPage self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_track_query(TrackQuery self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_track_search_result(
+      TrackSearchResult self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_8(int self, SseSerializer serializer);
 
   @protected
   void sse_encode_unit(void self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_usize(int self, SseSerializer serializer);
 }
 
 // Section: wire_class
@@ -236,4 +976,36 @@ class RustLibWire implements BaseWire {
   /// The symbols are looked up in [dynamicLibrary].
   RustLibWire(ffi.DynamicLibrary dynamicLibrary)
       : _lookup = dynamicLibrary.lookup;
+
+  void
+      rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(
+      ptr,
+    );
+  }
+
+  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelTokenPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
+          'frbgen_ncudio_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken');
+  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken =
+      _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelTokenPtr
+          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+
+  void
+      rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(
+      ptr,
+    );
+  }
+
+  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelTokenPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
+          'frbgen_ncudio_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken');
+  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken =
+      _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelTokenPtr
+          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 }
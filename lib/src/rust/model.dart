@@ -11,17 +11,34 @@ class Album {
   final String name;
   final int? artistId;
   final DateTime createdAt;
+  final String? musicbrainzReleaseId;
+  /// Lowercase MusicBrainz release type, e.g. `album`, `ep`, `single`,
+  /// `compilation` or `live`.
+  final String? releaseType;
+  final String? nameRomaji;
+  final String? nameHiragana;
 
   const Album({
     required this.id,
     required this.name,
     this.artistId,
     required this.createdAt,
+    this.musicbrainzReleaseId,
+    this.releaseType,
+    this.nameRomaji,
+    this.nameHiragana,
   });
 
   @override
   int get hashCode =>
-      id.hashCode ^ name.hashCode ^ artistId.hashCode ^ createdAt.hashCode;
+      id.hashCode ^
+      name.hashCode ^
+      artistId.hashCode ^
+      createdAt.hashCode ^
+      musicbrainzReleaseId.hashCode ^
+      releaseType.hashCode ^
+      nameRomaji.hashCode ^
+      nameHiragana.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -31,22 +48,35 @@ class Album {
           id == other.id &&
           name == other.name &&
           artistId == other.artistId &&
-          createdAt == other.createdAt;
+          createdAt == other.createdAt &&
+          musicbrainzReleaseId == other.musicbrainzReleaseId &&
+          releaseType == other.releaseType &&
+          nameRomaji == other.nameRomaji &&
+          nameHiragana == other.nameHiragana;
 }
 
 class Artist {
   final int id;
   final String name;
   final DateTime createdAt;
+  final String? nameRomaji;
+  final String? nameHiragana;
 
   const Artist({
     required this.id,
     required this.name,
     required this.createdAt,
+    this.nameRomaji,
+    this.nameHiragana,
   });
 
   @override
-  int get hashCode => id.hashCode ^ name.hashCode ^ createdAt.hashCode;
+  int get hashCode =>
+      id.hashCode ^
+      name.hashCode ^
+      createdAt.hashCode ^
+      nameRomaji.hashCode ^
+      nameHiragana.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -55,5 +85,111 @@ class Artist {
           runtimeType == other.runtimeType &&
           id == other.id &&
           name == other.name &&
+          createdAt == other.createdAt &&
+          nameRomaji == other.nameRomaji &&
+          nameHiragana == other.nameHiragana;
+}
+
+class Genre {
+  final int id;
+  final String name;
+  final DateTime createdAt;
+
+  const Genre({
+    required this.id,
+    required this.name,
+    required this.createdAt,
+  });
+
+  @override
+  int get hashCode => id.hashCode ^ name.hashCode ^ createdAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Genre &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          name == other.name &&
+          createdAt == other.createdAt;
+}
+
+class LibraryRoot {
+  final int id;
+  final String path;
+  final bool enabled;
+  final int trackCount;
+  final DateTime? lastScannedAt;
+  final DateTime createdAt;
+
+  const LibraryRoot({
+    required this.id,
+    required this.path,
+    required this.enabled,
+    required this.trackCount,
+    this.lastScannedAt,
+    required this.createdAt,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      path.hashCode ^
+      enabled.hashCode ^
+      trackCount.hashCode ^
+      lastScannedAt.hashCode ^
+      createdAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LibraryRoot &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          path == other.path &&
+          enabled == other.enabled &&
+          trackCount == other.trackCount &&
+          lastScannedAt == other.lastScannedAt &&
           createdAt == other.createdAt;
 }
+
+/// A file that failed to import during a scan.
+class ScanError {
+  final int id;
+  final String location;
+  final String mountPoint;
+  /// One of `io`, `unsupported_format`, `decode` or `database`.
+  final String kind;
+  final String message;
+  final DateTime occurredAt;
+
+  const ScanError({
+    required this.id,
+    required this.location,
+    required this.mountPoint,
+    required this.kind,
+    required this.message,
+    required this.occurredAt,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      location.hashCode ^
+      mountPoint.hashCode ^
+      kind.hashCode ^
+      message.hashCode ^
+      occurredAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ScanError &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          location == other.location &&
+          mountPoint == other.mountPoint &&
+          kind == other.kind &&
+          message == other.message &&
+          occurredAt == other.occurredAt;
+}
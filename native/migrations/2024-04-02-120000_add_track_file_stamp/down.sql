@@ -0,0 +1,2 @@
+ALTER TABLE track DROP COLUMN file_modified_at;
+ALTER TABLE track DROP COLUMN file_size;
@@ -0,0 +1,2 @@
+ALTER TABLE track ADD COLUMN file_size BIGINT NOT NULL DEFAULT 0;
+ALTER TABLE track ADD COLUMN file_modified_at TIMESTAMP;
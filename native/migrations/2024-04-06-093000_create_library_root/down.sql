@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS index_track_mount_point;
+DROP TABLE IF EXISTS library_root;
@@ -0,0 +1,20 @@
+CREATE TABLE IF NOT EXISTS library_root (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    path TEXT NOT NULL UNIQUE,
+    enabled BOOLEAN NOT NULL DEFAULT 1,
+    track_count INTEGER NOT NULL DEFAULT 0,
+    last_scanned_at TIMESTAMP,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS index_library_root_path ON library_root(path);
+
+CREATE INDEX IF NOT EXISTS index_track_mount_point ON track(mount_point);
+
+-- Spell mount points like normalize_root_path does, without trailing
+-- separators, so `/music` and `/music/` become one root.
+UPDATE track SET mount_point = rtrim(mount_point, '/\')
+WHERE rtrim(mount_point, '/\') NOT IN ('', mount_point);
+
+INSERT OR IGNORE INTO library_root (path, track_count)
+SELECT mount_point, COUNT(*) FROM track GROUP BY mount_point;
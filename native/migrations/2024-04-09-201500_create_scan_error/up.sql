@@ -0,0 +1,10 @@
+CREATE TABLE IF NOT EXISTS scan_error (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    location TEXT NOT NULL UNIQUE,
+    mount_point TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    message TEXT NOT NULL,
+    occurred_at TIMESTAMP NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS index_scan_error_mount_point ON scan_error(mount_point);
@@ -0,0 +1,26 @@
+PRAGMA foreign_keys = OFF;
+
+BEGIN;
+
+CREATE TABLE album_old (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    name TEXT NOT NULL,
+    artist_id INTEGER,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    FOREIGN KEY (artist_id) REFERENCES artist(id)
+    UNIQUE (name, artist_id)
+);
+
+INSERT OR IGNORE INTO album_old (id, name, artist_id, created_at)
+SELECT id, name, artist_id, created_at FROM album;
+
+DROP TABLE album;
+ALTER TABLE album_old RENAME TO album;
+
+CREATE INDEX IF NOT EXISTS index_album_name ON album(name);
+CREATE INDEX IF NOT EXISTS index_album_artist_id ON album(artist_id);
+CREATE UNIQUE INDEX IF NOT EXISTS idx_album_name_artist_id_unique ON album(name, artist_id);
+
+COMMIT;
+
+PRAGMA foreign_keys = ON;
@@ -0,0 +1,58 @@
+-- Albums used to be looked up by name alone, so albums of the same name by
+-- different artists were merged into one. Rebuild the table so albums with a
+-- MusicBrainz release id are unique by that id instead of by name and artist,
+-- then split the merged albums by the artist of their tracks.
+PRAGMA foreign_keys = OFF;
+
+BEGIN;
+
+CREATE TABLE album_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    name TEXT NOT NULL,
+    artist_id INTEGER,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    musicbrainz_release_id TEXT,
+    FOREIGN KEY (artist_id) REFERENCES artist(id)
+);
+
+INSERT INTO album_new (id, name, artist_id, created_at)
+SELECT id, name, artist_id, created_at FROM album;
+
+DROP TABLE album;
+ALTER TABLE album_new RENAME TO album;
+
+CREATE INDEX IF NOT EXISTS index_album_name ON album(name);
+CREATE INDEX IF NOT EXISTS index_album_artist_id ON album(artist_id);
+CREATE UNIQUE INDEX IF NOT EXISTS idx_album_name_artist_id_unique ON album(name, artist_id)
+    WHERE musicbrainz_release_id IS NULL;
+CREATE UNIQUE INDEX IF NOT EXISTS index_album_musicbrainz_release_id ON album(musicbrainz_release_id);
+
+INSERT INTO album (name, artist_id)
+SELECT DISTINCT album.name, track.artist_id
+FROM track
+JOIN album ON album.id = track.album_id
+WHERE track.artist_id IS NOT album.artist_id
+    AND NOT EXISTS (
+        SELECT 1 FROM album AS existing
+        WHERE existing.name = album.name AND existing.artist_id IS track.artist_id
+    );
+
+UPDATE track SET album_id = (
+    SELECT split.id
+    FROM album AS merged
+    JOIN album AS split ON split.name = merged.name AND split.artist_id IS track.artist_id
+    WHERE merged.id = track.album_id
+)
+WHERE EXISTS (
+    SELECT 1 FROM album
+    WHERE album.id = track.album_id AND album.artist_id IS NOT track.artist_id
+);
+
+DELETE FROM album WHERE id NOT IN (SELECT album_id FROM track WHERE album_id IS NOT NULL);
+
+-- Force the next scan to re-read every file so release ids get picked up.
+UPDATE track SET file_modified_at = NULL;
+
+COMMIT;
+
+PRAGMA foreign_keys = ON;
@@ -0,0 +1 @@
+-- Nothing to undo: the next scan re-reads files either way.
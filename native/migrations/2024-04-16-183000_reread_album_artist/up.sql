@@ -0,0 +1,3 @@
+-- Albums are now credited to the album artist tag rather than to the artist
+-- of their first track. Force the next scan to re-read every file.
+UPDATE track SET file_modified_at = NULL;
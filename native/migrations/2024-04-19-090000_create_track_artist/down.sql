@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS index_track_artist_artist_id;
+DROP TABLE IF EXISTS track_artist;
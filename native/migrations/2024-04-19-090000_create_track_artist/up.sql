@@ -0,0 +1,16 @@
+CREATE TABLE IF NOT EXISTS track_artist (
+    track_id INTEGER NOT NULL REFERENCES track(id) ON DELETE CASCADE,
+    artist_id INTEGER NOT NULL REFERENCES artist(id),
+    role TEXT NOT NULL,
+    position INTEGER NOT NULL,
+    PRIMARY KEY (track_id, artist_id, role)
+);
+
+CREATE INDEX IF NOT EXISTS index_track_artist_artist_id ON track_artist(artist_id);
+
+INSERT INTO track_artist (track_id, artist_id, role, position)
+SELECT id, artist_id, 'main', 0 FROM track WHERE artist_id IS NOT NULL;
+
+-- Artist tags are now split into several artists. Force the next scan to
+-- re-read every file.
+UPDATE track SET file_modified_at = NULL;
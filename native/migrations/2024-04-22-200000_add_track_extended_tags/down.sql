@@ -0,0 +1,13 @@
+DROP INDEX IF EXISTS index_track_year;
+
+ALTER TABLE track DROP COLUMN label;
+ALTER TABLE track DROP COLUMN isrc;
+ALTER TABLE track DROP COLUMN bpm;
+ALTER TABLE track DROP COLUMN disc_total;
+ALTER TABLE track DROP COLUMN track_total;
+ALTER TABLE track DROP COLUMN comment;
+ALTER TABLE track DROP COLUMN composer;
+ALTER TABLE track DROP COLUMN genre;
+ALTER TABLE track DROP COLUMN original_date;
+ALTER TABLE track DROP COLUMN date;
+ALTER TABLE track DROP COLUMN year;
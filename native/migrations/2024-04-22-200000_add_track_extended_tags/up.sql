@@ -0,0 +1,16 @@
+ALTER TABLE track ADD COLUMN year INTEGER;
+ALTER TABLE track ADD COLUMN date TEXT;
+ALTER TABLE track ADD COLUMN original_date TEXT;
+ALTER TABLE track ADD COLUMN genre TEXT;
+ALTER TABLE track ADD COLUMN composer TEXT;
+ALTER TABLE track ADD COLUMN comment TEXT;
+ALTER TABLE track ADD COLUMN track_total INTEGER;
+ALTER TABLE track ADD COLUMN disc_total INTEGER;
+ALTER TABLE track ADD COLUMN bpm DOUBLE;
+ALTER TABLE track ADD COLUMN isrc TEXT;
+ALTER TABLE track ADD COLUMN label TEXT;
+
+CREATE INDEX IF NOT EXISTS index_track_year ON track(year);
+
+-- Force the next scan to re-read every file for the new tags.
+UPDATE track SET file_modified_at = NULL;
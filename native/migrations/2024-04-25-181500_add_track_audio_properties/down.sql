@@ -0,0 +1,6 @@
+ALTER TABLE track DROP COLUMN lossless;
+ALTER TABLE track DROP COLUMN channels;
+ALTER TABLE track DROP COLUMN bit_depth;
+ALTER TABLE track DROP COLUMN sample_rate;
+ALTER TABLE track DROP COLUMN bitrate;
+ALTER TABLE track DROP COLUMN codec;
@@ -0,0 +1,9 @@
+ALTER TABLE track ADD COLUMN codec TEXT;
+ALTER TABLE track ADD COLUMN bitrate INTEGER;
+ALTER TABLE track ADD COLUMN sample_rate INTEGER;
+ALTER TABLE track ADD COLUMN bit_depth INTEGER;
+ALTER TABLE track ADD COLUMN channels INTEGER;
+ALTER TABLE track ADD COLUMN lossless BOOLEAN NOT NULL DEFAULT 0;
+
+-- Force the next scan to re-read every file for its audio properties.
+UPDATE track SET file_modified_at = NULL;
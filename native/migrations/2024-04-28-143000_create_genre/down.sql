@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS index_track_genre_genre_id;
+DROP TABLE IF EXISTS track_genre;
+DROP TABLE IF EXISTS genre;
@@ -0,0 +1,17 @@
+CREATE TABLE IF NOT EXISTS genre (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    name TEXT NOT NULL UNIQUE,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS track_genre (
+    track_id INTEGER NOT NULL REFERENCES track(id) ON DELETE CASCADE,
+    genre_id INTEGER NOT NULL REFERENCES genre(id),
+    position INTEGER NOT NULL,
+    PRIMARY KEY (track_id, genre_id)
+);
+
+CREATE INDEX IF NOT EXISTS index_track_genre_genre_id ON track_genre(genre_id);
+
+-- Force the next scan to re-read every file to fill the genres.
+UPDATE track SET file_modified_at = NULL;
@@ -0,0 +1,4 @@
+ALTER TABLE album ADD COLUMN release_type TEXT;
+
+-- Force the next scan to re-read every file for the release type.
+UPDATE track SET file_modified_at = NULL;
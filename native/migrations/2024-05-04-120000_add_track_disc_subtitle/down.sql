@@ -0,0 +1 @@
+ALTER TABLE track DROP COLUMN disc_subtitle;
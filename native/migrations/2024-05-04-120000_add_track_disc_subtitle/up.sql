@@ -0,0 +1,4 @@
+ALTER TABLE track ADD COLUMN disc_subtitle TEXT;
+
+-- Force the next scan to re-read every file for the disc subtitle.
+UPDATE track SET file_modified_at = NULL;
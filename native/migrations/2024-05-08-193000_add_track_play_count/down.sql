@@ -0,0 +1,2 @@
+ALTER TABLE track DROP COLUMN last_played_at;
+ALTER TABLE track DROP COLUMN play_count;
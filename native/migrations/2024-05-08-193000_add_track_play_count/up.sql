@@ -0,0 +1,2 @@
+ALTER TABLE track ADD COLUMN play_count INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE track ADD COLUMN last_played_at TIMESTAMP;
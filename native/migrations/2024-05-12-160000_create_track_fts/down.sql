@@ -0,0 +1,2 @@
+DROP TRIGGER IF EXISTS track_fts_delete;
+DROP TABLE IF EXISTS track_fts;
@@ -0,0 +1,31 @@
+CREATE VIRTUAL TABLE IF NOT EXISTS track_fts USING fts5(
+    title,
+    artist,
+    album,
+    genre,
+    composer,
+    tokenize = 'unicode61 remove_diacritics 2'
+);
+
+INSERT INTO track_fts (rowid, title, artist, album, genre, composer)
+SELECT
+    track.id,
+    track.title,
+    (
+        SELECT group_concat(artist.name, ' ') FROM artist
+        WHERE artist.id IN (
+            SELECT artist_id FROM track_artist WHERE track_artist.track_id = track.id
+            UNION SELECT album.artist_id
+        )
+    ),
+    album.name,
+    track.genre,
+    track.composer
+FROM track
+LEFT JOIN album ON album.id = track.album_id;
+
+-- Rows are written by the scanner once a track's credits are known, but
+-- removed here so every way of deleting tracks keeps the index clean.
+CREATE TRIGGER IF NOT EXISTS track_fts_delete AFTER DELETE ON track BEGIN
+    DELETE FROM track_fts WHERE rowid = old.id;
+END;
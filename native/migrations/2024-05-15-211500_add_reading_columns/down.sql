@@ -0,0 +1,39 @@
+DROP TRIGGER IF EXISTS track_fts_delete;
+DROP TABLE IF EXISTS track_fts;
+
+CREATE VIRTUAL TABLE track_fts USING fts5(
+    title,
+    artist,
+    album,
+    genre,
+    composer,
+    tokenize = 'unicode61 remove_diacritics 2'
+);
+
+INSERT INTO track_fts (rowid, title, artist, album, genre, composer)
+SELECT
+    track.id,
+    track.title,
+    (
+        SELECT group_concat(artist.name, ' ') FROM artist
+        WHERE artist.id IN (
+            SELECT artist_id FROM track_artist WHERE track_artist.track_id = track.id
+            UNION SELECT album.artist_id
+        )
+    ),
+    album.name,
+    track.genre,
+    track.composer
+FROM track
+LEFT JOIN album ON album.id = track.album_id;
+
+CREATE TRIGGER track_fts_delete AFTER DELETE ON track BEGIN
+    DELETE FROM track_fts WHERE rowid = old.id;
+END;
+
+ALTER TABLE artist DROP COLUMN name_hiragana;
+ALTER TABLE artist DROP COLUMN name_romaji;
+ALTER TABLE album DROP COLUMN name_hiragana;
+ALTER TABLE album DROP COLUMN name_romaji;
+ALTER TABLE track DROP COLUMN title_hiragana;
+ALTER TABLE track DROP COLUMN title_romaji;
@@ -0,0 +1,46 @@
+ALTER TABLE track ADD COLUMN title_romaji TEXT;
+ALTER TABLE track ADD COLUMN title_hiragana TEXT;
+ALTER TABLE album ADD COLUMN name_romaji TEXT;
+ALTER TABLE album ADD COLUMN name_hiragana TEXT;
+ALTER TABLE artist ADD COLUMN name_romaji TEXT;
+ALTER TABLE artist ADD COLUMN name_hiragana TEXT;
+
+-- Readings are computed by the scanner, so force a re-read of every file.
+-- Artists and albums get theirs when a rescanned track refers to them.
+UPDATE track SET file_modified_at = NULL;
+
+DROP TRIGGER IF EXISTS track_fts_delete;
+DROP TABLE IF EXISTS track_fts;
+
+CREATE VIRTUAL TABLE track_fts USING fts5(
+    title,
+    artist,
+    album,
+    genre,
+    composer,
+    title_reading,
+    artist_reading,
+    album_reading,
+    tokenize = 'unicode61 remove_diacritics 2'
+);
+
+INSERT INTO track_fts (rowid, title, artist, album, genre, composer)
+SELECT
+    track.id,
+    track.title,
+    (
+        SELECT group_concat(artist.name, ' ') FROM artist
+        WHERE artist.id IN (
+            SELECT artist_id FROM track_artist WHERE track_artist.track_id = track.id
+            UNION SELECT album.artist_id
+        )
+    ),
+    album.name,
+    track.genre,
+    track.composer
+FROM track
+LEFT JOIN album ON album.id = track.album_id;
+
+CREATE TRIGGER track_fts_delete AFTER DELETE ON track BEGIN
+    DELETE FROM track_fts WHERE rowid = old.id;
+END;
@@ -0,0 +1,325 @@
+use std::collections::{HashMap, HashSet};
+
+use diesel::{ExpressionMethods, NullableExpressionMethods, QueryDsl, RunQueryDsl, SqliteConnection};
+
+use crate::model::{ifnull, Artist, Track};
+
+use super::simple::{album_track_order, establish_connection, hidden_mount_points, populate_tracks, TrackDTO};
+
+/// Release types in the order an artist's albums are grouped by. Other types
+/// follow alphabetically, then albums without a release type.
+const RELEASE_TYPE_ORDER: &[&str] = &["album", "ep", "single", "compilation", "live", "soundtrack", "remix"];
+
+pub struct AlbumSummary {
+    pub id: i32,
+    pub name: String,
+    pub artist: Option<Artist>,
+    pub release_type: Option<String>,
+    /// Earliest year any of the album's tracks is tagged with.
+    pub year: Option<i32>,
+    pub track_count: i32,
+    pub duration_ms: i64,
+    pub picture_id: Option<String>,
+}
+
+pub struct ArtistSummary {
+    pub id: i32,
+    pub name: String,
+    /// Tracks the artist is credited on in any role.
+    pub track_count: i32,
+    /// Albums credited to the artist as album artist.
+    pub album_count: i32,
+    pub duration_ms: i64,
+    pub picture_id: Option<String>,
+}
+
+pub struct ReleaseGroup {
+    pub release_type: Option<String>,
+    pub albums: Vec<AlbumSummary>,
+}
+
+pub struct ArtistDetail {
+    pub artist: ArtistSummary,
+    /// The artist's own albums, grouped by release type.
+    pub release_groups: Vec<ReleaseGroup>,
+    /// Albums by other artists that the artist is credited on.
+    pub appears_on: Vec<AlbumSummary>,
+}
+
+pub struct DiscGroup {
+    pub disc: Option<i32>,
+    /// The first `DISCSUBTITLE` tagged on the disc's tracks.
+    pub subtitle: Option<String>,
+    pub tracks: Vec<TrackDTO>,
+}
+
+pub struct AlbumDetail {
+    pub album: AlbumSummary,
+    pub discs: Vec<DiscGroup>,
+}
+
+/// Album id, name, artist id, release type, year, track count, total
+/// duration and picture id.
+type AlbumSummaryRow = (i32, String, Option<i32>, Option<String>, Option<i32>, i64, Option<i64>, Option<String>);
+
+/// Summaries of the albums with a visible track, limited to `album_ids` if
+/// given, sorted by the reading of their name.
+fn album_summaries(
+    conn: &mut SqliteConnection,
+    hidden_mount_points: &[String],
+    album_ids: Option<&[i32]>,
+) -> Vec<AlbumSummary> {
+    use crate::schema::album::dsl as album_dsl;
+    use crate::schema::artist::dsl as artist_dsl;
+    use crate::schema::track::dsl as track_dsl;
+
+    let mut query = album_dsl::album
+        .inner_join(track_dsl::track)
+        .filter(track_dsl::mount_point.ne_all(hidden_mount_points.to_vec()))
+        .group_by(album_dsl::id)
+        .select((
+            album_dsl::id,
+            album_dsl::name,
+            album_dsl::artist_id,
+            album_dsl::release_type,
+            diesel::dsl::min(track_dsl::year),
+            diesel::dsl::count(track_dsl::id),
+            diesel::dsl::sum(track_dsl::duration_ms),
+            diesel::dsl::max(track_dsl::picture_id),
+        ))
+        .order_by((ifnull(album_dsl::name_hiragana, album_dsl::name.nullable()), album_dsl::name, album_dsl::id))
+        .into_boxed();
+
+    if let Some(album_ids) = album_ids {
+        query = query.filter(album_dsl::id.eq_any(album_ids.to_vec()));
+    }
+
+    let rows: Vec<AlbumSummaryRow> = query.load(conn).unwrap();
+
+    let artist_ids: HashSet<i32> = rows.iter().filter_map(|row| row.2).collect();
+    let artists: HashMap<i32, Artist> = artist_dsl::artist
+        .filter(artist_dsl::id.eq_any(artist_ids))
+        .load::<Artist>(conn)
+        .unwrap()
+        .into_iter()
+        .map(|artist| (artist.id, artist))
+        .collect();
+
+    rows.into_iter()
+        .map(
+            |(id, name, artist_id, release_type, year, track_count, duration_ms, picture_id)| AlbumSummary {
+                id,
+                name,
+                artist: artist_id.and_then(|artist_id| artists.get(&artist_id).cloned()),
+                release_type,
+                year,
+                track_count: track_count as i32,
+                duration_ms: duration_ms.unwrap_or(0),
+                picture_id,
+            },
+        )
+        .collect()
+}
+
+/// Summaries of the artists credited on a visible track or album, limited to
+/// `artist_ids` if given, sorted by the reading of their name.
+fn artist_summaries(
+    conn: &mut SqliteConnection,
+    hidden_mount_points: &[String],
+    artist_ids: Option<&[i32]>,
+) -> Vec<ArtistSummary> {
+    use crate::schema::album::dsl as album_dsl;
+    use crate::schema::artist::dsl as artist_dsl;
+    use crate::schema::track::dsl as track_dsl;
+    use crate::schema::track_artist::dsl as track_artist_dsl;
+
+    let mut query = artist_dsl::artist
+        .inner_join(track_artist_dsl::track_artist.inner_join(track_dsl::track))
+        .filter(track_dsl::mount_point.ne_all(hidden_mount_points.to_vec()))
+        .select((artist_dsl::id, track_dsl::id, track_dsl::duration_ms))
+        .distinct()
+        .into_boxed();
+
+    if let Some(artist_ids) = artist_ids {
+        query = query.filter(artist_dsl::id.eq_any(artist_ids.to_vec()));
+    }
+
+    // An artist may be credited on a track in several roles, so tracks are
+    // counted once per artist here rather than with a grouped count.
+    let mut totals: HashMap<i32, (i32, i64)> = HashMap::new();
+    for (artist_id, _, duration_ms) in query.load::<(i32, i32, i32)>(conn).unwrap() {
+        let total = totals.entry(artist_id).or_default();
+        total.0 += 1;
+        total.1 += duration_ms as i64;
+    }
+
+    // Only the albums of the requested artists need summarizing.
+    let album_ids: Option<Vec<i32>> = artist_ids.map(|artist_ids| {
+        album_dsl::album
+            .filter(album_dsl::artist_id.eq_any(artist_ids.to_vec()))
+            .select(album_dsl::id)
+            .load(conn)
+            .unwrap()
+    });
+
+    let mut albums: HashMap<i32, (i32, Option<String>)> = HashMap::new();
+    for album in album_summaries(conn, hidden_mount_points, album_ids.as_deref()) {
+        if let Some(artist) = album.artist {
+            let entry = albums.entry(artist.id).or_default();
+            entry.0 += 1;
+            if entry.1.is_none() {
+                entry.1 = album.picture_id;
+            }
+        }
+    }
+
+    let ids: HashSet<i32> = totals
+        .keys()
+        .chain(albums.keys())
+        .copied()
+        .filter(|id| match artist_ids {
+            Some(artist_ids) => artist_ids.contains(id),
+            None => true,
+        })
+        .collect();
+
+    let artists: Vec<Artist> = artist_dsl::artist
+        .filter(artist_dsl::id.eq_any(ids))
+        .order_by((ifnull(artist_dsl::name_hiragana, artist_dsl::name.nullable()), artist_dsl::name, artist_dsl::id))
+        .load(conn)
+        .unwrap();
+
+    artists
+        .into_iter()
+        .map(|artist| {
+            let (track_count, duration_ms) = totals.get(&artist.id).copied().unwrap_or_default();
+            let (album_count, picture_id) = albums.remove(&artist.id).unwrap_or_default();
+
+            ArtistSummary {
+                id: artist.id,
+                name: artist.name,
+                track_count,
+                album_count,
+                duration_ms,
+                picture_id,
+            }
+        })
+        .collect()
+}
+
+/// Sort key placing `release_type` by [`RELEASE_TYPE_ORDER`].
+fn release_type_rank(release_type: &Option<String>) -> (usize, Option<String>) {
+    match release_type {
+        Some(release_type) => match RELEASE_TYPE_ORDER.iter().position(|known| known == release_type) {
+            Some(position) => (position, None),
+            None => (RELEASE_TYPE_ORDER.len(), Some(release_type.clone())),
+        },
+        None => (RELEASE_TYPE_ORDER.len() + 1, None),
+    }
+}
+
+/// Every album with a visible track, by the reading of its name.
+pub fn get_all_albums(hide_offline: bool) -> Vec<AlbumSummary> {
+    let conn = &mut establish_connection().unwrap();
+    let hidden_mount_points = hidden_mount_points(conn, hide_offline);
+
+    album_summaries(conn, &hidden_mount_points, None)
+}
+
+/// Every artist credited on a visible track or album, by the reading of their
+/// name.
+pub fn get_all_artists(hide_offline: bool) -> Vec<ArtistSummary> {
+    let conn = &mut establish_connection().unwrap();
+    let hidden_mount_points = hidden_mount_points(conn, hide_offline);
+
+    artist_summaries(conn, &hidden_mount_points, None)
+}
+
+/// The album with its tracks grouped by disc, or `None` if it has no visible
+/// track.
+pub fn get_album_detail(album_id: i32, hide_offline: bool) -> Option<AlbumDetail> {
+    use crate::schema::track::dsl as track_dsl;
+
+    let conn = &mut establish_connection().unwrap();
+    let hidden_mount_points = hidden_mount_points(conn, hide_offline);
+
+    let album = album_summaries(conn, &hidden_mount_points, Some(&[album_id])).pop()?;
+
+    let tracks: Vec<Track> = track_dsl::track
+        .filter(track_dsl::album_id.eq(album_id))
+        .filter(track_dsl::mount_point.ne_all(&hidden_mount_points))
+        .order_by(album_track_order())
+        .load(conn)
+        .unwrap();
+
+    let mut discs: Vec<DiscGroup> = Vec::new();
+    for track in populate_tracks(conn, tracks) {
+        match discs.last_mut() {
+            Some(group) if group.disc == track.disc => {
+                if group.subtitle.is_none() {
+                    group.subtitle = track.disc_subtitle.clone();
+                }
+                group.tracks.push(track);
+            }
+            _ => discs.push(DiscGroup {
+                disc: track.disc,
+                subtitle: track.disc_subtitle.clone(),
+                tracks: vec![track],
+            }),
+        }
+    }
+
+    Some(AlbumDetail { album, discs })
+}
+
+/// The artist with their albums grouped by release type, or `None` if they
+/// have no visible track or album.
+pub fn get_artist_detail(artist_id: i32, hide_offline: bool) -> Option<ArtistDetail> {
+    use crate::schema::album::dsl as album_dsl;
+    use crate::schema::track::dsl as track_dsl;
+    use crate::schema::track_artist::dsl as track_artist_dsl;
+
+    let conn = &mut establish_connection().unwrap();
+    let hidden_mount_points = hidden_mount_points(conn, hide_offline);
+
+    let artist = artist_summaries(conn, &hidden_mount_points, Some(&[artist_id])).pop()?;
+
+    let mut album_ids: Vec<i32> = album_dsl::album
+        .filter(album_dsl::artist_id.eq(artist_id))
+        .select(album_dsl::id)
+        .load(conn)
+        .unwrap();
+
+    let credited_album_ids: Vec<i32> = track_dsl::track
+        .inner_join(track_artist_dsl::track_artist)
+        .filter(track_artist_dsl::artist_id.eq(artist_id))
+        .filter(track_dsl::album_id.is_not_null())
+        .select(track_dsl::album_id.assume_not_null())
+        .distinct()
+        .load(conn)
+        .unwrap();
+    album_ids.extend(credited_album_ids);
+
+    let (own_albums, appears_on): (Vec<AlbumSummary>, Vec<AlbumSummary>) =
+        album_summaries(conn, &hidden_mount_points, Some(&album_ids))
+            .into_iter()
+            .partition(|album| album.artist.as_ref().map(|album_artist| album_artist.id) == Some(artist_id));
+
+    let mut release_groups: Vec<ReleaseGroup> = Vec::new();
+    for album in own_albums {
+        match release_groups.iter_mut().find(|group| group.release_type == album.release_type) {
+            Some(group) => group.albums.push(album),
+            None => release_groups.push(ReleaseGroup {
+                release_type: album.release_type.clone(),
+                albums: vec![album],
+            }),
+        }
+    }
+    release_groups.sort_by_key(|group| release_type_rank(&group.release_type));
+
+    Some(ArtistDetail {
+        artist,
+        release_groups,
+        appears_on,
+    })
+}
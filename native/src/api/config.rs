@@ -0,0 +1,81 @@
+use std::{fs, path::PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use super::simple::get_config_path;
+
+/// File extensions of every audio format lofty can read.
+const DEFAULT_EXTENSIONS: &[&str] = &[
+    "aac", "aif", "aifc", "aiff", "ape", "flac", "m4a", "m4b", "m4p", "mp1", "mp2", "mp3", "mp4", "mpc", "mpp", "mp+",
+    "oga", "ogg", "opus", "spx", "wav", "wave", "wv",
+];
+
+/// Strings that separate several artists in one artist tag.
+const DEFAULT_ARTIST_SEPARATORS: &[&str] = &[";", "/", " feat. ", " & "];
+
+/// Strings that separate several genres in one genre tag.
+const DEFAULT_GENRE_SEPARATORS: &[&str] = &[";", "/", ","];
+
+/// User settings, stored as `config.toml` in [`get_config_path`].
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub struct Config {
+    /// Extensions of the files the scanner imports, matched case-insensitively.
+    pub extensions: Vec<String>,
+    /// Whether files without an extension, or with one in
+    /// [`Config::sniffed_extensions`], are sniffed for an audio format instead
+    /// of being skipped.
+    pub detect_by_content: bool,
+    /// Extensions of files that are sniffed for an audio format when
+    /// [`Config::detect_by_content`] is set, for audio saved under a wrong
+    /// extension. Other extensions not in [`Config::extensions`] are skipped
+    /// without being opened.
+    pub sniffed_extensions: Vec<String>,
+    /// Strings that split an artist, remixer or composer tag into several
+    /// artists, matched case-insensitively. Artists after a featuring
+    /// separator such as `" feat. "` are credited as featured artists.
+    pub artist_separators: Vec<String>,
+    /// Strings that split a genre tag into several genres.
+    pub genre_separators: Vec<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            extensions: DEFAULT_EXTENSIONS.iter().map(|ext| ext.to_string()).collect(),
+            detect_by_content: true,
+            sniffed_extensions: Vec::new(),
+            artist_separators: DEFAULT_ARTIST_SEPARATORS.iter().map(|separator| separator.to_string()).collect(),
+            genre_separators: DEFAULT_GENRE_SEPARATORS.iter().map(|separator| separator.to_string()).collect(),
+        }
+    }
+}
+
+fn config_file_path() -> PathBuf {
+    PathBuf::from(get_config_path()).join("config.toml")
+}
+
+/// Returns the saved config, or the default one if none was saved or it
+/// cannot be read.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_config() -> Config {
+    match fs::read_to_string(config_file_path()) {
+        Ok(content) => toml::from_str(&content).unwrap_or_else(|e| {
+            println!("Error reading config: {:?}", e);
+            Config::default()
+        }),
+        Err(_) => Config::default(),
+    }
+}
+
+pub fn set_config(config: Config) -> Result<(), String> {
+    let content = toml::to_string_pretty(&config).map_err(|e| e.to_string())?;
+
+    fs::create_dir_all(get_config_path()).map_err(|e| e.to_string())?;
+    fs::write(config_file_path(), content).map_err(|e| e.to_string())
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_default_config() -> Config {
+    Config::default()
+}
@@ -0,0 +1,446 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use unicode_normalization::char::{compose, is_combining_mark};
+use unicode_normalization::UnicodeNormalization;
+
+use super::simple::{establish_connection, hidden_mount_points, tracks_by_ids, TrackDTO};
+
+/// Track id, title, title readings, album id and main artist id.
+type TitleRow = (i32, Option<String>, Option<String>, Option<String>, Option<i32>, Option<i32>);
+
+/// Id, name and name readings of an album or artist.
+type NameRow = (i32, String, Option<String>, Option<String>);
+
+/// Track id, score, and the spans matched in the title, album and artist.
+type ScoredTrack = (i32, f64, [Vec<(usize, usize)>; 3]);
+
+/// How much a match in each field counts towards a track's score.
+const TITLE_WEIGHT: f64 = 1.0;
+const ALBUM_WEIGHT: f64 = 0.7;
+const ARTIST_WEIGHT: f64 = 0.5;
+
+/// Part of a text to highlight, in UTF-16 code units from its start as Dart
+/// indexes strings, end exclusive.
+#[derive(PartialEq, Debug)]
+pub struct HighlightRange {
+    pub start: u32,
+    pub end: u32,
+}
+
+pub struct TrackSearchResult {
+    pub track: TrackDTO,
+    /// Between 0 and 1, higher for closer matches in weightier fields.
+    pub score: f64,
+    /// Matched parts of the track's title.
+    pub title_ranges: Vec<HighlightRange>,
+    /// Matched parts of the name of the track's album.
+    pub album_ranges: Vec<HighlightRange>,
+    /// Matched parts of the name of the track's main artist.
+    pub artist_ranges: Vec<HighlightRange>,
+}
+
+/// Text folded for matching, remembering which character of the original
+/// text each folded character came from.
+struct FoldedText {
+    chars: Vec<char>,
+    /// Start and end of the original character in UTF-16 code units.
+    origins: Vec<(usize, usize)>,
+    /// Start and end of each run of letters and digits in `chars`.
+    words: Vec<(usize, usize)>,
+}
+
+/// Folds `text` so that case, full- and half-width forms and diacritics do
+/// not matter: `Ｒａｄｉｏｈｅａｄ` and `Beyoncé` match `radiohead` and
+/// `beyonce`.
+fn fold(text: &str) -> FoldedText {
+    let mut chars = Vec::new();
+    let mut origins = Vec::new();
+
+    let mut offset = 0;
+    for c in text.chars() {
+        let origin = (offset, offset + c.len_utf16());
+        offset = origin.1;
+
+        if c.is_ascii() {
+            chars.push(c.to_ascii_lowercase());
+            origins.push(origin);
+            continue;
+        }
+
+        // Kana voicing marks are kept so that が stays apart from か.
+        let folded = std::iter::once(c)
+            .nfkd()
+            .filter(|c| !is_combining_mark(*c) || matches!(c, '\u{3099}' | '\u{309A}'))
+            .nfc()
+            .flat_map(char::to_lowercase);
+
+        for folded in folded {
+            // Half-width kana write voicing marks as characters of their own,
+            // as in ｶﾞ, which are composed like those of full-width kana.
+            if matches!(folded, '\u{3099}' | '\u{309A}') {
+                if let Some(composed) = chars.last().and_then(|last| compose(*last, folded)) {
+                    *chars.last_mut().unwrap() = composed;
+                    origins.last_mut().unwrap().1 = origin.1;
+                    continue;
+                }
+            }
+
+            chars.push(folded);
+            origins.push(origin);
+        }
+    }
+
+    let mut words = Vec::new();
+    let mut word_start = None;
+    for (index, c) in chars.iter().enumerate() {
+        match (c.is_alphanumeric(), word_start) {
+            (true, None) => word_start = Some(index),
+            (false, Some(start)) => {
+                words.push((start, index));
+                word_start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(start) = word_start {
+        words.push((start, chars.len()));
+    }
+
+    FoldedText { chars, origins, words }
+}
+
+/// Typos tolerated in a query word of `len` characters.
+fn allowed_typos(len: usize) -> usize {
+    match len {
+        0..=3 => 0,
+        4..=7 => 1,
+        _ => 2,
+    }
+}
+
+/// The fewest insertions, deletions, substitutions and swaps of adjacent
+/// characters turning `word` into some prefix of `candidate`, if at most
+/// `max`, with the length of the longest such prefix.
+fn prefix_distance(word: &[char], candidate: &[char], max: usize) -> Option<(usize, usize)> {
+    if candidate.len() + max < word.len() {
+        return None;
+    }
+
+    let mut before_previous: Vec<usize> = vec![0; candidate.len() + 1];
+    let mut previous: Vec<usize> = (0..=candidate.len()).collect();
+    let mut current: Vec<usize> = vec![0; candidate.len() + 1];
+
+    for i in 1..=word.len() {
+        current[0] = i;
+        for j in 1..=candidate.len() {
+            let cost = usize::from(word[i - 1] != candidate[j - 1]);
+            current[j] = (previous[j] + 1).min(current[j - 1] + 1).min(previous[j - 1] + cost);
+            if i > 1 && j > 1 && word[i - 1] == candidate[j - 2] && word[i - 2] == candidate[j - 1] {
+                current[j] = current[j].min(before_previous[j - 2] + 1);
+            }
+        }
+
+        if current.iter().all(|distance| *distance > max) {
+            return None;
+        }
+        std::mem::swap(&mut before_previous, &mut previous);
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    let distance = *previous[1..].iter().min()?;
+    if distance > max {
+        return None;
+    }
+    let len = previous.iter().rposition(|d| *d == distance)?;
+    Some((distance, len))
+}
+
+/// How well one query word matches a field, and where in the field's
+/// original text in UTF-16 code units, unless it only matched one of the
+/// field's readings.
+#[derive(Clone, Copy)]
+struct WordMatch {
+    score: f64,
+    span: Option<(usize, usize)>,
+}
+
+/// The best match of `word` against the words of `text`: whole words beat
+/// prefixes, which beat matches inside a word, which beat matches with typos.
+fn match_word(word: &[char], text: &FoldedText) -> Option<WordMatch> {
+    let mut best: Option<(f64, usize, usize)> = None;
+
+    for &(start, end) in &text.words {
+        let candidate = &text.chars[start..end];
+
+        let found = if candidate == word {
+            Some((1.0, start, end))
+        } else if candidate.starts_with(word) {
+            Some((0.9, start, start + word.len()))
+        } else if let Some(offset) = candidate.windows(word.len()).position(|window| window == word) {
+            Some((0.7, start + offset, start + offset + word.len()))
+        } else {
+            match allowed_typos(word.len()) {
+                0 => None,
+                max => prefix_distance(word, candidate, max).map(|(distance, len)| {
+                    (0.6 * (1.0 - distance as f64 / word.len() as f64), start, start + len)
+                }),
+            }
+        };
+
+        if let Some(found) = found {
+            match best {
+                Some(best) if best.0 >= found.0 => {}
+                _ => best = Some(found),
+            }
+        }
+    }
+
+    best.map(|(score, start, end)| WordMatch {
+        score,
+        span: Some((text.origins[start].0, text.origins[end - 1].1)),
+    })
+}
+
+/// How well each query word matches a title or name, as written or by its
+/// romaji or hiragana reading.
+fn field_matches(words: &[Vec<char>], text: &str, readings: [Option<&str>; 2]) -> Vec<Option<WordMatch>> {
+    // Readings of text without Japanese are the text itself.
+    let readings: Vec<FoldedText> = readings
+        .into_iter()
+        .flatten()
+        .filter(|reading| *reading != text)
+        .map(fold)
+        .collect();
+    let text = fold(text);
+
+    words
+        .iter()
+        .map(|word| {
+            let mut best = match_word(word, &text);
+            for reading in &readings {
+                if let Some(found) = match_word(word, reading) {
+                    match best {
+                        Some(best) if best.score >= found.score => {}
+                        _ => best = Some(WordMatch { score: found.score, span: None }),
+                    }
+                }
+            }
+            best
+        })
+        .collect()
+}
+
+/// Sorted, merged highlight ranges from UTF-16 spans.
+fn highlight_ranges(mut spans: Vec<(usize, usize)>) -> Vec<HighlightRange> {
+    spans.sort();
+
+    let mut ranges: Vec<HighlightRange> = Vec::new();
+    for (start, end) in spans {
+        match ranges.last_mut() {
+            Some(last) if start <= last.end as usize => last.end = last.end.max(end as u32),
+            _ => ranges.push(HighlightRange {
+                start: start as u32,
+                end: end as u32,
+            }),
+        }
+    }
+    ranges
+}
+
+/// Up to `limit` tracks whose title, album or main artist match every word
+/// of `query`, best matches first.
+///
+/// Matching ignores case, character width and diacritics, tolerates a typo
+/// in words of four or more characters and two in words of eight or more,
+/// and also looks at the romaji and hiragana readings of Japanese text.
+/// Title matches count for more than album matches, which count for more
+/// than artist matches.
+pub fn fuzzy_search_tracks(query: String, limit: u32, hide_offline: bool) -> Vec<TrackSearchResult> {
+    use crate::schema::album::dsl as album_dsl;
+    use crate::schema::artist::dsl as artist_dsl;
+    use crate::schema::track::dsl as track_dsl;
+
+    let query = fold(&query);
+    let words: Vec<Vec<char>> = query
+        .words
+        .iter()
+        .map(|&(start, end)| query.chars[start..end].to_vec())
+        .collect();
+    if words.is_empty() {
+        return Vec::new();
+    }
+
+    let conn = &mut establish_connection().unwrap();
+    let hidden_mount_points = hidden_mount_points(conn, hide_offline);
+
+    let tracks: Vec<TitleRow> = track_dsl::track
+        .filter(track_dsl::mount_point.ne_all(hidden_mount_points))
+        .select((
+            track_dsl::id,
+            track_dsl::title,
+            track_dsl::title_romaji,
+            track_dsl::title_hiragana,
+            track_dsl::album_id,
+            track_dsl::artist_id,
+        ))
+        .load(conn)
+        .unwrap();
+
+    // Albums and artists are shared by many tracks, so each is matched once.
+    let album_matches: HashMap<i32, Vec<Option<WordMatch>>> = album_dsl::album
+        .select((album_dsl::id, album_dsl::name, album_dsl::name_romaji, album_dsl::name_hiragana))
+        .load::<NameRow>(conn)
+        .unwrap()
+        .into_par_iter()
+        .map(|(id, name, romaji, hiragana)| (id, field_matches(&words, &name, [romaji.as_deref(), hiragana.as_deref()])))
+        .collect();
+    let artist_matches: HashMap<i32, Vec<Option<WordMatch>>> = artist_dsl::artist
+        .select((artist_dsl::id, artist_dsl::name, artist_dsl::name_romaji, artist_dsl::name_hiragana))
+        .load::<NameRow>(conn)
+        .unwrap()
+        .into_par_iter()
+        .map(|(id, name, romaji, hiragana)| (id, field_matches(&words, &name, [romaji.as_deref(), hiragana.as_deref()])))
+        .collect();
+
+    let no_matches = vec![None; words.len()];
+    let mut scored: Vec<ScoredTrack> = tracks
+        .into_par_iter()
+        .filter_map(|(id, title, title_romaji, title_hiragana, album_id, artist_id)| {
+            let title_matches = field_matches(
+                &words,
+                title.as_deref().unwrap_or_default(),
+                [title_romaji.as_deref(), title_hiragana.as_deref()],
+            );
+            let album_matches = album_id
+                .and_then(|album_id| album_matches.get(&album_id))
+                .unwrap_or(&no_matches);
+            let artist_matches = artist_id
+                .and_then(|artist_id| artist_matches.get(&artist_id))
+                .unwrap_or(&no_matches);
+
+            // Each query word counts where it matches best, so words may
+            // match in different fields.
+            let mut total = 0.0;
+            let mut spans: [Vec<(usize, usize)>; 3] = Default::default();
+            for index in 0..words.len() {
+                let (field, score, span) = [
+                    (title_matches[index], TITLE_WEIGHT),
+                    (album_matches[index], ALBUM_WEIGHT),
+                    (artist_matches[index], ARTIST_WEIGHT),
+                ]
+                .into_iter()
+                .enumerate()
+                .filter_map(|(field, (found, weight))| found.map(|found| (field, found.score * weight, found.span)))
+                .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal))?;
+
+                total += score;
+                spans[field].extend(span);
+            }
+
+            Some((id, total / words.len() as f64, spans))
+        })
+        .collect();
+
+    scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(Ordering::Equal).then(a.0.cmp(&b.0)));
+    scored.truncate(limit as usize);
+
+    let ids: Vec<i32> = scored.iter().map(|(id, _, _)| *id).collect();
+    let mut tracks: HashMap<i32, TrackDTO> = tracks_by_ids(conn, &ids)
+        .into_iter()
+        .map(|track| (track.id, track))
+        .collect();
+
+    scored
+        .into_iter()
+        .filter_map(|(id, score, [title_spans, album_spans, artist_spans])| {
+            Some(TrackSearchResult {
+                track: tracks.remove(&id)?,
+                score,
+                title_ranges: highlight_ranges(title_spans),
+                album_ranges: highlight_ranges(album_spans),
+                artist_ranges: highlight_ranges(artist_spans),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chars(text: &str) -> Vec<char> {
+        text.chars().collect()
+    }
+
+    fn folded(text: &str) -> String {
+        fold(text).chars.into_iter().collect()
+    }
+
+    #[test]
+    fn fold_ignores_case_width_and_diacritics() {
+        assert_eq!(folded("Radiohead"), "radiohead");
+        assert_eq!(folded("Ｒａｄｉｏｈｅａｄ"), "radiohead");
+        assert_eq!(folded("Beyoncé"), "beyonce");
+        assert_eq!(folded("Déjà Vu"), "deja vu");
+        assert_eq!(folded("ｶﾞｸ"), "ガク");
+        assert_eq!(fold("ｶﾞｸ").origins, [(0, 2), (2, 3)]);
+        assert_eq!(folded("が"), "が");
+    }
+
+    #[test]
+    fn fold_splits_words() {
+        let text = fold("Re:Zero, ep.2");
+        let words: Vec<String> = text
+            .words
+            .iter()
+            .map(|&(start, end)| text.chars[start..end].iter().collect())
+            .collect();
+        assert_eq!(words, ["re", "zero", "ep", "2"]);
+    }
+
+    #[test]
+    fn prefix_distance_counts_typos() {
+        assert_eq!(prefix_distance(&chars("radiohead"), &chars("radiohead"), 2), Some((0, 9)));
+        assert_eq!(prefix_distance(&chars("radiohed"), &chars("radiohead"), 2), Some((1, 9)));
+        // Swapped neighbours count as one typo.
+        assert_eq!(prefix_distance(&chars("raidohead"), &chars("radiohead"), 1), Some((1, 9)));
+        // The word may match a prefix of a longer candidate.
+        assert_eq!(prefix_distance(&chars("radoi"), &chars("radiohead"), 1), Some((1, 5)));
+        assert_eq!(prefix_distance(&chars("abcd"), &chars("wxyz"), 2), None);
+        assert_eq!(prefix_distance(&chars("radiohead"), &chars("radio"), 2), None);
+    }
+
+    #[test]
+    fn match_word_prefers_closer_matches() {
+        let text = fold("Paranoid Android");
+        let score = |word: &str| match_word(&chars(word), &text).map(|found| found.score);
+
+        assert_eq!(score("android"), Some(1.0));
+        assert_eq!(score("andr"), Some(0.9));
+        assert_eq!(score("droid"), Some(0.7));
+        assert!(score("androd").is_some_and(|score| score < 0.7));
+        assert_eq!(score("zzz"), None);
+    }
+
+    #[test]
+    fn match_word_tolerates_typos() {
+        let found = match_word(&chars("radiohed"), &fold("Radiohead")).unwrap();
+        assert!(found.score > 0.0 && found.score < 0.7);
+        assert_eq!(found.span, Some((0, 9)));
+    }
+
+    #[test]
+    fn spans_are_utf16_offsets() {
+        // 𝄞 takes two UTF-16 code units, and ｂ folds to b.
+        let found = match_word(&chars("beyonce"), &fold("𝄞 Ｂeyoncé")).unwrap();
+        assert_eq!(found.span, Some((3, 10)));
+
+        assert_eq!(
+            highlight_ranges(vec![(5, 7), (0, 2), (1, 3)]),
+            [HighlightRange { start: 0, end: 3 }, HighlightRange { start: 5, end: 7 }]
+        );
+    }
+}
@@ -0,0 +1,377 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use chrono::Utc;
+use diesel::{Connection, ExpressionMethods, QueryDsl, QueryResult, RunQueryDsl, SqliteConnection};
+
+use crate::model::{LibraryRoot, NewLibraryRoot};
+
+use super::scan::{delete_orphaned_metadata, scan_mount_point, ScanCancelToken, SyncSummary};
+use super::simple::establish_connection;
+
+/// Reachability of a library root, e.g. whether its removable drive is
+/// mounted.
+pub struct LibraryRootAvailability {
+    pub id: i32,
+    pub path: String,
+    pub available: bool,
+}
+
+/// Whether the root at `path` can be read. A root that already has tracks
+/// also needs to be non-empty, because the mount point of an unplugged drive
+/// is often left behind as an empty directory.
+pub(crate) fn is_root_available(path: &str, has_tracks: bool) -> bool {
+    match fs::read_dir(path) {
+        Ok(mut entries) => !has_tracks || entries.next().is_some(),
+        Err(_) => false,
+    }
+}
+
+/// Paths of the library roots that are currently offline.
+pub(crate) fn offline_root_paths(conn: &mut SqliteConnection) -> QueryResult<Vec<String>> {
+    use crate::schema::library_root::dsl as library_root_dsl;
+
+    let roots: Vec<(String, i32)> = library_root_dsl::library_root
+        .select((library_root_dsl::path, library_root_dsl::track_count))
+        .load(conn)?;
+
+    Ok(roots
+        .into_iter()
+        .filter(|(path, track_count)| !is_root_available(path, *track_count > 0))
+        .map(|(path, _)| path)
+        .collect())
+}
+
+/// Spells a root path the same way regardless of trailing separators or `.`
+/// components, so one folder is only ever registered once.
+pub(crate) fn normalize_root_path(path: &str) -> String {
+    Path::new(path)
+        .components()
+        .collect::<PathBuf>()
+        .to_string_lossy()
+        .to_string()
+}
+
+/// Registers `path` as a library root if it is not one already.
+///
+/// Roots may not be nested, since every track belongs to exactly one root, so
+/// this fails if `path` is inside another root or contains one.
+pub(crate) fn register_library_root(conn: &mut SqliteConnection, path: &str) -> Result<(), String> {
+    use crate::schema::library_root::dsl as library_root_dsl;
+
+    let existing_paths: Vec<String> = library_root_dsl::library_root
+        .select(library_root_dsl::path)
+        .load(conn)
+        .map_err(|e| e.to_string())?;
+
+    if existing_paths.iter().any(|existing_path| existing_path == path) {
+        return Ok(());
+    }
+
+    for existing_path in existing_paths {
+        if Path::new(path).starts_with(&existing_path) || Path::new(&existing_path).starts_with(path) {
+            return Err(format!("{} overlaps the library root {}", path, existing_path));
+        }
+    }
+
+    diesel::insert_or_ignore_into(library_root_dsl::library_root)
+        .values(NewLibraryRoot { path: path.to_string() })
+        .execute(conn)
+        .map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+/// Recounts the tracks of the root at `path`, and records the scan time when
+/// `scanned` is set.
+pub(crate) fn refresh_library_root(conn: &mut SqliteConnection, path: &str, scanned: bool) -> QueryResult<()> {
+    use crate::schema::library_root::dsl as library_root_dsl;
+    use crate::schema::track::dsl as track_dsl;
+
+    let track_count: i64 = track_dsl::track
+        .filter(track_dsl::mount_point.eq(path))
+        .count()
+        .get_result(conn)?;
+
+    let root = library_root_dsl::library_root.filter(library_root_dsl::path.eq(path));
+
+    if scanned {
+        diesel::update(root)
+            .set((
+                library_root_dsl::track_count.eq(track_count as i32),
+                library_root_dsl::last_scanned_at.eq(Utc::now().naive_utc()),
+            ))
+            .execute(conn)?;
+    } else {
+        diesel::update(root)
+            .set(library_root_dsl::track_count.eq(track_count as i32))
+            .execute(conn)?;
+    }
+
+    Ok(())
+}
+
+/// Registers a folder as a library root. The folder is not scanned until
+/// [`rescan_library_root`] is called.
+///
+/// Roots may not be nested, since every track belongs to exactly one root.
+pub fn add_library_root(path: String) -> Result<LibraryRoot, String> {
+    use crate::schema::library_root::dsl as library_root_dsl;
+
+    if !Path::new(&path).is_dir() {
+        return Err(format!("{} is not a directory", path));
+    }
+
+    let path = normalize_root_path(&path);
+    let conn = &mut establish_connection().unwrap();
+
+    register_library_root(conn, &path)?;
+
+    library_root_dsl::library_root
+        .filter(library_root_dsl::path.eq(&path))
+        .first(conn)
+        .map_err(|e| e.to_string())
+}
+
+pub fn get_library_roots() -> Vec<LibraryRoot> {
+    use crate::schema::library_root::dsl as library_root_dsl;
+
+    let conn = &mut establish_connection().unwrap();
+
+    library_root_dsl::library_root
+        .order_by(library_root_dsl::path)
+        .load(conn)
+        .unwrap()
+}
+
+pub fn get_library_root_availability() -> Vec<LibraryRootAvailability> {
+    get_library_roots()
+        .into_iter()
+        .map(|root| LibraryRootAvailability {
+            available: is_root_available(&root.path, root.track_count > 0),
+            id: root.id,
+            path: root.path,
+        })
+        .collect()
+}
+
+/// Unregisters a library root and removes its tracks, along with any albums
+/// and artists left without tracks.
+pub fn remove_library_root(id: i32) -> Result<(), String> {
+    use crate::schema::library_root::dsl as library_root_dsl;
+    use crate::schema::scan_error::dsl as scan_error_dsl;
+    use crate::schema::track::dsl as track_dsl;
+
+    let conn = &mut establish_connection().unwrap();
+
+    conn.transaction(|conn| {
+        let path: String = library_root_dsl::library_root
+            .find(id)
+            .select(library_root_dsl::path)
+            .first(conn)?;
+
+        diesel::delete(track_dsl::track.filter(track_dsl::mount_point.eq(&path))).execute(conn)?;
+        diesel::delete(scan_error_dsl::scan_error.filter(scan_error_dsl::mount_point.eq(&path))).execute(conn)?;
+        diesel::delete(library_root_dsl::library_root.find(id)).execute(conn)?;
+
+        delete_orphaned_metadata(conn)
+    })
+    .map_err(|e| e.to_string())
+}
+
+/// Disabled roots keep their tracks but are skipped by
+/// [`rescan_library_roots`] and by the library watcher.
+pub fn set_library_root_enabled(id: i32, enabled: bool) {
+    use crate::schema::library_root::dsl as library_root_dsl;
+
+    let conn = &mut establish_connection().unwrap();
+
+    diesel::update(library_root_dsl::library_root.find(id))
+        .set(library_root_dsl::enabled.eq(enabled))
+        .execute(conn)
+        .unwrap();
+}
+
+pub fn rescan_library_root(id: i32) -> Result<SyncSummary, String> {
+    use crate::schema::library_root::dsl as library_root_dsl;
+
+    let conn = &mut establish_connection().unwrap();
+
+    let path: String = library_root_dsl::library_root
+        .find(id)
+        .select(library_root_dsl::path)
+        .first(conn)
+        .map_err(|e| e.to_string())?;
+
+    // The root is registered already, so it cannot overlap another one.
+    scan_mount_point(&path, &ScanCancelToken::new(), &|_| {})
+}
+
+/// Rescans every enabled library root, returning one summary per root in the
+/// order of [`get_library_roots`].
+pub fn rescan_library_roots() -> Vec<SyncSummary> {
+    get_library_roots()
+        .into_iter()
+        .filter(|root| root.enabled)
+        .map(|root| scan_mount_point(&root.path, &ScanCancelToken::new(), &|_| {}).unwrap())
+        .collect()
+}
+
+/// Points a library root at a new folder, rewriting the `location` and
+/// `mount_point` of its tracks in place so their ids are kept.
+///
+/// Fails without changing anything if any track's file is missing under
+/// `new_path`.
+pub fn relocate_library_root(id: i32, new_path: String) -> Result<LibraryRoot, String> {
+    use crate::schema::library_root::dsl as library_root_dsl;
+    use crate::schema::scan_error::dsl as scan_error_dsl;
+    use crate::schema::track::dsl as track_dsl;
+
+    if !Path::new(&new_path).is_dir() {
+        return Err(format!("{} is not a directory", new_path));
+    }
+
+    let new_path = normalize_root_path(&new_path);
+    let conn = &mut establish_connection().unwrap();
+
+    let root: LibraryRoot = library_root_dsl::library_root
+        .find(id)
+        .first(conn)
+        .map_err(|e| e.to_string())?;
+
+    let other_paths: Vec<String> = library_root_dsl::library_root
+        .filter(library_root_dsl::id.ne(id))
+        .select(library_root_dsl::path)
+        .load(conn)
+        .map_err(|e| e.to_string())?;
+
+    for other_path in other_paths {
+        if Path::new(&new_path).starts_with(&other_path) || Path::new(&other_path).starts_with(&new_path) {
+            return Err(format!("{} overlaps the library root {}", new_path, other_path));
+        }
+    }
+
+    let tracks: Vec<(i32, String)> = track_dsl::track
+        .filter(track_dsl::mount_point.eq(&root.path))
+        .select((track_dsl::id, track_dsl::location))
+        .load(conn)
+        .map_err(|e| e.to_string())?;
+
+    let mut relocated_tracks = Vec::with_capacity(tracks.len());
+    let mut missing_locations = Vec::new();
+
+    for (track_id, location) in tracks {
+        let relative = Path::new(&location)
+            .strip_prefix(&root.path)
+            .map_err(|_| format!("{} is not under {}", location, root.path))?;
+        let new_location = Path::new(&new_path).join(relative);
+
+        if new_location.is_file() {
+            relocated_tracks.push((track_id, new_location.to_string_lossy().to_string()));
+        } else {
+            missing_locations.push(new_location.to_string_lossy().to_string());
+        }
+    }
+
+    if let Some(first_missing) = missing_locations.first() {
+        return Err(format!(
+            "{} of the tracks are missing from {}, e.g. {}",
+            missing_locations.len(),
+            new_path,
+            first_missing
+        ));
+    }
+
+    conn.transaction(|conn| {
+        for (track_id, new_location) in relocated_tracks.iter() {
+            diesel::update(track_dsl::track.find(track_id))
+                .set((
+                    track_dsl::location.eq(new_location),
+                    track_dsl::mount_point.eq(&new_path),
+                ))
+                .execute(conn)?;
+        }
+
+        diesel::delete(scan_error_dsl::scan_error.filter(scan_error_dsl::mount_point.eq(&root.path))).execute(conn)?;
+
+        diesel::update(library_root_dsl::library_root.find(id))
+            .set(library_root_dsl::path.eq(&new_path))
+            .execute(conn)?;
+
+        library_root_dsl::library_root.find(id).first(conn)
+    })
+    .map_err(|e: diesel::result::Error| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{write_wav, TestLibrary};
+
+    fn track_locations() -> Vec<(i32, String)> {
+        use crate::schema::track::dsl as track_dsl;
+
+        let conn = &mut establish_connection().unwrap();
+
+        track_dsl::track
+            .select((track_dsl::id, track_dsl::location))
+            .order_by(track_dsl::id)
+            .load(conn)
+            .unwrap()
+    }
+
+    #[test]
+    fn unknown_library_roots_are_errors() {
+        let library = TestLibrary::new();
+        let root = add_library_root(library.mount_point()).unwrap();
+
+        assert!(remove_library_root(root.id + 1).is_err());
+        assert!(rescan_library_root(root.id + 1).is_err());
+        assert_eq!(get_library_roots().len(), 1);
+
+        remove_library_root(root.id).unwrap();
+
+        assert!(get_library_roots().is_empty());
+        assert!(remove_library_root(root.id).is_err());
+        assert!(rescan_library_root(root.id).is_err());
+    }
+
+    #[test]
+    fn relocating_a_root_keeps_track_ids() {
+        let library = TestLibrary::new();
+        write_wav(&library.path("a.wav"), &[("INAM", "A")]);
+        write_wav(&library.path("sub/b.wav"), &[("INAM", "B")]);
+
+        let root = add_library_root(library.mount_point()).unwrap();
+        rescan_library_root(root.id).unwrap();
+        let before = track_locations();
+
+        // A copy missing one of the files is refused.
+        let partial = library.music_dir.with_file_name("partial");
+        write_wav(&partial.join("a.wav"), &[("INAM", "A")]);
+        assert!(relocate_library_root(root.id, partial.to_string_lossy().to_string()).is_err());
+        assert_eq!(track_locations(), before);
+
+        let moved = library.music_dir.with_file_name("moved");
+        std::fs::rename(&library.music_dir, &moved).unwrap();
+
+        let relocated = relocate_library_root(root.id, moved.to_string_lossy().to_string()).unwrap();
+        assert_eq!(relocated.path, moved.to_string_lossy());
+
+        let expected: Vec<(i32, String)> = before
+            .iter()
+            .map(|(id, location)| {
+                let relative = Path::new(location).strip_prefix(&library.music_dir).unwrap();
+                (*id, moved.join(relative).to_string_lossy().to_string())
+            })
+            .collect();
+        assert_eq!(before.len(), 2);
+        assert_eq!(track_locations(), expected);
+
+        // The files are known at their new location, so nothing is parsed again.
+        let summary = rescan_library_root(root.id).unwrap();
+        assert_eq!((summary.added, summary.unchanged, summary.removed), (0, 2, 0));
+    }
+}
@@ -2,5 +2,14 @@
 // Do not put code in `mod.rs`, but put in e.g. `simple.rs`.
 //
 
+pub mod browse;
+pub mod config;
+pub mod fuzzy_search;
+pub mod library;
+pub mod query;
+pub mod query_language;
+pub mod scan;
+pub mod search;
 pub mod simple;
 pub mod utils;
+pub mod watcher;
@@ -0,0 +1,355 @@
+use diesel::sql_types::Integer;
+use diesel::sqlite::Sqlite;
+use diesel::{
+    EscapeExpressionMethods, ExpressionMethods, JoinOnDsl, NullableExpressionMethods, QueryDsl,
+    RunQueryDsl, TextExpressionMethods,
+};
+
+use crate::model::ifnull;
+
+use super::search::indexed_track_ids;
+use super::simple::{establish_connection, hidden_mount_points, tracks_by_ids, TrackDTO};
+
+/// What tracks can be sorted by. Titles and names sort by their hiragana
+/// reading, so Japanese text comes in kana order after Latin text.
+pub enum SortField {
+    Title,
+    /// The track's main artist.
+    Artist,
+    /// The album artist, or the track artist for tracks without an album.
+    AlbumArtist,
+    /// Album name, keeping albums of the same name apart.
+    Album,
+    /// Disc, then track number, then location, as albums are played.
+    TrackNumber,
+    Duration,
+    Year,
+    DateAdded,
+    Bitrate,
+    SampleRate,
+    BitDepth,
+    Channels,
+    FileSize,
+    PlayCount,
+    LastPlayed,
+}
+
+pub struct SortKey {
+    pub field: SortField,
+    pub descending: bool,
+}
+
+impl SortKey {
+    pub(crate) fn ascending(field: SortField) -> SortKey {
+        SortKey {
+            field,
+            descending: false,
+        }
+    }
+}
+
+/// A condition every track returned by a [`TrackQuery`] must meet.
+#[derive(PartialEq, Debug)]
+pub enum TrackFilter {
+    /// Tracks the artist is credited on in any role.
+    Artist(i32),
+    Album(i32),
+    Genre(i32),
+    Year { min: Option<i32>, max: Option<i32> },
+    DurationMs { min: Option<i32>, max: Option<i32> },
+    /// Lowercase codec name, e.g. `flac`, `mp3`, `alac` or `pcm`.
+    Codec(String),
+    /// File extension without the dot, e.g. `flac`, `m4a` or `wav`, matched
+    /// case-insensitively.
+    FileExtension(String),
+    Lossless(bool),
+    Bitrate { min: Option<i32>, max: Option<i32> },
+    MinSampleRate(i32),
+    MinBitDepth(i32),
+    Channels(i32),
+    /// Tracks below the library root with this id.
+    LibraryRoot(i32),
+    /// Tracks with words starting with every word of `text` in `field`, as
+    /// written or by their romaji or hiragana reading.
+    Text { field: TextField, text: String },
+    /// Tracks not matching the inner filter.
+    Not(Box<TrackFilter>),
+}
+
+/// Which text of a track a [`TrackFilter::Text`] looks at.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TextField {
+    /// Title, artists, album, genres and composers.
+    Any,
+    Title,
+    /// Every credited artist and the album artist.
+    Artist,
+    Album,
+    Genre,
+    Composer,
+}
+
+pub struct TrackQuery {
+    /// Sort keys, most significant first. Tracks missing a sorted value come
+    /// last in either direction, and ties are broken by id.
+    pub sort: Vec<SortKey>,
+    pub filters: Vec<TrackFilter>,
+    pub hide_offline: bool,
+    pub offset: u32,
+    pub limit: Option<u32>,
+}
+
+impl TrackQuery {
+    pub(crate) fn sorted(sort: Vec<SortKey>, filters: Vec<TrackFilter>, hide_offline: bool) -> TrackQuery {
+        TrackQuery {
+            sort,
+            filters,
+            hide_offline,
+            offset: 0,
+            limit: None,
+        }
+    }
+}
+
+/// Orders `$query` by `$expr` in the direction of `$descending`, with nulls
+/// last either way.
+macro_rules! then_order_by_nulls_last {
+    ($query:expr, $expr:expr, $descending:expr) => {
+        if $descending {
+            $query.then_order_by(($expr.is_null(), $expr.desc()))
+        } else {
+            $query.then_order_by(($expr.is_null(), $expr.asc()))
+        }
+    };
+}
+
+/// Narrows `$statement`, a boxed query over `track`, to the tracks matching
+/// `$filter`.
+macro_rules! filter_tracks {
+    ($statement:expr, $filter:expr) => {{
+        use crate::schema::library_root::dsl as library_root_dsl;
+        use crate::schema::track::dsl as track_dsl;
+        use crate::schema::track_artist::dsl as track_artist_dsl;
+        use crate::schema::track_genre::dsl as track_genre_dsl;
+
+        let mut statement = $statement;
+        match $filter {
+            TrackFilter::Artist(artist_id) => statement.filter(
+                track_dsl::id.eq_any(
+                    track_artist_dsl::track_artist
+                        .select(track_artist_dsl::track_id)
+                        .filter(track_artist_dsl::artist_id.eq(artist_id)),
+                ),
+            ),
+            TrackFilter::Album(album_id) => statement.filter(track_dsl::album_id.eq(album_id)),
+            TrackFilter::Genre(genre_id) => statement.filter(
+                track_dsl::id.eq_any(
+                    track_genre_dsl::track_genre
+                        .select(track_genre_dsl::track_id)
+                        .filter(track_genre_dsl::genre_id.eq(genre_id)),
+                ),
+            ),
+            TrackFilter::Year { min, max } => {
+                if let Some(min) = min {
+                    statement = statement.filter(track_dsl::year.ge(min));
+                }
+                match max {
+                    Some(max) => statement.filter(track_dsl::year.le(max)),
+                    None => statement,
+                }
+            }
+            TrackFilter::DurationMs { min, max } => {
+                if let Some(min) = min {
+                    statement = statement.filter(track_dsl::duration_ms.ge(min));
+                }
+                match max {
+                    Some(max) => statement.filter(track_dsl::duration_ms.le(max)),
+                    None => statement,
+                }
+            }
+            TrackFilter::Codec(codec) => statement.filter(track_dsl::codec.eq(codec.to_lowercase())),
+            TrackFilter::FileExtension(extension) => {
+                // `LIKE` ignores ASCII case, as extensions are matched.
+                let escaped = extension.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_");
+                statement.filter(track_dsl::location.like(format!("%.{}", escaped)).escape('\\'))
+            }
+            TrackFilter::Lossless(lossless) => statement.filter(track_dsl::lossless.eq(lossless)),
+            TrackFilter::Bitrate { min, max } => {
+                if let Some(min) = min {
+                    statement = statement.filter(track_dsl::bitrate.ge(min));
+                }
+                match max {
+                    Some(max) => statement.filter(track_dsl::bitrate.le(max)),
+                    None => statement,
+                }
+            }
+            TrackFilter::MinSampleRate(min) => statement.filter(track_dsl::sample_rate.ge(min)),
+            TrackFilter::MinBitDepth(min) => statement.filter(track_dsl::bit_depth.ge(min)),
+            TrackFilter::Channels(channels) => statement.filter(track_dsl::channels.eq(channels)),
+            TrackFilter::LibraryRoot(library_root_id) => statement.filter(
+                track_dsl::mount_point.eq_any(
+                    library_root_dsl::library_root
+                        .select(library_root_dsl::path)
+                        .filter(library_root_dsl::id.eq(library_root_id)),
+                ),
+            ),
+            TrackFilter::Text { field, text } => match indexed_track_ids(&text, field) {
+                Some(track_ids) => statement.filter(track_dsl::id.eq_any(track_ids)),
+                None => statement,
+            },
+            TrackFilter::Not(filter) => statement.filter(track_dsl::id.ne_all(track_ids_matching(*filter))),
+        }
+    }};
+}
+
+/// Ids of every track matching `filter`, for use as a subquery.
+fn track_ids_matching(filter: TrackFilter) -> crate::schema::track::BoxedQuery<'static, Sqlite, Integer> {
+    use crate::schema::track::dsl as track_dsl;
+
+    let statement = track_dsl::track.select(track_dsl::id).into_boxed();
+    filter_tracks!(statement, filter)
+}
+
+/// Ids of the tracks matching `query`, in its order.
+pub fn query_track_ids(query: TrackQuery) -> Vec<i32> {
+    use crate::schema;
+    use crate::schema::track::dsl as track_dsl;
+
+    let conn = &mut establish_connection().unwrap();
+    let hidden_mount_points = hidden_mount_points(conn, query.hide_offline);
+
+    let album_artist = diesel::alias!(schema::artist as album_artist);
+
+    let mut statement = track_dsl::track
+        .left_join(schema::artist::table.on(track_dsl::artist_id.eq(schema::artist::id.nullable())))
+        .left_join(schema::album::table.on(track_dsl::album_id.eq(schema::album::id.nullable())))
+        .left_join(album_artist.on(schema::album::artist_id.eq(album_artist.field(schema::artist::id).nullable())))
+        .select(track_dsl::id)
+        .filter(track_dsl::mount_point.ne_all(hidden_mount_points))
+        .into_boxed();
+
+    for filter in query.filters {
+        statement = filter_tracks!(statement, filter);
+    }
+
+    for key in query.sort {
+        statement = match key.field {
+            // Readings are missing on tracks, artists and albums stored
+            // before they were added, which then sort by the text itself.
+            SortField::Title => then_order_by_nulls_last!(
+                statement,
+                ifnull(track_dsl::title_hiragana, track_dsl::title),
+                key.descending
+            ),
+            SortField::Artist => then_order_by_nulls_last!(
+                statement,
+                ifnull(schema::artist::name_hiragana, schema::artist::name.nullable()),
+                key.descending
+            ),
+            SortField::AlbumArtist => then_order_by_nulls_last!(
+                statement,
+                ifnull(
+                    ifnull(
+                        album_artist.field(schema::artist::name_hiragana),
+                        album_artist.field(schema::artist::name).nullable()
+                    ),
+                    ifnull(schema::artist::name_hiragana, schema::artist::name.nullable())
+                ),
+                key.descending
+            ),
+            SortField::Album => {
+                let statement = then_order_by_nulls_last!(
+                    statement,
+                    ifnull(schema::album::name_hiragana, schema::album::name.nullable()),
+                    key.descending
+                );
+                if key.descending {
+                    statement.then_order_by(track_dsl::album_id.desc())
+                } else {
+                    statement.then_order_by(track_dsl::album_id.asc())
+                }
+            }
+            SortField::TrackNumber => {
+                let statement = then_order_by_nulls_last!(statement, track_dsl::disc, key.descending);
+                let statement = then_order_by_nulls_last!(statement, track_dsl::number, key.descending);
+                if key.descending {
+                    statement.then_order_by(track_dsl::location.desc())
+                } else {
+                    statement.then_order_by(track_dsl::location.asc())
+                }
+            }
+            SortField::Duration => then_order_by_nulls_last!(statement, track_dsl::duration_ms, key.descending),
+            SortField::Year => then_order_by_nulls_last!(statement, track_dsl::year, key.descending),
+            SortField::DateAdded => then_order_by_nulls_last!(statement, track_dsl::created_at, key.descending),
+            SortField::Bitrate => then_order_by_nulls_last!(statement, track_dsl::bitrate, key.descending),
+            SortField::SampleRate => then_order_by_nulls_last!(statement, track_dsl::sample_rate, key.descending),
+            SortField::BitDepth => then_order_by_nulls_last!(statement, track_dsl::bit_depth, key.descending),
+            SortField::Channels => then_order_by_nulls_last!(statement, track_dsl::channels, key.descending),
+            SortField::FileSize => then_order_by_nulls_last!(statement, track_dsl::file_size, key.descending),
+            SortField::PlayCount => then_order_by_nulls_last!(statement, track_dsl::play_count, key.descending),
+            SortField::LastPlayed => then_order_by_nulls_last!(statement, track_dsl::last_played_at, key.descending),
+        };
+    }
+
+    statement = statement.then_order_by(track_dsl::id).offset(query.offset as i64);
+    if let Some(limit) = query.limit {
+        statement = statement.limit(limit as i64);
+    }
+
+    statement.load(conn).unwrap()
+}
+
+/// The tracks matching `query`, in its order.
+pub fn query_tracks(query: TrackQuery) -> Vec<TrackDTO> {
+    let ids = query_track_ids(query);
+
+    let conn = &mut establish_connection().unwrap();
+    tracks_by_ids(conn, &ids)
+}
+
+#[cfg(test)]
+mod tests {
+    use diesel::connection::SimpleConnection;
+
+    use super::*;
+    use crate::api::simple::sync_directory;
+    use crate::testing::{write_wav, TestLibrary};
+
+    #[test]
+    fn names_without_readings_sort_by_the_text_itself() {
+        use crate::schema::track::dsl as track_dsl;
+
+        let library = TestLibrary::new();
+        for n in 1..=3 {
+            write_wav(
+                &library.path(&format!("{n}.wav")),
+                &[("INAM", "Title"), ("IART", &format!("Artist {n}"))],
+            );
+        }
+        sync_directory(library.mount_point()).unwrap();
+
+        let conn = &mut establish_connection().unwrap();
+        let ids: Vec<i32> = track_dsl::track
+            .select(track_dsl::id)
+            .order_by(track_dsl::id)
+            .load(conn)
+            .unwrap();
+
+        // Only the last track has readings, like one scanned after they were
+        // added. The other two are out of id order by their text.
+        let mut statements = String::new();
+        for (id, text, reading) in [(ids[0], "b", "NULL"), (ids[1], "a", "NULL"), (ids[2], "z", "'A'")] {
+            statements.push_str(&format!(
+                "UPDATE track SET title = '{text}', title_hiragana = {reading} WHERE id = {id};
+                 UPDATE artist SET name = '{text}', name_hiragana = {reading}
+                     WHERE id = (SELECT artist_id FROM track WHERE id = {id});"
+            ));
+        }
+        conn.batch_execute(&statements).unwrap();
+
+        for field in [SortField::Title, SortField::Artist] {
+            let query = TrackQuery::sorted(vec![SortKey::ascending(field)], Vec::new(), false);
+            assert_eq!(query_track_ids(query), [ids[2], ids[1], ids[0]]);
+        }
+    }
+}
@@ -0,0 +1,500 @@
+use super::query::{TextField, TrackFilter};
+
+/// Why a query could not be parsed, with the span of the offending text in
+/// UTF-16 code units from the start of the query, as Dart indexes strings.
+#[derive(Debug)]
+pub struct QueryParseError {
+    pub message: String,
+    pub start: u32,
+    pub end: u32,
+}
+
+/// How a numeric field's values are written, and how wide one written value
+/// is: `duration:5m` matches 5:00.000 through 5:00.999, not only tracks of
+/// exactly 300000 ms.
+#[derive(Clone, Copy)]
+enum NumberKind {
+    Year,
+    /// A duration such as `5m`, `1h30m`, `90s` or `3:30`, in milliseconds.
+    Duration,
+    /// A bitrate in kbit/s, optionally written with a `k` or `kbps` suffix.
+    Bitrate,
+}
+
+impl NumberKind {
+    fn step(self) -> i32 {
+        match self {
+            NumberKind::Year | NumberKind::Bitrate => 1,
+            NumberKind::Duration => 1000,
+        }
+    }
+
+    fn parse(self, value: &str) -> Option<i32> {
+        match self {
+            NumberKind::Year => value.parse().ok(),
+            NumberKind::Duration => duration_ms_of(value),
+            NumberKind::Bitrate => value
+                .strip_suffix("kbps")
+                .or_else(|| value.strip_suffix('k'))
+                .unwrap_or(value)
+                .parse()
+                .ok(),
+        }
+    }
+
+    fn expected(self) -> &'static str {
+        match self {
+            NumberKind::Year => "a year such as `2018`",
+            NumberKind::Duration => "a duration such as `90s`, `5m`, `1h30m` or `3:30`",
+            NumberKind::Bitrate => "a bitrate in kbit/s such as `320`",
+        }
+    }
+}
+
+/// Reads a duration written as `3:30` or `1:02:03`, or as numbers followed
+/// by `h`, `m` or `s`, with a bare number taken as seconds.
+fn duration_ms_of(value: &str) -> Option<i32> {
+    let seconds: u32 = if value.contains(':') {
+        let mut seconds: u32 = 0;
+        for (index, part) in value.split(':').enumerate() {
+            if index > 2 || part.is_empty() || !part.bytes().all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+            seconds = seconds.checked_mul(60)?.checked_add(part.parse().ok()?)?;
+        }
+        seconds
+    } else {
+        let mut seconds: u32 = 0;
+        let mut rest = value;
+        while !rest.is_empty() {
+            let digits = rest.bytes().take_while(|b| b.is_ascii_digit()).count();
+            if digits == 0 {
+                return None;
+            }
+            let number: u32 = rest[..digits].parse().ok()?;
+            rest = &rest[digits..];
+
+            let unit = match rest.chars().next() {
+                Some('h') => 3600,
+                Some('m') => 60,
+                Some('s') => 1,
+                None => 1,
+                Some(_) => return None,
+            };
+            if !rest.is_empty() {
+                rest = &rest[1..];
+            }
+            seconds = seconds.checked_add(number.checked_mul(unit)?)?;
+        }
+        seconds
+    };
+
+    seconds.checked_mul(1000)?.try_into().ok()
+}
+
+/// A value or range such as `2018`, `2018..2020`, `..2020`, `>5m` or `<=320`,
+/// turned into inclusive bounds.
+fn range_of(value: &str, kind: NumberKind) -> Option<(Option<i32>, Option<i32>)> {
+    let step = kind.step();
+    let last_of = |value: i32| value.checked_add(step - 1);
+
+    if let Some((min, max)) = value.split_once("..") {
+        let min = match min {
+            "" => None,
+            min => Some(kind.parse(min)?),
+        };
+        let max = match max {
+            "" => None,
+            max => Some(last_of(kind.parse(max)?)?),
+        };
+        if min.is_none() && max.is_none() {
+            return None;
+        }
+        return Some((min, max));
+    }
+
+    if let Some(value) = value.strip_prefix(">=") {
+        Some((Some(kind.parse(value)?), None))
+    } else if let Some(value) = value.strip_prefix("<=") {
+        Some((None, Some(last_of(kind.parse(value)?)?)))
+    } else if let Some(value) = value.strip_prefix('>') {
+        Some((Some(kind.parse(value)?.checked_add(step)?), None))
+    } else if let Some(value) = value.strip_prefix('<') {
+        Some((None, Some(kind.parse(value)?.checked_sub(1)?)))
+    } else {
+        let value = kind.parse(value.strip_prefix('=').unwrap_or(value))?;
+        Some((Some(value), Some(last_of(value)?)))
+    }
+}
+
+/// A piece of the query with its span in characters.
+struct Spanned {
+    text: String,
+    start: usize,
+    end: usize,
+}
+
+struct Parser {
+    chars: Vec<char>,
+    position: usize,
+}
+
+impl Parser {
+    fn error(message: String, start: usize, end: usize) -> QueryParseError {
+        QueryParseError {
+            message,
+            start: start as u32,
+            end: end as u32,
+        }
+    }
+
+    /// Turns the span of `error` from characters into UTF-16 code units.
+    fn in_utf16(&self, error: QueryParseError) -> QueryParseError {
+        let offset = |position: u32| {
+            self.chars[..position as usize]
+                .iter()
+                .map(|c| c.len_utf16() as u32)
+                .sum()
+        };
+
+        QueryParseError {
+            start: offset(error.start),
+            end: offset(error.end),
+            message: error.message,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.position += 1;
+        }
+    }
+
+    /// Reads a quoted string, in which `\"` and `\\` stand for themselves.
+    fn quoted(&mut self) -> Result<Spanned, QueryParseError> {
+        let start = self.position;
+        self.position += 1;
+
+        let mut text = String::new();
+        loop {
+            match self.peek() {
+                None => return Err(Parser::error("Unterminated quote".to_string(), start, self.position)),
+                Some('"') => {
+                    self.position += 1;
+                    break;
+                }
+                Some('\\') if matches!(self.chars.get(self.position + 1), Some('"' | '\\')) => {
+                    text.push(self.chars[self.position + 1]);
+                    self.position += 2;
+                }
+                Some(c) => {
+                    text.push(c);
+                    self.position += 1;
+                }
+            }
+        }
+
+        Ok(Spanned {
+            text,
+            start,
+            end: self.position,
+        })
+    }
+
+    /// Reads up to the next whitespace, or also up to a `:` if `stop_at_colon`.
+    fn bare(&mut self, stop_at_colon: bool) -> Result<Spanned, QueryParseError> {
+        let start = self.position;
+
+        while let Some(c) = self.peek() {
+            if c.is_whitespace() || (stop_at_colon && c == ':') {
+                break;
+            }
+            if c == '"' {
+                return Err(Parser::error(
+                    "Quotes must surround a whole value".to_string(),
+                    self.position,
+                    self.position + 1,
+                ));
+            }
+            self.position += 1;
+        }
+
+        Ok(Spanned {
+            text: self.chars[start..self.position].iter().collect(),
+            start,
+            end: self.position,
+        })
+    }
+
+    fn value(&mut self) -> Result<Spanned, QueryParseError> {
+        match self.peek() {
+            Some('"') => self.quoted(),
+            _ => self.bare(false),
+        }
+    }
+
+    /// Reads one term: free text, or `field:value`, either optionally negated
+    /// with a leading `-`.
+    fn term(&mut self) -> Result<TrackFilter, QueryParseError> {
+        let start = self.position;
+
+        let negated = self.peek() == Some('-');
+        if negated {
+            self.position += 1;
+            match self.peek() {
+                Some(c) if !c.is_whitespace() => {}
+                _ => return Err(Parser::error("Expected a term after `-`".to_string(), start, self.position)),
+            }
+        }
+
+        let filter = if self.peek() == Some('"') {
+            let text = self.quoted()?;
+            TrackFilter::Text {
+                field: TextField::Any,
+                text: text.text,
+            }
+        } else {
+            let word = self.bare(true)?;
+            if self.peek() == Some(':') && !word.text.is_empty() && !is_field(&word.text) {
+                // Text such as `Re:Zero` is searched for as written.
+                let rest = self.bare(false)?;
+                TrackFilter::Text {
+                    field: TextField::Any,
+                    text: word.text + &rest.text,
+                }
+            } else if self.peek() == Some(':') {
+                if word.text.is_empty() {
+                    return Err(Parser::error(
+                        "Expected a field name before `:`".to_string(),
+                        self.position,
+                        self.position + 1,
+                    ));
+                }
+                self.position += 1;
+                let value = self.value()?;
+                if value.text.trim().is_empty() {
+                    return Err(Parser::error(
+                        format!("Expected a value after `{}:`", word.text),
+                        word.start,
+                        self.position,
+                    ));
+                }
+                field_filter(&word, value)?
+            } else {
+                TrackFilter::Text {
+                    field: TextField::Any,
+                    text: word.text,
+                }
+            }
+        };
+
+        if self.peek().is_some_and(|c| !c.is_whitespace()) {
+            return Err(Parser::error(
+                "Expected a space between terms".to_string(),
+                self.position,
+                self.position + 1,
+            ));
+        }
+
+        Ok(if negated { TrackFilter::Not(Box::new(filter)) } else { filter })
+    }
+}
+
+/// Names that start a `field:value` term, matched case-insensitively.
+const FIELDS: &[&str] = &[
+    "title", "artist", "album", "genre", "composer", "year", "duration", "bitrate", "format", "codec", "lossless",
+];
+
+fn is_field(name: &str) -> bool {
+    FIELDS.iter().any(|field| field.eq_ignore_ascii_case(name))
+}
+
+/// The filter for `field:value`, where `field` is one of [`FIELDS`].
+fn field_filter(field: &Spanned, value: Spanned) -> Result<TrackFilter, QueryParseError> {
+    let text_field = match field.text.to_lowercase().as_str() {
+        "title" => Some(TextField::Title),
+        "artist" => Some(TextField::Artist),
+        "album" => Some(TextField::Album),
+        "genre" => Some(TextField::Genre),
+        "composer" => Some(TextField::Composer),
+        _ => None,
+    };
+    if let Some(text_field) = text_field {
+        return Ok(TrackFilter::Text {
+            field: text_field,
+            text: value.text,
+        });
+    }
+
+    let range = |kind: NumberKind| {
+        range_of(&value.text.to_lowercase(), kind).ok_or_else(|| {
+            Parser::error(format!("Expected {}, or a range of them", kind.expected()), value.start, value.end)
+        })
+    };
+
+    match field.text.to_lowercase().as_str() {
+        "year" => {
+            let (min, max) = range(NumberKind::Year)?;
+            Ok(TrackFilter::Year { min, max })
+        }
+        "duration" => {
+            let (min, max) = range(NumberKind::Duration)?;
+            Ok(TrackFilter::DurationMs { min, max })
+        }
+        "bitrate" => {
+            let (min, max) = range(NumberKind::Bitrate)?;
+            Ok(TrackFilter::Bitrate { min, max })
+        }
+        "format" => Ok(TrackFilter::FileExtension(value.text.trim_start_matches('.').to_lowercase())),
+        "codec" => Ok(TrackFilter::Codec(value.text.to_lowercase())),
+        "lossless" => match value.text.to_lowercase().as_str() {
+            "yes" | "true" => Ok(TrackFilter::Lossless(true)),
+            "no" | "false" => Ok(TrackFilter::Lossless(false)),
+            _ => Err(Parser::error("Expected `yes` or `no`".to_string(), value.start, value.end)),
+        },
+        _ => unreachable!("`{}` is not in FIELDS", field.text),
+    }
+}
+
+/// Parses a search query into filters every matching track meets, for use in
+/// a [`super::query::TrackQuery`].
+///
+/// A query is a list of terms separated by spaces. A term is free text
+/// matched against title, artists, album, genres and composers, or
+/// `field:value` with one of these fields, other text before a `:` being
+/// free text too:
+///
+/// - `title`, `artist`, `album`, `genre`, `composer`: text in that field
+/// - `year`: a year or range, e.g. `2018`, `2018..2020`, `>=2018`
+/// - `duration`: a duration or range, e.g. `>5m`, `3:00..4:30`, `<90s`
+/// - `bitrate`: kbit/s or a range, e.g. `>=320`
+/// - `format`: a file extension, e.g. `flac`, `m4a`, `wav`
+/// - `codec`: e.g. `mp3`, `aac`, `alac`, `vorbis`, or `pcm` for WAV and AIFF
+/// - `lossless`: `yes` or `no`
+///
+/// Values with spaces are quoted, e.g. `artist:"Kenshi Yonezu"`, and a
+/// leading `-` excludes the tracks a term matches, e.g. `-live`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn parse_track_filters(query: String) -> Result<Vec<TrackFilter>, QueryParseError> {
+    let mut parser = Parser {
+        chars: query.chars().collect(),
+        position: 0,
+    };
+
+    let mut filters = Vec::new();
+    loop {
+        parser.skip_whitespace();
+        if parser.peek().is_none() {
+            break;
+        }
+        filters.push(parser.term().map_err(|error| parser.in_utf16(error))?);
+    }
+
+    Ok(filters)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(query: &str) -> Vec<TrackFilter> {
+        parse_track_filters(query.to_string()).unwrap()
+    }
+
+    fn error_span(query: &str) -> (u32, u32) {
+        let error = parse_track_filters(query.to_string()).unwrap_err();
+        (error.start, error.end)
+    }
+
+    fn text(text: &str) -> TrackFilter {
+        TrackFilter::Text {
+            field: TextField::Any,
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn year_ranges() {
+        assert_eq!(parse("year:2018"), [TrackFilter::Year { min: Some(2018), max: Some(2018) }]);
+        assert_eq!(parse("year:2018..2020"), [TrackFilter::Year { min: Some(2018), max: Some(2020) }]);
+        assert_eq!(parse("year:..2020"), [TrackFilter::Year { min: None, max: Some(2020) }]);
+        assert_eq!(parse("year:2018.."), [TrackFilter::Year { min: Some(2018), max: None }]);
+        assert_eq!(parse("year:>2018"), [TrackFilter::Year { min: Some(2019), max: None }]);
+        assert_eq!(parse("year:<=2018"), [TrackFilter::Year { min: None, max: Some(2018) }]);
+        assert_eq!(parse("bitrate:>=320kbps"), [TrackFilter::Bitrate { min: Some(320), max: None }]);
+    }
+
+    #[test]
+    fn durations() {
+        assert_eq!(duration_ms_of("90s"), Some(90_000));
+        assert_eq!(duration_ms_of("5m"), Some(300_000));
+        assert_eq!(duration_ms_of("1h30m"), Some(5_400_000));
+        assert_eq!(duration_ms_of("3:30"), Some(210_000));
+        assert_eq!(duration_ms_of("1:02:03"), Some(3_723_000));
+        assert_eq!(duration_ms_of("1:2:3:4"), None);
+        assert_eq!(duration_ms_of("5x"), None);
+
+        // A written duration covers the whole second it names.
+        assert_eq!(
+            parse("duration:5m"),
+            [TrackFilter::DurationMs { min: Some(300_000), max: Some(300_999) }]
+        );
+        assert_eq!(parse("duration:>5m"), [TrackFilter::DurationMs { min: Some(301_000), max: None }]);
+        assert_eq!(parse("duration:<90s"), [TrackFilter::DurationMs { min: None, max: Some(89_999) }]);
+    }
+
+    #[test]
+    fn quoting() {
+        assert_eq!(
+            parse(r#"artist:"Kenshi Yonezu" "a \"b\" c""#),
+            [
+                TrackFilter::Text {
+                    field: TextField::Artist,
+                    text: "Kenshi Yonezu".to_string(),
+                },
+                text(r#"a "b" c"#),
+            ]
+        );
+    }
+
+    #[test]
+    fn negation() {
+        assert_eq!(parse("-live"), [TrackFilter::Not(Box::new(text("live")))]);
+        assert_eq!(
+            parse("rock -lossless:yes"),
+            [text("rock"), TrackFilter::Not(Box::new(TrackFilter::Lossless(true)))]
+        );
+    }
+
+    #[test]
+    fn formats_and_codecs() {
+        assert_eq!(parse("format:WAV"), [TrackFilter::FileExtension("wav".to_string())]);
+        assert_eq!(parse("format:.m4a"), [TrackFilter::FileExtension("m4a".to_string())]);
+        assert_eq!(parse("codec:ALAC"), [TrackFilter::Codec("alac".to_string())]);
+    }
+
+    #[test]
+    fn unknown_fields_are_text() {
+        assert_eq!(parse("Re:Zero"), [text("Re:Zero")]);
+        assert_eq!(parse("-mood:happy"), [TrackFilter::Not(Box::new(text("mood:happy")))]);
+        assert_eq!(parse("Title:x"), [TrackFilter::Text { field: TextField::Title, text: "x".to_string() }]);
+    }
+
+    #[test]
+    fn error_spans() {
+        // Spans count UTF-16 code units, not bytes or characters.
+        assert_eq!(error_span("東京 year:abc"), (8, 11));
+        assert_eq!(error_span("🎵 year:abc"), (8, 11));
+        assert_eq!(error_span("𠮷野家 -"), (5, 6));
+        assert_eq!(error_span("\"open"), (0, 5));
+        assert_eq!(error_span("- x"), (0, 1));
+        assert_eq!(error_span(":x"), (0, 1));
+        assert_eq!(error_span("album:"), (0, 6));
+        assert_eq!(error_span("a\"b\""), (1, 2));
+        assert_eq!(error_span("lossless:maybe"), (9, 14));
+    }
+}
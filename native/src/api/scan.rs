@@ -0,0 +1,1355 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, AtomicU32, Ordering},
+        Arc,
+    },
+};
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+use diesel::{
+    Connection, ExpressionMethods, NullableExpressionMethods, OptionalExtension, QueryDsl, QueryResult, RunQueryDsl,
+    SqliteConnection, SqliteExpressionMethods,
+};
+use jwalk::WalkDir;
+use lofty::id3::v1::GENRES;
+use lofty::{error::ErrorKind, Accessor, AudioFile, FileType, ItemKey, LoftyError, Probe, Tag, TaggedFileExt};
+use rayon::iter::{ParallelBridge, ParallelIterator};
+
+use crate::frb_generated::StreamSink;
+use crate::model::{NewAlbum, NewArtist, NewGenre, NewScanError, NewTrack, NewTrackArtist, NewTrackGenre, ScanError};
+
+use super::config::{get_config, Config};
+use super::library::{is_root_available, normalize_root_path, refresh_library_root, register_library_root};
+use super::search::index_tracks;
+use super::simple::{establish_connection, get_cache_path, ArtistRole};
+
+pub(crate) struct ParsedTrack {
+    title: Option<String>,
+    artists: Vec<ArtistCredit>,
+    album_artist: Option<String>,
+    album: Option<String>,
+    musicbrainz_release_id: Option<String>,
+    release_type: Option<String>,
+    number: Option<i32>,
+    disc: Option<i32>,
+    duration_ms: i32,
+    location: String,
+    mount_point: String,
+    picture_id: Option<String>,
+    file_size: i64,
+    file_modified_at: Option<NaiveDateTime>,
+    year: Option<i32>,
+    date: Option<String>,
+    original_date: Option<String>,
+    genre: Option<String>,
+    genres: Vec<String>,
+    composer: Option<String>,
+    comment: Option<String>,
+    track_total: Option<i32>,
+    disc_total: Option<i32>,
+    bpm: Option<f64>,
+    isrc: Option<String>,
+    label: Option<String>,
+    codec: Option<String>,
+    bitrate: Option<i32>,
+    sample_rate: Option<i32>,
+    bit_depth: Option<i32>,
+    channels: Option<i32>,
+    lossless: bool,
+    disc_subtitle: Option<String>,
+    title_romaji: Option<String>,
+    title_hiragana: Option<String>,
+}
+
+/// Size and modification time of a file on disk, used to tell whether a
+/// previously scanned file needs to be parsed again.
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) struct FileStamp {
+    pub(crate) size: i64,
+    pub(crate) modified_at: Option<NaiveDateTime>,
+}
+
+impl FileStamp {
+    pub(crate) fn read<P: AsRef<Path>>(path: P) -> std::io::Result<FileStamp> {
+        let metadata = fs::metadata(path)?;
+
+        Ok(FileStamp {
+            size: metadata.len() as i64,
+            modified_at: metadata
+                .modified()
+                .ok()
+                .map(|modified| DateTime::<Utc>::from(modified).naive_utc()),
+        })
+    }
+}
+
+/// Why a file is considered for import.
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) enum MusicFileMatch {
+    /// Its extension is in [`Config::extensions`].
+    Extension,
+    /// It has no extension, or one in [`Config::sniffed_extensions`], and
+    /// [`Config::detect_by_content`] is set, so it is imported if
+    /// [`sniffs_as_music`].
+    Content,
+}
+
+/// Whether `path` may be imported, judging only by its name.
+pub(crate) fn match_music_file<P: AsRef<Path>>(path: P, config: &Config) -> Option<MusicFileMatch> {
+    let path = path.as_ref();
+
+    if path.is_dir() {
+        return None;
+    }
+
+    let sniffed = match path.extension() {
+        Some(ext) => {
+            let ext = ext.to_string_lossy();
+
+            if config.extensions.iter().any(|allowed| allowed.eq_ignore_ascii_case(&ext)) {
+                return Some(MusicFileMatch::Extension);
+            }
+
+            config
+                .sniffed_extensions
+                .iter()
+                .any(|sniffed| sniffed.eq_ignore_ascii_case(&ext))
+        }
+        None => true,
+    };
+
+    if config.detect_by_content && sniffed {
+        Some(MusicFileMatch::Content)
+    } else {
+        None
+    }
+}
+
+/// Whether the content of `path` looks like a format lofty can read.
+pub(crate) fn sniffs_as_music<P: AsRef<Path>>(path: P) -> bool {
+    Probe::open(path)
+        .ok()
+        .and_then(|probe| probe.guess_file_type().ok())
+        .and_then(|probe| probe.file_type())
+        .is_some()
+}
+
+/// An artist credited on a parsed track.
+struct ArtistCredit {
+    name: String,
+    role: ArtistRole,
+}
+
+/// Whether artists following `separator` are featured on the track rather
+/// than its main artists.
+fn is_featuring_separator(separator: &str) -> bool {
+    matches!(separator.trim().trim_end_matches('.'), "feat" | "ft" | "featuring")
+}
+
+/// Splits one artist tag value on `separators` and appends the artists to
+/// `credits`, skipping ones already credited in the same role.
+fn split_artists(value: &str, separators: &[String], mut role: ArtistRole, credits: &mut Vec<ArtistCredit>) {
+    // ASCII lowercasing keeps byte offsets, so matches index into `value` too.
+    let lowercase_value = value.to_ascii_lowercase();
+    let separators: Vec<String> = separators
+        .iter()
+        .filter(|separator| !separator.is_empty())
+        .map(|separator| separator.to_ascii_lowercase())
+        .collect();
+
+    let mut start = 0;
+    loop {
+        let next = separators
+            .iter()
+            .filter_map(|separator| {
+                lowercase_value[start..]
+                    .find(separator.as_str())
+                    .map(|offset| (start + offset, separator))
+            })
+            .min_by_key(|(index, separator)| (*index, std::cmp::Reverse(separator.len())));
+
+        let end = next.map_or(value.len(), |(index, _)| index);
+        let name = value[start..end].trim();
+
+        if !name.is_empty() && !credits.iter().any(|credit| credit.role == role && credit.name == name) {
+            credits.push(ArtistCredit {
+                name: name.to_string(),
+                role,
+            });
+        }
+
+        match next {
+            Some((index, separator)) => {
+                if role == ArtistRole::Main && is_featuring_separator(separator) {
+                    role = ArtistRole::Featured;
+                }
+                start = index + separator.len();
+            }
+            None => break,
+        }
+    }
+}
+
+/// Names the codec of a file and tells whether it is lossless.
+///
+/// MP4 files only report a bit depth for ALAC, which tells it apart from AAC.
+fn codec_of(file_type: FileType, bit_depth: Option<i32>) -> (Option<String>, bool) {
+    let (codec, lossless) = match file_type {
+        FileType::Aac => ("aac", false),
+        FileType::Aiff => ("pcm", true),
+        FileType::Ape => ("ape", true),
+        FileType::Flac => ("flac", true),
+        FileType::Mpeg => ("mp3", false),
+        FileType::Mp4 if bit_depth.is_some() => ("alac", true),
+        FileType::Mp4 => ("aac", false),
+        FileType::Mpc => ("musepack", false),
+        FileType::Opus => ("opus", false),
+        FileType::Vorbis => ("vorbis", false),
+        FileType::Speex => ("speex", false),
+        FileType::Wav => ("pcm", true),
+        FileType::WavPack => ("wavpack", true),
+        FileType::Custom(name) => return (Some(name.to_ascii_lowercase()), false),
+        _ => return (None, false),
+    };
+
+    (Some(codec.to_string()), lossless)
+}
+
+/// Expands ID3v1 genre references: `17` and `(17)` become `Rock`, and
+/// `(17)(18)Hard Rock` becomes `Rock`, `Techno` and `Hard Rock`.
+fn expand_id3v1_genres(value: &str) -> Vec<String> {
+    let value = value.trim();
+
+    if let Ok(index) = value.parse::<usize>() {
+        return match GENRES.get(index) {
+            Some(genre) => vec![genre.to_string()],
+            None => vec![value.to_string()],
+        };
+    }
+
+    let mut genres = Vec::new();
+    let mut rest = value;
+
+    while let Some(stripped) = rest.strip_prefix('(') {
+        // "((" escapes a genre name that starts with a parenthesis.
+        if stripped.starts_with('(') {
+            rest = stripped;
+            break;
+        }
+
+        let Some(end) = stripped.find(')') else { break };
+
+        let genre = match &stripped[..end] {
+            "RX" => "Remix",
+            "CR" => "Cover",
+            reference => match reference.parse::<usize>().ok().and_then(|index| GENRES.get(index)) {
+                Some(genre) => genre,
+                None => break,
+            },
+        };
+
+        genres.push(genre.to_string());
+        rest = &stripped[end + 1..];
+    }
+
+    let rest = rest.trim();
+    if !rest.is_empty() && !genres.iter().any(|genre| genre == rest) {
+        genres.push(rest.to_string());
+    }
+
+    genres
+}
+
+/// Splits one genre tag value on `separators` and appends the genres to
+/// `genres`, skipping ones already listed.
+fn split_genres(value: &str, separators: &[String], genres: &mut Vec<String>) {
+    let mut parts = vec![value.to_string()];
+
+    for separator in separators.iter().filter(|separator| !separator.is_empty()) {
+        parts = parts
+            .iter()
+            .flat_map(|part| part.split(separator.as_str()))
+            .map(|part| part.to_string())
+            .collect();
+    }
+
+    for genre in parts.iter().flat_map(|part| expand_id3v1_genres(part)) {
+        if !genres.contains(&genre) {
+            genres.push(genre);
+        }
+    }
+}
+
+/// Keys the MusicBrainz release type is stored under in Vorbis comments,
+/// ID3v2 `TXXX` frames and MP4 freeform atoms.
+const RELEASE_TYPE_KEYS: &[&str] = &[
+    "RELEASETYPE",
+    "MUSICBRAINZ_ALBUMTYPE",
+    "MusicBrainz Album Type",
+    "----:com.apple.iTunes:MusicBrainz Album Type",
+    "----:com.apple.iTunes:RELEASETYPE",
+];
+
+/// Secondary release types that describe an album better than its primary
+/// type, as MusicBrainz itself groups them.
+const SECONDARY_RELEASE_TYPES: &[&str] = &["compilation", "live", "soundtrack", "remix"];
+
+/// Reduces a release type tag such as `Album; Compilation` to one lowercase
+/// type, preferring a telling secondary type over the primary one.
+fn release_type_of(value: &str) -> Option<String> {
+    let types: Vec<String> = value
+        .split([';', '/', ','])
+        .map(|release_type| release_type.trim().to_lowercase())
+        .filter(|release_type| !release_type.is_empty())
+        .collect();
+
+    types
+        .iter()
+        .skip(1)
+        .find(|release_type| SECONDARY_RELEASE_TYPES.contains(&release_type.as_str()))
+        .or(types.first())
+        .cloned()
+}
+
+/// Romaji and hiragana readings of `text`. They are stored next to titles and
+/// names so Japanese text can be searched by how it is read, and sorted in
+/// kana order rather than by code point.
+pub(crate) fn readings_of(text: &str) -> (String, String) {
+    let result = kakasi::convert(text);
+    (result.romaji, result.hiragana)
+}
+
+/// Reads the year from a date tag such as `2003`, `2003-07` or `2003-07-21`.
+fn year_of_date(date: &str) -> Option<i32> {
+    date.trim().get(..4)?.parse().ok()
+}
+
+/// Every value of `key` in `tag`, joined with `"; "`, or `None` if it has none.
+fn joined_strings(tag: &Tag, key: &ItemKey) -> Option<String> {
+    let values: Vec<&str> = tag.get_strings(key).collect();
+
+    if values.is_empty() {
+        None
+    } else {
+        Some(values.join("; "))
+    }
+}
+
+pub(crate) fn parse_music_file(
+    path: &Path,
+    mount_point: &Path,
+    stamp: FileStamp,
+    config: &Config,
+) -> lofty::Result<ParsedTrack> {
+    let probe = Probe::open(path)?.guess_file_type()?;
+    let tagged_file = probe.read()?;
+
+    let properties = tagged_file.properties();
+
+    let location = path.to_string_lossy().to_string();
+    let duration_ms = properties.duration().as_millis() as i32;
+    let bit_depth = properties.bit_depth().map(|n| n as i32);
+    let (codec, lossless) = codec_of(tagged_file.file_type(), bit_depth);
+
+    let mut parsed_track = ParsedTrack {
+        picture_id: None,
+        title: None,
+        number: None,
+        disc: None,
+        artists: Vec::new(),
+        album_artist: None,
+        album: None,
+        musicbrainz_release_id: None,
+        release_type: None,
+        location,
+        duration_ms,
+        mount_point: mount_point.to_string_lossy().to_string(),
+        file_size: stamp.size,
+        file_modified_at: stamp.modified_at,
+        year: None,
+        date: None,
+        original_date: None,
+        genre: None,
+        genres: Vec::new(),
+        composer: None,
+        comment: None,
+        track_total: None,
+        disc_total: None,
+        bpm: None,
+        isrc: None,
+        label: None,
+        codec,
+        bitrate: properties.audio_bitrate().or(properties.overall_bitrate()).map(|n| n as i32),
+        sample_rate: properties.sample_rate().map(|n| n as i32),
+        bit_depth,
+        channels: properties.channels().map(|n| n as i32),
+        lossless,
+        disc_subtitle: None,
+        title_romaji: None,
+        title_hiragana: None,
+    };
+
+    let tag = match tagged_file.primary_tag() {
+        Some(primary_tag) => primary_tag,
+        None => match tagged_file.first_tag() {
+            Some(first_tag) => first_tag,
+            None => return Ok(parsed_track),
+        },
+    };
+
+    parsed_track.title = tag.title().map(|s| s.to_string());
+    if let Some(title) = &parsed_track.title {
+        let (romaji, hiragana) = readings_of(title);
+        parsed_track.title_romaji = Some(romaji);
+        parsed_track.title_hiragana = Some(hiragana);
+    }
+    for (key, role) in [
+        (ItemKey::TrackArtist, ArtistRole::Main),
+        (ItemKey::Remixer, ArtistRole::Remixer),
+        (ItemKey::Composer, ArtistRole::Composer),
+    ] {
+        for value in tag.get_strings(&key) {
+            split_artists(value, &config.artist_separators, role, &mut parsed_track.artists);
+        }
+    }
+    parsed_track.album_artist = tag.get_string(&ItemKey::AlbumArtist).map(|s| s.to_string());
+    parsed_track.album = tag.album().map(|s| s.to_string());
+    parsed_track.release_type = RELEASE_TYPE_KEYS
+        .iter()
+        .find_map(|key| tag.get_string(&ItemKey::Unknown(key.to_string())))
+        .and_then(release_type_of);
+    parsed_track.musicbrainz_release_id = tag.get_string(&ItemKey::MusicBrainzReleaseId).map(|s| s.to_string());
+    parsed_track.number = tag.track().map(|n| n as i32);
+    parsed_track.disc = tag.disk().map(|n| n as i32);
+    parsed_track.track_total = tag.track_total().map(|n| n as i32);
+    parsed_track.disc_total = tag.disk_total().map(|n| n as i32);
+    parsed_track.disc_subtitle = tag.get_string(&ItemKey::SetSubtitle).map(|s| s.to_string());
+
+    parsed_track.date = tag.get_string(&ItemKey::RecordingDate).map(|s| s.to_string());
+    parsed_track.original_date = tag.get_string(&ItemKey::OriginalReleaseDate).map(|s| s.to_string());
+    parsed_track.year = tag
+        .year()
+        .map(|year| year as i32)
+        .or_else(|| parsed_track.date.as_deref().and_then(year_of_date));
+
+    for value in tag.get_strings(&ItemKey::Genre) {
+        split_genres(value, &config.genre_separators, &mut parsed_track.genres);
+    }
+    if !parsed_track.genres.is_empty() {
+        parsed_track.genre = Some(parsed_track.genres.join("; "));
+    }
+    parsed_track.composer = joined_strings(tag, &ItemKey::Composer);
+    parsed_track.comment = tag.comment().map(|s| s.to_string());
+    parsed_track.bpm = tag
+        .get_string(&ItemKey::Bpm)
+        .or_else(|| tag.get_string(&ItemKey::IntegerBpm))
+        .and_then(|bpm| bpm.trim().parse().ok());
+    parsed_track.isrc = tag.get_string(&ItemKey::Isrc).map(|s| s.to_string());
+    parsed_track.label = tag.get_string(&ItemKey::Label).map(|s| s.to_string());
+
+    if let Some(picture) = tag.pictures().first() {
+        let picture_id_digest = md5::compute(picture.data());
+        let picture_id = format!("{:x}", picture_id_digest);
+        let mut picture_path = PathBuf::from(get_cache_path()).join(&picture_id);
+
+        picture_path.set_extension("jpg");
+        fs::write(&picture_path, picture.data()).ok();
+
+        if picture_path.exists() {
+            parsed_track.picture_id = Some(picture_id);
+        }
+    }
+
+    Ok(parsed_track)
+}
+
+/// Counts reported once a scan has finished.
+pub struct SyncSummary {
+    /// Files that were not in the library before.
+    pub added: u32,
+    /// Known files whose size or modification time changed and were re-parsed.
+    pub updated: u32,
+    /// Known files that were skipped because they did not change.
+    pub unchanged: u32,
+    /// Files that could not be parsed or written to the database.
+    pub failed: u32,
+    /// Tracks whose files were no longer found under the mount point.
+    pub removed: u32,
+    /// Whether the scan was stopped through its [`ScanCancelToken`] before
+    /// every file was written.
+    pub cancelled: bool,
+}
+
+/// Running counters of a scan in progress.
+pub struct ScanProgress {
+    /// Music files found so far by the directory walk.
+    pub discovered: u32,
+    /// Files that have been checked against the library and parsed if needed.
+    pub parsed: u32,
+    /// Parsed files that have been written to the database.
+    pub written: u32,
+    /// The file handled most recently.
+    pub current_path: String,
+}
+
+/// Event sent to Dart while [`scan_directory`] runs.
+pub enum ScanEvent {
+    Progress(ScanProgress),
+    /// A file could not be imported. `kind` is one of the values stored in
+    /// [`ScanError::kind`].
+    Error { path: String, kind: String, message: String },
+    Finished(SyncSummary),
+}
+
+/// Why a file could not be imported.
+pub(crate) struct ScanFailure {
+    kind: &'static str,
+    message: String,
+}
+
+impl From<std::io::Error> for ScanFailure {
+    fn from(e: std::io::Error) -> Self {
+        ScanFailure {
+            kind: "io",
+            message: e.to_string(),
+        }
+    }
+}
+
+impl From<LoftyError> for ScanFailure {
+    fn from(e: LoftyError) -> Self {
+        let kind = match e.kind() {
+            ErrorKind::Io(_) => "io",
+            ErrorKind::UnknownFormat => "unsupported_format",
+            _ => "decode",
+        };
+
+        ScanFailure {
+            kind,
+            message: e.to_string(),
+        }
+    }
+}
+
+impl From<diesel::result::Error> for ScanFailure {
+    fn from(e: diesel::result::Error) -> Self {
+        ScanFailure {
+            kind: "database",
+            message: e.to_string(),
+        }
+    }
+}
+
+impl From<notify_debouncer_full::notify::Error> for ScanFailure {
+    fn from(e: notify_debouncer_full::notify::Error) -> Self {
+        ScanFailure {
+            kind: "io",
+            message: e.to_string(),
+        }
+    }
+}
+
+impl ScanFailure {
+    fn to_event(&self, location: &str) -> ScanEvent {
+        ScanEvent::Error {
+            path: location.to_string(),
+            kind: self.kind.to_string(),
+            message: self.message.clone(),
+        }
+    }
+}
+
+/// Records that the file at `location` failed to import, replacing any
+/// earlier error for it.
+pub(crate) fn record_scan_error(
+    conn: &mut SqliteConnection,
+    location: &str,
+    mount_point: &str,
+    failure: &ScanFailure,
+) -> QueryResult<()> {
+    use crate::schema::scan_error::dsl as scan_error_dsl;
+
+    let new_scan_error = NewScanError {
+        location: location.to_string(),
+        mount_point: mount_point.to_string(),
+        kind: failure.kind.to_string(),
+        message: failure.message.clone(),
+        occurred_at: Utc::now().naive_utc(),
+    };
+
+    diesel::insert_into(scan_error_dsl::scan_error)
+        .values(&new_scan_error)
+        .on_conflict(scan_error_dsl::location)
+        .do_update()
+        .set(&new_scan_error)
+        .execute(conn)?;
+
+    Ok(())
+}
+
+/// Forgets the recorded error of the file at `location`, if any.
+pub(crate) fn clear_scan_error(conn: &mut SqliteConnection, location: &str) -> QueryResult<()> {
+    use crate::schema::scan_error::dsl as scan_error_dsl;
+
+    diesel::delete(scan_error_dsl::scan_error.filter(scan_error_dsl::location.eq(location))).execute(conn)?;
+
+    Ok(())
+}
+
+/// Files that failed to import, most recent first.
+pub fn get_scan_errors() -> Vec<ScanError> {
+    use crate::schema::scan_error::dsl as scan_error_dsl;
+
+    let conn = &mut establish_connection().unwrap();
+
+    scan_error_dsl::scan_error
+        .order_by((scan_error_dsl::occurred_at.desc(), scan_error_dsl::location))
+        .load(conn)
+        .unwrap()
+}
+
+pub fn clear_scan_errors() {
+    use crate::schema::scan_error::dsl as scan_error_dsl;
+
+    let conn = &mut establish_connection().unwrap();
+
+    diesel::delete(scan_error_dsl::scan_error).execute(conn).unwrap();
+}
+
+/// Handle that lets Dart stop a running scan.
+///
+/// Cancelling stops the walk, skips files that have not been parsed yet and
+/// ends the write phase after the current track, committing what was written
+/// so far, so every track already written stays consistent. Missing tracks
+/// are not removed from a cancelled scan.
+#[derive(Clone, Default)]
+pub struct ScanCancelToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl ScanCancelToken {
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn new() -> ScanCancelToken {
+        ScanCancelToken::default()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+/// Number of files handled between two [`ScanEvent::Progress`] events.
+const PROGRESS_INTERVAL: u32 = 50;
+
+/// Scans `mount_point` like [`super::simple::sync_directory`], reporting
+/// progress, per-file errors and the final summary through `sink`.
+pub fn scan_directory(
+    mount_point: String,
+    cancel_token: &ScanCancelToken,
+    sink: StreamSink<ScanEvent>,
+) -> Result<(), String> {
+    let summary = scan_mount_point(&mount_point, cancel_token, &|event| {
+        let _ = sink.add(event);
+    })?;
+
+    let _ = sink.add(ScanEvent::Finished(summary));
+    Ok(())
+}
+
+enum ScannedFile {
+    Unchanged(String),
+    Parsed(Box<ParsedTrack>),
+    Failed(String, ScanFailure),
+}
+
+/// Walks `mount_point` and imports its music files into the library,
+/// registering it as a library root if needed. Fails without scanning if
+/// `mount_point` is not a root yet but is inside one or contains one.
+///
+/// Files whose size and modification time match what was recorded on the
+/// previous scan are skipped without being opened. Tracks under
+/// `mount_point` whose files were not found are removed, along with any
+/// albums and artists left without tracks. Nothing is removed if the mount
+/// point is offline or could not be walked completely, so an unplugged drive
+/// does not empty the library.
+pub(crate) fn scan_mount_point(
+    mount_point: &str,
+    cancel_token: &ScanCancelToken,
+    on_event: &(dyn Fn(ScanEvent) + Sync),
+) -> Result<SyncSummary, String> {
+    use crate::schema::scan_error::dsl as scan_error_dsl;
+    use crate::schema::track::dsl as track_dsl;
+
+    let mount_point = normalize_root_path(mount_point);
+    let conn = &mut establish_connection().unwrap();
+
+    register_library_root(conn, &mount_point)?;
+
+    let known_errors: HashSet<String> = scan_error_dsl::scan_error
+        .filter(scan_error_dsl::mount_point.eq(&mount_point))
+        .select(scan_error_dsl::location)
+        .load::<String>(conn)
+        .unwrap()
+        .into_iter()
+        .collect();
+
+    let known_stamps: HashMap<String, FileStamp> = track_dsl::track
+        .filter(track_dsl::mount_point.eq(&mount_point))
+        .select((track_dsl::location, track_dsl::file_size, track_dsl::file_modified_at))
+        .load::<(String, i64, Option<NaiveDateTime>)>(conn)
+        .unwrap()
+        .into_iter()
+        .map(|(location, size, modified_at)| (location, FileStamp { size, modified_at }))
+        .collect();
+
+    let config = get_config();
+    let root_path = mount_point;
+    let mount_point = PathBuf::from(&root_path);
+    let walk_failed = AtomicBool::new(!is_root_available(&root_path, !known_stamps.is_empty()));
+    let discovered = AtomicU32::new(0);
+    let parsed = AtomicU32::new(0);
+
+    let walk_cancel_token = cancel_token.clone();
+    let scanned_files: Vec<ScannedFile> = WalkDir::new(&mount_point)
+        .process_read_dir(move |_, _, _, children| {
+            if walk_cancel_token.is_cancelled() {
+                children.clear();
+            }
+        })
+        .into_iter()
+        .par_bridge()
+        .filter_map(|e| match e {
+            Ok(e) => Some(e),
+            Err(_) => {
+                walk_failed.store(true, Ordering::Relaxed);
+                None
+            }
+        })
+        .filter(|_| !cancel_token.is_cancelled())
+        .filter_map(|e| match_music_file(e.path(), &config).map(|music_file_match| (e, music_file_match)))
+        .filter_map(|(e, music_file_match)| {
+            let path = e.path();
+            let location = path.to_string_lossy().to_string();
+
+            let stamp = FileStamp::read(&path);
+            let unchanged = matches!(&stamp, Ok(stamp) if known_stamps.get(&location) == Some(stamp));
+
+            // Unchanged files are not sniffed again: they were audio last time.
+            if !unchanged && music_file_match == MusicFileMatch::Content && !sniffs_as_music(&path) {
+                return None;
+            }
+
+            discovered.fetch_add(1, Ordering::Relaxed);
+
+            let scanned_file = match stamp {
+                Ok(_) if unchanged => ScannedFile::Unchanged(location.clone()),
+                Ok(stamp) => match parse_music_file(&path, &mount_point, stamp, &config) {
+                    Ok(parsed_track) => ScannedFile::Parsed(Box::new(parsed_track)),
+                    Err(e) => ScannedFile::Failed(location.clone(), e.into()),
+                },
+                Err(e) => ScannedFile::Failed(location.clone(), e.into()),
+            };
+
+            if let ScannedFile::Failed(location, failure) = &scanned_file {
+                on_event(failure.to_event(location));
+            }
+
+            let parsed = parsed.fetch_add(1, Ordering::Relaxed) + 1;
+            if parsed.is_multiple_of(PROGRESS_INTERVAL) {
+                on_event(ScanEvent::Progress(ScanProgress {
+                    discovered: discovered.load(Ordering::Relaxed),
+                    parsed,
+                    written: 0,
+                    current_path: location,
+                }));
+            }
+
+            Some(scanned_file)
+        })
+        .collect();
+
+    let mut summary = SyncSummary {
+        added: 0,
+        updated: 0,
+        unchanged: 0,
+        failed: 0,
+        removed: 0,
+        cancelled: false,
+    };
+
+    let discovered = discovered.into_inner();
+    let parsed = parsed.into_inner();
+    let mut written = 0;
+    let mut seen_locations: HashSet<String> = HashSet::with_capacity(scanned_files.len());
+
+    let mut writer = TrackWriter::default();
+    let mut scanned_files = scanned_files.into_iter().peekable();
+
+    while scanned_files.peek().is_some() && !cancel_token.is_cancelled() {
+        conn.transaction(|conn| {
+            for scanned_file in scanned_files.by_ref().take(WRITE_CHUNK_SIZE) {
+                let parsed_track = match scanned_file {
+                    ScannedFile::Unchanged(location) => {
+                        seen_locations.insert(location);
+                        summary.unchanged += 1;
+                        continue;
+                    }
+                    ScannedFile::Failed(location, failure) => {
+                        record_scan_error(conn, &location, &root_path, &failure)?;
+                        seen_locations.insert(location);
+                        summary.failed += 1;
+                        continue;
+                    }
+                    ScannedFile::Parsed(parsed_track) => *parsed_track,
+                };
+
+                let location = parsed_track.location.clone();
+                let is_known = known_stamps.contains_key(&location);
+
+                // Each track gets a savepoint of its own, so a failed write
+                // leaves nothing behind when the rest of the chunk commits.
+                match conn.transaction(|conn| writer.write(conn, parsed_track)) {
+                    Ok(()) => {
+                        if known_errors.contains(&location) {
+                            clear_scan_error(conn, &location)?;
+                        }
+
+                        if is_known {
+                            summary.updated += 1;
+                        } else {
+                            summary.added += 1;
+                        }
+                    }
+                    Err(e) => {
+                        // The rolled back write may have created some of the
+                        // cached artists, albums and genres.
+                        writer.forget_cached_ids();
+
+                        let failure = ScanFailure::from(e);
+                        on_event(failure.to_event(&location));
+                        record_scan_error(conn, &location, &root_path, &failure)?;
+                        summary.failed += 1;
+                    }
+                }
+
+                written += 1;
+                if written % PROGRESS_INTERVAL == 0 {
+                    on_event(ScanEvent::Progress(ScanProgress {
+                        discovered,
+                        parsed,
+                        written,
+                        current_path: location.clone(),
+                    }));
+                }
+
+                seen_locations.insert(location);
+
+                if cancel_token.is_cancelled() {
+                    break;
+                }
+            }
+
+            writer.finish(conn)
+        })
+        .unwrap();
+    }
+
+    summary.cancelled = cancel_token.is_cancelled();
+
+    if !summary.cancelled && !walk_failed.load(Ordering::Relaxed) {
+        let missing_locations: Vec<&String> = known_stamps
+            .keys()
+            .filter(|location| !seen_locations.contains(*location))
+            .collect();
+
+        let stale_errors: Vec<&String> = known_errors
+            .iter()
+            .filter(|location| !seen_locations.contains(*location))
+            .collect();
+
+        conn.transaction(|conn| {
+            for locations in missing_locations.chunks(DELETE_CHUNK_SIZE) {
+                summary.removed += diesel::delete(track_dsl::track.filter(track_dsl::location.eq_any(locations)))
+                    .execute(conn)? as u32;
+            }
+
+            if summary.removed > 0 || summary.updated > 0 {
+                delete_orphaned_metadata(conn)?;
+            }
+
+            for locations in stale_errors.chunks(DELETE_CHUNK_SIZE) {
+                diesel::delete(scan_error_dsl::scan_error.filter(scan_error_dsl::location.eq_any(locations)))
+                    .execute(conn)?;
+            }
+
+            QueryResult::Ok(())
+        })
+        .unwrap();
+    }
+
+    refresh_library_root(conn, &root_path, !summary.cancelled).unwrap();
+
+    Ok(summary)
+}
+
+/// Maximum number of bound parameters used in a single `IN (...)` delete.
+const DELETE_CHUNK_SIZE: usize = 500;
+
+/// Number of scanned files written to the database per transaction.
+const WRITE_CHUNK_SIZE: usize = 500;
+
+/// Removes albums that no track belongs to, then artists that neither a
+/// track, a track credit nor an album refers to, and genres no track has.
+pub(crate) fn delete_orphaned_metadata(conn: &mut SqliteConnection) -> QueryResult<()> {
+    use crate::schema::album::dsl as album_dsl;
+    use crate::schema::artist::dsl as artist_dsl;
+    use crate::schema::genre::dsl as genre_dsl;
+    use crate::schema::track::dsl as track_dsl;
+    use crate::schema::track_artist::dsl as track_artist_dsl;
+    use crate::schema::track_genre::dsl as track_genre_dsl;
+
+    diesel::delete(
+        album_dsl::album.filter(
+            album_dsl::id
+                .nullable()
+                .ne_all(track_dsl::track.select(track_dsl::album_id).filter(track_dsl::album_id.is_not_null())),
+        ),
+    )
+    .execute(conn)?;
+
+    diesel::delete(
+        artist_dsl::artist
+            .filter(
+                artist_dsl::id
+                    .nullable()
+                    .ne_all(track_dsl::track.select(track_dsl::artist_id).filter(track_dsl::artist_id.is_not_null())),
+            )
+            .filter(
+                artist_dsl::id
+                    .nullable()
+                    .ne_all(album_dsl::album.select(album_dsl::artist_id).filter(album_dsl::artist_id.is_not_null())),
+            )
+            .filter(artist_dsl::id.ne_all(track_artist_dsl::track_artist.select(track_artist_dsl::artist_id))),
+    )
+    .execute(conn)?;
+
+    diesel::delete(genre_dsl::genre.filter(genre_dsl::id.ne_all(track_genre_dsl::track_genre.select(track_genre_dsl::genre_id))))
+        .execute(conn)?;
+
+    Ok(())
+}
+
+/// Writes parsed tracks to the database, remembering the ids of the artists,
+/// albums and genres it has already looked up or created.
+///
+/// Callers should write many tracks inside one transaction; the cache lives
+/// as long as the writer, so a writer should not outlive the transaction it
+/// was used in if that transaction may be rolled back. Written tracks reach
+/// the search index in one go when [`TrackWriter::finish`] is called, which
+/// callers must do before committing.
+#[derive(Default)]
+pub(crate) struct TrackWriter {
+    artist_ids: HashMap<String, i32>,
+    album_ids: HashMap<AlbumKey, i32>,
+    album_release_types: HashMap<i32, String>,
+    genre_ids: HashMap<String, i32>,
+    unindexed_track_ids: Vec<i32>,
+}
+
+/// What identifies an album in [`TrackWriter`]'s cache.
+#[derive(PartialEq, Eq, Hash)]
+enum AlbumKey {
+    MusicBrainz(String),
+    NameArtist(String, Option<i32>),
+}
+
+impl TrackWriter {
+    fn artist_id(&mut self, conn: &mut SqliteConnection, name: String) -> QueryResult<i32> {
+        use crate::schema::artist::dsl as artist_dsl;
+
+        if let Some(id) = self.artist_ids.get(&name) {
+            return Ok(*id);
+        }
+
+        let (romaji, hiragana) = readings_of(&name);
+
+        diesel::insert_or_ignore_into(artist_dsl::artist)
+            .values(NewArtist {
+                name: name.clone(),
+                name_romaji: Some(romaji.clone()),
+                name_hiragana: Some(hiragana.clone()),
+            })
+            .execute(conn)?;
+
+        let id = artist_dsl::artist
+            .filter(artist_dsl::name.eq(&name))
+            .select(artist_dsl::id)
+            .first(conn)?;
+
+        // Artists created before readings were stored get theirs here.
+        diesel::update(artist_dsl::artist.find(id).filter(artist_dsl::name_romaji.is_null()))
+            .set((artist_dsl::name_romaji.eq(romaji), artist_dsl::name_hiragana.eq(hiragana)))
+            .execute(conn)?;
+
+        self.artist_ids.insert(name, id);
+        Ok(id)
+    }
+
+    fn genre_id(&mut self, conn: &mut SqliteConnection, name: String) -> QueryResult<i32> {
+        use crate::schema::genre::dsl as genre_dsl;
+
+        if let Some(id) = self.genre_ids.get(&name) {
+            return Ok(*id);
+        }
+
+        diesel::insert_or_ignore_into(genre_dsl::genre)
+            .values(NewGenre { name: name.clone() })
+            .execute(conn)?;
+
+        let id = genre_dsl::genre
+            .filter(genre_dsl::name.eq(&name))
+            .select(genre_dsl::id)
+            .first(conn)?;
+
+        self.genre_ids.insert(name, id);
+        Ok(id)
+    }
+
+    /// Finds or creates the album named `name` by `artist_id`. When the track
+    /// carries a MusicBrainz release id, that id alone identifies the album, so
+    /// different releases of the same title stay apart. A tagged release type
+    /// replaces the album's stored one.
+    fn album_id(
+        &mut self,
+        conn: &mut SqliteConnection,
+        name: String,
+        artist_id: Option<i32>,
+        musicbrainz_release_id: Option<String>,
+        release_type: Option<String>,
+    ) -> QueryResult<i32> {
+        use crate::schema::album::dsl as album_dsl;
+
+        let key = match &musicbrainz_release_id {
+            Some(release_id) => AlbumKey::MusicBrainz(release_id.clone()),
+            None => AlbumKey::NameArtist(name.clone(), artist_id),
+        };
+
+        if let Some(id) = self.album_ids.get(&key).copied() {
+            self.set_release_type(conn, id, release_type)?;
+            return Ok(id);
+        }
+
+        let (romaji, hiragana) = readings_of(&name);
+
+        let id = match musicbrainz_release_id {
+            Some(release_id) => {
+                let existing_id = album_dsl::album
+                    .filter(album_dsl::musicbrainz_release_id.eq(&release_id))
+                    .select(album_dsl::id)
+                    .first(conn)
+                    .optional()?;
+
+                // An album created from tracks without a release id is claimed
+                // by the first track that has one.
+                let untagged_id = match existing_id {
+                    Some(_) => None,
+                    None => album_dsl::album
+                        .filter(album_dsl::name.eq(&name))
+                        .filter(album_dsl::artist_id.is(artist_id))
+                        .filter(album_dsl::musicbrainz_release_id.is_null())
+                        .select(album_dsl::id)
+                        .first(conn)
+                        .optional()?,
+                };
+
+                match (existing_id, untagged_id) {
+                    (Some(id), _) => id,
+                    (None, Some(id)) => {
+                        diesel::update(album_dsl::album.find(id))
+                            .set(album_dsl::musicbrainz_release_id.eq(&release_id))
+                            .execute(conn)?;
+                        id
+                    }
+                    (None, None) => {
+                        diesel::insert_into(album_dsl::album)
+                            .values(NewAlbum {
+                                name,
+                                artist_id,
+                                musicbrainz_release_id: Some(release_id.clone()),
+                                name_romaji: Some(romaji.clone()),
+                                name_hiragana: Some(hiragana.clone()),
+                            })
+                            .execute(conn)?;
+
+                        album_dsl::album
+                            .filter(album_dsl::musicbrainz_release_id.eq(&release_id))
+                            .select(album_dsl::id)
+                            .first(conn)?
+                    }
+                }
+            }
+            None => {
+                let existing_id = album_dsl::album
+                    .filter(album_dsl::name.eq(&name))
+                    .filter(album_dsl::artist_id.is(artist_id))
+                    .order_by(album_dsl::musicbrainz_release_id.is_not_null())
+                    .select(album_dsl::id)
+                    .first(conn)
+                    .optional()?;
+
+                match existing_id {
+                    Some(id) => id,
+                    None => {
+                        diesel::insert_into(album_dsl::album)
+                            .values(NewAlbum {
+                                name: name.clone(),
+                                artist_id,
+                                musicbrainz_release_id: None,
+                                name_romaji: Some(romaji.clone()),
+                                name_hiragana: Some(hiragana.clone()),
+                            })
+                            .execute(conn)?;
+
+                        album_dsl::album
+                            .filter(album_dsl::name.eq(&name))
+                            .filter(album_dsl::artist_id.is(artist_id))
+                            .filter(album_dsl::musicbrainz_release_id.is_null())
+                            .select(album_dsl::id)
+                            .first(conn)?
+                    }
+                }
+            }
+        };
+
+        // Albums created before readings were stored get theirs here.
+        diesel::update(album_dsl::album.find(id).filter(album_dsl::name_romaji.is_null()))
+            .set((album_dsl::name_romaji.eq(romaji), album_dsl::name_hiragana.eq(hiragana)))
+            .execute(conn)?;
+
+        self.set_release_type(conn, id, release_type)?;
+        self.album_ids.insert(key, id);
+        Ok(id)
+    }
+
+    /// Stores a tagged release type on album `id` unless this writer already
+    /// stored the same one.
+    fn set_release_type(&mut self, conn: &mut SqliteConnection, id: i32, release_type: Option<String>) -> QueryResult<()> {
+        use crate::schema::album::dsl as album_dsl;
+
+        let Some(release_type) = release_type else {
+            return Ok(());
+        };
+
+        if self.album_release_types.get(&id) == Some(&release_type) {
+            return Ok(());
+        }
+
+        diesel::update(album_dsl::album.find(id))
+            .set(album_dsl::release_type.eq(&release_type))
+            .execute(conn)?;
+
+        self.album_release_types.insert(id, release_type);
+        Ok(())
+    }
+
+    /// Updates the search index entries of the tracks written since the last
+    /// call.
+    pub(crate) fn finish(&mut self, conn: &mut SqliteConnection) -> QueryResult<()> {
+        index_tracks(conn, &self.unindexed_track_ids)?;
+        self.unindexed_track_ids.clear();
+        Ok(())
+    }
+
+    /// Forgets the artists, albums and genres looked up so far, for when the
+    /// writes that may have created them were rolled back.
+    pub(crate) fn forget_cached_ids(&mut self) {
+        self.artist_ids.clear();
+        self.album_ids.clear();
+        self.album_release_types.clear();
+        self.genre_ids.clear();
+    }
+
+    /// Inserts `parsed_track`, or updates the track already stored at its
+    /// location, creating its artists, album and genres if needed and
+    /// replacing its artist credits and genres.
+    pub(crate) fn write(&mut self, conn: &mut SqliteConnection, parsed_track: ParsedTrack) -> QueryResult<()> {
+        use crate::schema::track::dsl as track_dsl;
+        use crate::schema::track_artist::dsl as track_artist_dsl;
+        use crate::schema::track_genre::dsl as track_genre_dsl;
+
+        let mut credits = Vec::with_capacity(parsed_track.artists.len());
+        for credit in parsed_track.artists {
+            credits.push((self.artist_id(conn, credit.name)?, credit.role));
+        }
+
+        let artist_id = credits
+            .iter()
+            .find(|(_, role)| *role == ArtistRole::Main)
+            .map(|(artist_id, _)| *artist_id);
+
+        // Albums belong to their album artist, so a compilation stays one
+        // album however many track artists it has.
+        let album_artist_id = match parsed_track.album_artist {
+            Some(album_artist) => Some(self.artist_id(conn, album_artist)?),
+            None => artist_id,
+        };
+
+        let album_id = match parsed_track.album {
+            Some(album) => Some(self.album_id(
+                conn,
+                album,
+                album_artist_id,
+                parsed_track.musicbrainz_release_id,
+                parsed_track.release_type,
+            )?),
+            None => None,
+        };
+
+        let mut genre_ids = Vec::with_capacity(parsed_track.genres.len());
+        for genre in parsed_track.genres {
+            genre_ids.push(self.genre_id(conn, genre)?);
+        }
+
+        let new_track = NewTrack {
+            picture_id: parsed_track.picture_id,
+            album_id,
+            artist_id,
+            number: parsed_track.number,
+            disc: parsed_track.disc,
+            title: parsed_track.title,
+            duration_ms: parsed_track.duration_ms,
+            location: parsed_track.location,
+            mount_point: parsed_track.mount_point,
+            file_size: parsed_track.file_size,
+            file_modified_at: parsed_track.file_modified_at,
+            year: parsed_track.year,
+            date: parsed_track.date,
+            original_date: parsed_track.original_date,
+            genre: parsed_track.genre,
+            composer: parsed_track.composer,
+            comment: parsed_track.comment,
+            track_total: parsed_track.track_total,
+            disc_total: parsed_track.disc_total,
+            bpm: parsed_track.bpm,
+            isrc: parsed_track.isrc,
+            label: parsed_track.label,
+            codec: parsed_track.codec,
+            bitrate: parsed_track.bitrate,
+            sample_rate: parsed_track.sample_rate,
+            bit_depth: parsed_track.bit_depth,
+            channels: parsed_track.channels,
+            lossless: parsed_track.lossless,
+            disc_subtitle: parsed_track.disc_subtitle,
+            title_romaji: parsed_track.title_romaji,
+            title_hiragana: parsed_track.title_hiragana,
+        };
+
+        diesel::insert_into(track_dsl::track)
+            .values(&new_track)
+            .on_conflict(track_dsl::location)
+            .do_update()
+            .set(&new_track)
+            .execute(conn)?;
+
+        let track_id: i32 = track_dsl::track
+            .filter(track_dsl::location.eq(&new_track.location))
+            .select(track_dsl::id)
+            .first(conn)?;
+
+        diesel::delete(track_artist_dsl::track_artist.filter(track_artist_dsl::track_id.eq(track_id))).execute(conn)?;
+
+        let new_track_artists: Vec<NewTrackArtist> = credits
+            .into_iter()
+            .enumerate()
+            .map(|(position, (artist_id, role))| NewTrackArtist {
+                track_id,
+                artist_id,
+                role: role.as_str().to_string(),
+                position: position as i32,
+            })
+            .collect();
+
+        diesel::insert_into(track_artist_dsl::track_artist)
+            .values(&new_track_artists)
+            .execute(conn)?;
+
+        diesel::delete(track_genre_dsl::track_genre.filter(track_genre_dsl::track_id.eq(track_id))).execute(conn)?;
+
+        let new_track_genres: Vec<NewTrackGenre> = genre_ids
+            .into_iter()
+            .enumerate()
+            .map(|(position, genre_id)| NewTrackGenre {
+                track_id,
+                genre_id,
+                position: position as i32,
+            })
+            .collect();
+
+        diesel::insert_into(track_genre_dsl::track_genre)
+            .values(&new_track_genres)
+            .execute(conn)?;
+
+        self.unindexed_track_ids.push(track_id);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use diesel::sql_query;
+
+    use super::*;
+    use crate::testing::{write_wav, TestLibrary};
+
+    #[test]
+    fn failed_track_writes_leave_nothing_behind() {
+        use crate::schema::album::dsl as album_dsl;
+        use crate::schema::artist::dsl as artist_dsl;
+        use crate::schema::track::dsl as track_dsl;
+
+        let library = TestLibrary::new();
+        write_wav(
+            &library.path("broken.wav"),
+            &[("INAM", "Broken"), ("IART", "Shared"), ("IPRD", "Ghost Album")],
+        );
+        write_wav(&library.path("kept.wav"), &[("INAM", "Kept"), ("IART", "Shared")]);
+
+        let conn = &mut establish_connection().unwrap();
+        sql_query(
+            "CREATE TRIGGER reject_broken BEFORE INSERT ON track WHEN NEW.title = 'Broken' \
+             BEGIN SELECT RAISE(ABORT, 'broken'); END",
+        )
+        .execute(conn)
+        .unwrap();
+
+        let summary = scan_mount_point(&library.mount_point(), &ScanCancelToken::new(), &|_| {}).unwrap();
+        assert_eq!((summary.added, summary.failed), (1, 1));
+
+        // Whichever of the two was written first, the kept track points at an
+        // artist that exists.
+        let tracks: Vec<(Option<String>, String)> = track_dsl::track
+            .inner_join(artist_dsl::artist)
+            .select((track_dsl::title, artist_dsl::name))
+            .load(conn)
+            .unwrap();
+        assert_eq!(tracks, [(Some("Kept".to_string()), "Shared".to_string())]);
+
+        let albums: Vec<String> = album_dsl::album.select(album_dsl::name).load(conn).unwrap();
+        assert!(albums.is_empty());
+
+        let scan_errors = get_scan_errors();
+        assert_eq!(scan_errors.len(), 1);
+        assert_eq!(scan_errors[0].location, library.path("broken.wav").to_string_lossy());
+        assert_eq!(scan_errors[0].kind, "database");
+    }
+}
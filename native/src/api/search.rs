@@ -0,0 +1,162 @@
+use diesel::sql_types::{BigInt, Bool, Integer, Text};
+use diesel::sqlite::Sqlite;
+use diesel::{QueryDsl, QueryResult, RunQueryDsl, SqliteConnection};
+
+use super::query::TextField;
+use super::scan::readings_of;
+use super::simple::establish_connection;
+
+diesel::table! {
+    /// The FTS5 index kept up to date by [`index_tracks`]. Only `rowid`, the
+    /// track id, is declared; matching goes through raw `MATCH` expressions.
+    track_fts (rowid) {
+        rowid -> Integer,
+    }
+}
+
+/// Weights of the `track_fts` columns in ranking: title, artist, album,
+/// genre, composer, then the readings of title, artist and album.
+const COLUMN_WEIGHTS: &str = "10.0, 5.0, 3.0, 1.0, 1.0, 8.0, 4.0, 2.0";
+
+#[derive(diesel::QueryableByName)]
+struct TrackId {
+    #[diesel(sql_type = Integer)]
+    id: i32,
+}
+
+/// Number of tracks indexed per statement.
+const INDEX_CHUNK_SIZE: usize = 500;
+
+/// Replaces the full-text index entries of `track_ids` with their current
+/// title, artists, album, genres and composers, and the romaji and hiragana
+/// readings stored for the title, artists and album.
+pub(crate) fn index_tracks(conn: &mut SqliteConnection, track_ids: &[i32]) -> QueryResult<()> {
+    for chunk in track_ids.chunks(INDEX_CHUNK_SIZE) {
+        // Ids are integers, so they can be written into the statement as is.
+        let ids = chunk.iter().map(|id| id.to_string()).collect::<Vec<String>>().join(", ");
+
+        diesel::sql_query(format!("DELETE FROM track_fts WHERE rowid IN ({})", ids)).execute(conn)?;
+
+        diesel::sql_query(format!(
+            "INSERT INTO track_fts (
+                rowid, title, artist, album, genre, composer, title_reading, artist_reading, album_reading
+            )
+            SELECT
+                track.id,
+                track.title,
+                (
+                    SELECT group_concat(artist.name, ' ') FROM artist
+                    WHERE artist.id IN (
+                        SELECT artist_id FROM track_artist WHERE track_artist.track_id = track.id
+                        UNION SELECT album.artist_id
+                    )
+                ),
+                album.name,
+                track.genre,
+                track.composer,
+                track.title_romaji || ' ' || track.title_hiragana,
+                (
+                    SELECT group_concat(artist.name_romaji || ' ' || artist.name_hiragana, ' ') FROM artist
+                    WHERE artist.id IN (
+                        SELECT artist_id FROM track_artist WHERE track_artist.track_id = track.id
+                        UNION SELECT album.artist_id
+                    )
+                ),
+                album.name_romaji || ' ' || album.name_hiragana
+            FROM track
+            LEFT JOIN album ON album.id = track.album_id
+            WHERE track.id IN ({})",
+            ids
+        ))
+        .execute(conn)?;
+    }
+
+    Ok(())
+}
+
+/// Turns what the user typed into an FTS5 query matching every word as a
+/// prefix, written as is or as its romaji or hiragana reading, with FTS5
+/// syntax in the input taken literally.
+fn match_expression(query: &str) -> Option<String> {
+    let terms: Vec<String> = query
+        .split_whitespace()
+        .map(|term| {
+            let (romaji, hiragana) = readings_of(term);
+
+            let mut spellings = vec![term.to_string()];
+            for reading in [romaji, hiragana] {
+                if !reading.is_empty() && !spellings.contains(&reading) {
+                    spellings.push(reading);
+                }
+            }
+
+            let prefixes: Vec<String> = spellings
+                .iter()
+                .map(|spelling| format!("\"{}\"*", spelling.replace('"', "\"\"")))
+                .collect();
+            format!("({})", prefixes.join(" OR "))
+        })
+        .collect();
+
+    if terms.is_empty() {
+        None
+    } else {
+        Some(terms.join(" AND "))
+    }
+}
+
+/// The `track_fts` columns a [`TextField`] is looked up in, or none to look
+/// in every column.
+fn columns_of(field: TextField) -> &'static [&'static str] {
+    match field {
+        TextField::Any => &[],
+        TextField::Title => &["title", "title_reading"],
+        TextField::Artist => &["artist", "artist_reading"],
+        TextField::Album => &["album", "album_reading"],
+        TextField::Genre => &["genre"],
+        TextField::Composer => &["composer"],
+    }
+}
+
+/// Ids of the tracks with words starting with every word of `text` in
+/// `field`, for use as a subquery, or `None` if `text` has no words.
+pub(crate) fn indexed_track_ids(
+    text: &str,
+    field: TextField,
+) -> Option<track_fts::BoxedQuery<'static, Sqlite, Integer>> {
+    let mut match_expression = match_expression(text)?;
+
+    let columns = columns_of(field);
+    if !columns.is_empty() {
+        match_expression = format!("{{{}}} : ({})", columns.join(" "), match_expression);
+    }
+
+    Some(
+        track_fts::table
+            .select(track_fts::rowid)
+            .filter(diesel::dsl::sql::<Bool>("track_fts MATCH ").bind::<Text, _>(match_expression))
+            .into_boxed(),
+    )
+}
+
+/// Ids of up to `limit` tracks whose title, artist, album, genre or composer
+/// contain words starting with every word of `query`, best matches first.
+/// Japanese titles and names also match by their romaji or hiragana reading.
+pub fn search_tracks(query: String, limit: u32) -> Vec<i32> {
+    let Some(match_expression) = match_expression(&query) else {
+        return Vec::new();
+    };
+
+    let conn = &mut establish_connection().unwrap();
+
+    let track_ids: Vec<TrackId> = diesel::sql_query(format!(
+        "SELECT rowid AS id FROM track_fts WHERE track_fts MATCH ? ORDER BY bm25(track_fts, {}) LIMIT ?",
+        COLUMN_WEIGHTS
+    ))
+    .bind::<Text, _>(match_expression)
+    .bind::<BigInt, _>(limit as i64)
+    .load(conn)
+    .unwrap();
+
+    track_ids.into_iter().map(|track_id| track_id.id).collect()
+}
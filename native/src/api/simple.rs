@@ -1,20 +1,22 @@
-use std::{collections::HashMap, fs, ops::Deref};
+use std::{collections::{HashMap, HashSet}, fs, ops::Deref};
 
+use chrono::{NaiveDateTime, Utc};
 use diesel::{
-    BoolExpressionMethods, Connection, ExpressionMethods, JoinOnDsl, NullableExpressionMethods, QueryDsl, RunQueryDsl, SqliteConnection
+    connection::SimpleConnection, BoolExpressionMethods, Connection, ExpressionMethods, NullableExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper, SqliteConnection
 };
+use diesel::dsl::IsNull;
 use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
 
 const MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations");
-type DB = diesel::sqlite::Sqlite;
 
-use std::path::PathBuf;
+type DB = diesel::sqlite::Sqlite;
 
-use jwalk::WalkDir;
-use lofty::{Accessor, AudioFile, Probe, TaggedFileExt};
-use rayon::iter::{ParallelBridge, ParallelIterator};
+use crate::model::{ifnull, Album, Artist, Genre, Track, TrackArtist};
+use crate::schema;
 
-use crate::model::{Album, Artist, NewTrack, Track};
+use super::library::offline_root_paths;
+use super::query::{query_track_ids, query_tracks, SortField, SortKey, TrackFilter, TrackQuery};
+use super::scan::{delete_orphaned_metadata, scan_mount_point, ScanCancelToken, SyncSummary};
 
 #[flutter_rust_bridge::frb(sync)]
 pub fn get_db_url() -> String {
@@ -36,9 +38,17 @@ pub fn get_data_path() -> String {
     dirs::data_dir().unwrap().join("ncudio").to_string_lossy().to_string()
 }
 
-fn establish_connection() -> Result<SqliteConnection, diesel::ConnectionError> {
+pub(crate) fn establish_connection() -> Result<SqliteConnection, diesel::ConnectionError> {
     fs::create_dir_all(get_config_path()).unwrap();
-    SqliteConnection::establish(&get_db_url())
+    let mut connection = SqliteConnection::establish(&get_db_url())?;
+
+    // WAL lets the watcher and a running scan write without blocking readers,
+    // and NORMAL sync only fsyncs at checkpoints instead of every commit.
+    connection
+        .batch_execute("PRAGMA journal_mode = WAL; PRAGMA synchronous = NORMAL; PRAGMA busy_timeout = 5000;")
+        .map_err(diesel::ConnectionError::CouldntSetupConfiguration)?;
+
+    Ok(connection)
 }
 
 fn run_migrations(connection: &mut impl MigrationHarness<DB>) -> Result<(), ()> {
@@ -75,351 +85,603 @@ pub fn initialize_db() {
     run_migrations(&mut connection).unwrap();
 }
 
-struct ParsedTrack {
-    title: Option<String>,
-    artist: Option<String>,
-    album: Option<String>,
-    number: Option<i32>,
-    disc: Option<i32>,
-    duration_ms: i32,
-    location: String,
-    mount_point: String,
-    picture_id: Option<String>,
+/// Walks `mount_point` and imports its music files into the library. Fails
+/// if `mount_point` is inside a library root or contains one.
+///
+/// See [`super::scan::scan_directory`] for a version that reports progress and can be
+/// cancelled.
+pub fn sync_directory(mount_point: String) -> Result<SyncSummary, String> {
+    scan_mount_point(&mount_point, &ScanCancelToken::new(), &|_| {})
 }
 
-fn parse_music_file<P: AsRef<std::path::Path>>(path: P, mount_point: &P) -> Option<ParsedTrack> {
-    let path = path.as_ref();
-    let mount_point = mount_point.as_ref();
-
-    if path.is_dir() {
-        return None;
-    }
-
-    let ext = path.extension()?;
+/// How an artist is credited on a track.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ArtistRole {
+    Main,
+    Featured,
+    Remixer,
+    Composer,
+}
 
-    if ext != "mp3" && ext != "flac" && ext != "wav" && ext != "ogg" {
-        return None;
+impl ArtistRole {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            ArtistRole::Main => "main",
+            ArtistRole::Featured => "featured",
+            ArtistRole::Remixer => "remixer",
+            ArtistRole::Composer => "composer",
+        }
     }
 
-    let probe = Probe::open(path).ok()?;
-    let tagged_file = probe.read().ok()?;
-
-    let properties = tagged_file.properties();
-
-    let location = path.to_string_lossy().to_string();
-    let duration_ms = properties.duration().as_millis() as i32;
-
-    let mut parsed_track = ParsedTrack {
-        picture_id: None,
-        title: None,
-        number: None,
-        disc: None,
-        artist: None,
-        album: None,
-        location,
-        duration_ms,
-        mount_point: mount_point.to_string_lossy().to_string(),
-    };
-
-    let tag = match tagged_file.primary_tag() {
-        Some(primary_tag) => primary_tag,
-        None => match tagged_file.first_tag() {
-            Some(first_tag) => first_tag,
-            None => return Some(parsed_track),
-        },
-    };
-
-    parsed_track.title = tag.title().map(|s| s.to_string());
-    parsed_track.artist = tag.artist().map(|s| s.to_string());
-    parsed_track.album = tag.album().map(|s| s.to_string());
-    parsed_track.number = tag.track().map(|n| n as i32);
-    parsed_track.disc = tag.disk().map(|n| n as i32);
-
-    if let Some(picture) = tag.pictures().first() {
-        let picture_id_digest = md5::compute(picture.data());
-        let picture_id = format!("{:x}", picture_id_digest);
-        let mut picture_path = PathBuf::from(get_cache_path()).join(&picture_id);
-
-        picture_path.set_extension("jpg");
-        fs::write(&picture_path, picture.data()).ok();
-
-        if picture_path.exists() {
-            parsed_track.picture_id = Some(picture_id);
+    pub(crate) fn from_str(role: &str) -> Option<ArtistRole> {
+        match role {
+            "main" => Some(ArtistRole::Main),
+            "featured" => Some(ArtistRole::Featured),
+            "remixer" => Some(ArtistRole::Remixer),
+            "composer" => Some(ArtistRole::Composer),
+            _ => None,
         }
     }
-
-    Some(parsed_track)
 }
 
-pub fn sync_directory(mount_point: String) {
-    use crate::model;
-    use crate::schema::album::dsl as album_dsl;
-    use crate::schema::artist::dsl as artist_dsl;
-    use crate::schema::track::dsl as track_dsl;
-
-    let mount_point = PathBuf::from(mount_point);
-
-    let parsed_tracks: Vec<ParsedTrack> = WalkDir::new(&mount_point)
-        .into_iter()
-        .par_bridge()
-        .filter_map(|e| e.ok())
-        .filter_map(|e| parse_music_file(e.path(), &mount_point))
-        .collect();
-
-    let conn = &mut establish_connection().unwrap();
-
-    for parsed_tracks in parsed_tracks {
-        let new_artist = match parsed_tracks.artist {
-            Some(artist) => Some(model::NewArtist { name: artist }),
-            None => None,
-        };
-        let artist: Option<model::Artist> = match new_artist {
-            Some(new_artist) => {
-                let artist = artist_dsl::artist
-                    .filter(artist_dsl::name.eq(&new_artist.name))
-                    .first(conn)
-                    .ok();
-
-                match artist {
-                    Some(artist) => Some(artist),
-                    None => {
-                        let _ = diesel::insert_into(artist_dsl::artist)
-                            .values(&new_artist)
-                            .execute(conn);
-                        artist_dsl::artist
-                            .filter(artist_dsl::name.eq(&new_artist.name))
-                            .first(conn)
-                            .ok()
-                    }
-                }
-            }
-            None => None,
-        };
-
-        let new_album = match parsed_tracks.album {
-            Some(album) => Some(model::NewAlbum {
-                name: album,
-                artist_id: artist.as_ref().map(|a| a.id),
-            }),
-            None => None,
-        };
-
-        let album: Option<model::Album> = match new_album {
-            Some(new_album) => {
-                let album = album_dsl::album
-                    .filter(album_dsl::name.eq(&new_album.name))
-                    .first(conn)
-                    .ok();
-
-                match album {
-                    Some(album) => Some(album),
-                    None => {
-                        let _ = diesel::insert_into(album_dsl::album)
-                            .values(&new_album)
-                            .execute(conn);
-                        album_dsl::album
-                            .filter(album_dsl::name.eq(&new_album.name))
-                            .first(conn)
-                            .ok()
-                    }
-                }
-            }
-            None => None,
-        };
-
-        let new_track = NewTrack {
-            picture_id: parsed_tracks.picture_id,
-            album_id: album.as_ref().map(|a| a.id),
-            artist_id: artist.as_ref().map(|a| a.id),
-            number: parsed_tracks.number,
-            disc: parsed_tracks.disc,
-            title: parsed_tracks.title,
-            duration_ms: parsed_tracks.duration_ms,
-            location: parsed_tracks.location,
-            mount_point: parsed_tracks.mount_point,
-        };
-
-        let res = diesel::insert_into(track_dsl::track)
-            .values(&new_track)
-            .on_conflict(track_dsl::location).do_update()
-            .set(&new_track)
-            .execute(conn);
-
-        if let Err(e) = res {
-            println!("Error inserting track: {:?}", e);
-        }
-    }
+pub struct TrackArtistDTO {
+    pub artist: Artist,
+    pub role: ArtistRole,
 }
 
 pub struct TrackDTO {
     pub id: i32,
     pub title: Option<String>,
+    /// Romaji and hiragana readings of the title, computed when the track
+    /// was scanned.
+    pub title_romaji: Option<String>,
+    pub title_hiragana: Option<String>,
     pub artist: Option<Artist>,
     pub album: Option<Album>,
+    /// The artist the album is credited to, which differs from `artist` on
+    /// compilations and tracks with guests.
+    pub album_artist: Option<Artist>,
+    /// Every artist credited on the track, in tag order. `artist` is the
+    /// first of the main artists.
+    pub artists: Vec<TrackArtistDTO>,
     pub number: Option<i32>,
     pub disc: Option<i32>,
     pub duration_ms: i32,
     pub location: String,
     pub mount_point: String,
     pub picture_id: Option<String>,
+    /// Whether the library root holding the track is currently reachable.
+    pub available: bool,
+    pub year: Option<i32>,
+    /// Release date as tagged, e.g. `2003` or `2003-07-21`.
+    pub date: Option<String>,
+    pub original_date: Option<String>,
+    /// The track's genres, joined with `"; "`.
+    pub genre: Option<String>,
+    pub genres: Vec<Genre>,
+    /// Every composer tag value, joined with `"; "`.
+    pub composer: Option<String>,
+    pub comment: Option<String>,
+    pub track_total: Option<i32>,
+    pub disc_total: Option<i32>,
+    pub bpm: Option<f64>,
+    pub isrc: Option<String>,
+    pub label: Option<String>,
+    /// Lowercase codec name, e.g. `flac`, `alac`, `mp3` or `pcm`.
+    pub codec: Option<String>,
+    /// Audio bitrate in kbit/s.
+    pub bitrate: Option<i32>,
+    /// Sample rate in Hz.
+    pub sample_rate: Option<i32>,
+    pub bit_depth: Option<i32>,
+    pub channels: Option<i32>,
+    /// Size of the file in bytes.
+    pub file_size: i64,
+    pub lossless: bool,
+    /// Title of the track's disc, from the `DISCSUBTITLE` tag.
+    pub disc_subtitle: Option<String>,
+    pub play_count: i32,
+    pub last_played_at: Option<NaiveDateTime>,
+}
+
+/// Order of tracks within an album: by disc, then track number, each with
+/// missing values last, then by location so untagged files follow their
+/// filenames.
+pub(crate) type AlbumTrackOrder = (
+    IsNull<schema::track::disc>,
+    schema::track::disc,
+    IsNull<schema::track::number>,
+    schema::track::number,
+    schema::track::location,
+);
+
+pub(crate) fn album_track_order() -> AlbumTrackOrder {
+    (
+        schema::track::disc.is_null(),
+        schema::track::disc,
+        schema::track::number.is_null(),
+        schema::track::number,
+        schema::track::location,
+    )
+}
+
+/// Mount points whose tracks should be left out of a listing.
+pub(crate) fn hidden_mount_points(conn: &mut SqliteConnection, hide_offline: bool) -> Vec<String> {
+    if hide_offline {
+        offline_root_paths(conn).unwrap()
+    } else {
+        Vec::new()
+    }
 }
 
-pub fn get_all_tracks() -> Vec<TrackDTO> {
+pub fn get_all_tracks(hide_offline: bool) -> Vec<TrackDTO> {
     use crate::schema;
 
     let conn = &mut establish_connection().unwrap();
+    let hidden_mount_points = hidden_mount_points(conn, hide_offline);
 
-    let tracks: Vec<Track> = schema::track::table.load(conn).unwrap();
+    let tracks: Vec<Track> = schema::track::table
+        .filter(schema::track::mount_point.ne_all(&hidden_mount_points))
+        .load(conn)
+        .unwrap();
 
     populate_tracks(conn, tracks)
 }
 
-pub fn get_all_track_ids_sorted_by_title() -> Vec<i32> {
+pub struct TrackPage {
+    pub tracks: Vec<TrackDTO>,
+    /// Number of tracks across all pages.
+    pub total: u32,
+}
+
+/// One page of the library in id order, `limit` tracks starting at `offset`.
+pub fn get_track_page(offset: u32, limit: u32, hide_offline: bool) -> TrackPage {
     use crate::schema::track::dsl as track_dsl;
 
     let conn = &mut establish_connection().unwrap();
+    let hidden_mount_points = hidden_mount_points(conn, hide_offline);
 
-    track_dsl::track
-        .select(track_dsl::id)
-        .order_by((track_dsl::title, track_dsl::album_id, track_dsl::artist_id, track_dsl::disc, track_dsl::number))
+    let total: i64 = track_dsl::track
+        .filter(track_dsl::mount_point.ne_all(&hidden_mount_points))
+        .count()
+        .get_result(conn)
+        .unwrap();
+
+    let tracks: Vec<Track> = track_dsl::track
+        .filter(track_dsl::mount_point.ne_all(&hidden_mount_points))
+        .order_by(track_dsl::id)
+        .limit(limit as i64)
+        .offset(offset as i64)
         .load(conn)
-        .unwrap()
+        .unwrap();
+
+    TrackPage {
+        tracks: populate_tracks(conn, tracks),
+        total: total as u32,
+    }
 }
 
-pub fn get_all_track_ids_sorted_by_artist() -> Vec<i32> {
+/// The tracks with the given ids, in the order of `ids`. Ids of tracks that
+/// no longer exist are skipped.
+///
+/// Meant to be paired with the sorted id lists to load only the rows a list
+/// is showing.
+pub fn get_tracks_by_ids(ids: Vec<i32>) -> Vec<TrackDTO> {
+    let conn = &mut establish_connection().unwrap();
+    tracks_by_ids(conn, &ids)
+}
+
+pub(crate) fn tracks_by_ids(conn: &mut SqliteConnection, ids: &[i32]) -> Vec<TrackDTO> {
     use crate::schema::track::dsl as track_dsl;
-    use crate::schema;
 
-    let conn = &mut establish_connection().unwrap();
+    let tracks: Vec<Track> = load_in_chunks(ids, |chunk| {
+        track_dsl::track.filter(track_dsl::id.eq_any(chunk)).load::<Track>(conn).unwrap()
+    });
 
-    track_dsl::track
-        .select(track_dsl::id)
-        .left_join(
-            schema::artist::table.on(schema::track::artist_id.eq(schema::artist::id.nullable())),
-        )
-        .order_by((schema::artist::name, schema::track::album_id, schema::track::disc, schema::track::number))
-        .load(conn)
-        .unwrap()
+    let mut tracks_by_id: HashMap<i32, TrackDTO> = populate_tracks(conn, tracks)
+        .into_iter()
+        .map(|track| (track.id, track))
+        .collect();
+
+    ids.iter().filter_map(|id| tracks_by_id.remove(id)).collect()
 }
 
-pub fn get_all_track_ids_sorted_by_album() -> Vec<i32> {
-    use crate::schema::track::dsl as track_dsl;
-    use crate::schema;
+pub fn get_all_track_ids_sorted_by_title(hide_offline: bool) -> Vec<i32> {
+    query_track_ids(TrackQuery::sorted(
+        vec![
+            SortKey::ascending(SortField::Title),
+            SortKey::ascending(SortField::Album),
+            SortKey::ascending(SortField::Artist),
+            SortKey::ascending(SortField::TrackNumber),
+        ],
+        Vec::new(),
+        hide_offline,
+    ))
+}
 
-    let conn = &mut establish_connection().unwrap();
+/// Track ids grouped by album artist, falling back to the track artist for
+/// tracks without an album.
+pub fn get_all_track_ids_sorted_by_artist(hide_offline: bool) -> Vec<i32> {
+    query_track_ids(TrackQuery::sorted(
+        vec![
+            SortKey::ascending(SortField::AlbumArtist),
+            SortKey::ascending(SortField::Album),
+            SortKey::ascending(SortField::TrackNumber),
+        ],
+        Vec::new(),
+        hide_offline,
+    ))
+}
 
-    track_dsl::track
-        .select(track_dsl::id)
-        .left_join(
-            schema::album::table.on(schema::track::album_id.eq(schema::album::id.nullable())),
-        )
-        .order_by((schema::album::name, schema::track::disc, schema::track::number))
-        .load(conn)
-        .unwrap()
+pub fn get_all_track_ids_sorted_by_album(hide_offline: bool) -> Vec<i32> {
+    query_track_ids(TrackQuery::sorted(
+        vec![SortKey::ascending(SortField::Album), SortKey::ascending(SortField::TrackNumber)],
+        Vec::new(),
+        hide_offline,
+    ))
 }
 
-pub fn get_all_track_ids_sorted_by_duration() -> Vec<i32> {
-    use crate::schema::track::dsl as track_dsl;
+pub fn get_all_track_ids_sorted_by_duration(hide_offline: bool) -> Vec<i32> {
+    query_track_ids(TrackQuery::sorted(
+        vec![SortKey::ascending(SortField::Duration), SortKey::ascending(SortField::Title)],
+        Vec::new(),
+        hide_offline,
+    ))
+}
 
-    let conn = &mut establish_connection().unwrap();
+/// Technical properties a track listing can be narrowed to. Every set
+/// field must match.
+#[derive(Default)]
+pub struct AudioPropertiesFilter {
+    pub codec: Option<String>,
+    pub lossless: Option<bool>,
+    pub min_bitrate: Option<i32>,
+    pub max_bitrate: Option<i32>,
+    pub min_sample_rate: Option<i32>,
+    pub min_bit_depth: Option<i32>,
+    pub channels: Option<i32>,
+}
 
-    track_dsl::track
-        .select(track_dsl::id)
-        .order_by((track_dsl::duration_ms, track_dsl::title))
-        .load(conn)
-        .unwrap()
+pub enum AudioProperty {
+    Bitrate,
+    SampleRate,
+    BitDepth,
+    Channels,
+    FileSize,
+}
+
+/// Track ids matching `filter`, sorted by `sort_by`. Tracks whose property is
+/// unknown come last in either direction.
+pub fn get_track_ids_by_audio_properties(
+    filter: AudioPropertiesFilter,
+    sort_by: AudioProperty,
+    descending: bool,
+    hide_offline: bool,
+) -> Vec<i32> {
+    let mut filters = Vec::new();
+
+    if let Some(codec) = filter.codec {
+        filters.push(TrackFilter::Codec(codec));
+    }
+    if let Some(lossless) = filter.lossless {
+        filters.push(TrackFilter::Lossless(lossless));
+    }
+    if filter.min_bitrate.is_some() || filter.max_bitrate.is_some() {
+        filters.push(TrackFilter::Bitrate {
+            min: filter.min_bitrate,
+            max: filter.max_bitrate,
+        });
+    }
+    if let Some(min_sample_rate) = filter.min_sample_rate {
+        filters.push(TrackFilter::MinSampleRate(min_sample_rate));
+    }
+    if let Some(min_bit_depth) = filter.min_bit_depth {
+        filters.push(TrackFilter::MinBitDepth(min_bit_depth));
+    }
+    if let Some(channels) = filter.channels {
+        filters.push(TrackFilter::Channels(channels));
+    }
+
+    let field = match sort_by {
+        AudioProperty::Bitrate => SortField::Bitrate,
+        AudioProperty::SampleRate => SortField::SampleRate,
+        AudioProperty::BitDepth => SortField::BitDepth,
+        AudioProperty::Channels => SortField::Channels,
+        AudioProperty::FileSize => SortField::FileSize,
+    };
+
+    query_track_ids(TrackQuery::sorted(vec![SortKey { field, descending }], filters, hide_offline))
+}
+
+/// Largest number of ids bound in one `IN` list, well below SQLite's limit
+/// on bound parameters.
+const ID_CHUNK_SIZE: usize = 10_000;
+
+/// Loads `ids` in chunks of [`ID_CHUNK_SIZE`] with `load_chunk`.
+fn load_in_chunks<T>(ids: &[i32], load_chunk: impl FnMut(&[i32]) -> Vec<T>) -> Vec<T> {
+    ids.chunks(ID_CHUNK_SIZE).flat_map(load_chunk).collect()
 }
 
-fn populate_tracks(conn: &mut SqliteConnection, tracks: Vec<Track>) -> Vec<TrackDTO> {
+/// Builds the DTOs of `tracks` with a fixed number of bulk queries for
+/// their albums, artists, credits and genres.
+pub(crate) fn populate_tracks(conn: &mut SqliteConnection, tracks: Vec<Track>) -> Vec<TrackDTO> {
     use crate::schema::album::dsl as album_dsl;
     use crate::schema::artist::dsl as artist_dsl;
+    use crate::schema::genre::dsl as genre_dsl;
+    use crate::schema::track_artist::dsl as track_artist_dsl;
+    use crate::schema::track_genre::dsl as track_genre_dsl;
+
+    let offline_root_paths = offline_root_paths(conn).unwrap();
+
+    let track_ids: Vec<i32> = tracks.iter().map(|track| track.id).collect();
+    let album_ids: Vec<i32> = tracks
+        .iter()
+        .filter_map(|track| track.album_id)
+        .collect::<HashSet<i32>>()
+        .into_iter()
+        .collect();
+
+    let albums: HashMap<i32, Album> = load_in_chunks(&album_ids, |chunk| {
+        album_dsl::album.filter(album_dsl::id.eq_any(chunk)).load::<Album>(conn).unwrap()
+    })
+    .into_iter()
+    .map(|album| (album.id, album))
+    .collect();
+
+    let mut credits: HashMap<i32, Vec<TrackArtist>> = HashMap::new();
+    let credit_rows = load_in_chunks(&track_ids, |chunk| {
+        track_artist_dsl::track_artist
+            .filter(track_artist_dsl::track_id.eq_any(chunk))
+            .order_by((track_artist_dsl::track_id, track_artist_dsl::position))
+            .load::<TrackArtist>(conn)
+            .unwrap()
+    });
+    for credit in credit_rows {
+        credits.entry(credit.track_id).or_default().push(credit);
+    }
+
+    let mut genres: HashMap<i32, Vec<Genre>> = HashMap::new();
+    let genre_rows = load_in_chunks(&track_ids, |chunk| {
+        genre_dsl::genre
+            .inner_join(track_genre_dsl::track_genre)
+            .filter(track_genre_dsl::track_id.eq_any(chunk))
+            .order_by((track_genre_dsl::track_id, track_genre_dsl::position))
+            .select((track_genre_dsl::track_id, Genre::as_select()))
+            .load::<(i32, Genre)>(conn)
+            .unwrap()
+    });
+    for (track_id, genre) in genre_rows {
+        genres.entry(track_id).or_default().push(genre);
+    }
+
+    let artist_ids: Vec<i32> = tracks
+        .iter()
+        .filter_map(|track| track.artist_id)
+        .chain(albums.values().filter_map(|album| album.artist_id))
+        .chain(credits.values().flatten().map(|credit| credit.artist_id))
+        .collect::<HashSet<i32>>()
+        .into_iter()
+        .collect();
+
+    let artists: HashMap<i32, Artist> = load_in_chunks(&artist_ids, |chunk| {
+        artist_dsl::artist.filter(artist_dsl::id.eq_any(chunk)).load::<Artist>(conn).unwrap()
+    })
+    .into_iter()
+    .map(|artist| (artist.id, artist))
+    .collect();
 
-    let mut artist_cache: HashMap<i32, Artist> = HashMap::new();
-    let mut album_cache:HashMap<i32, Album> = HashMap::new();
-    let mut track_dtos = Vec::new();
+    let mut track_dtos = Vec::with_capacity(tracks.len());
 
     for track in tracks {
-        let artist: Option<Artist> = match track.artist_id {
-            Some(artist_id) => {
-                if let Some(artist) = artist_cache.get(&artist_id) {
-                    Some(artist.clone())
-                } else {
-                    let artist: Option<Artist> = artist_dsl::artist
-                        .filter(artist_dsl::id.eq(artist_id))
-                        .first(conn)
-                        .ok();
-
-                    match artist {
-                        Some(artist) => {
-                            artist_cache.insert(artist_id, artist.clone());
-                            Some(artist)
-                        }
-                        None => None,
-                    }
-                }
-            }
-            None => None,
-        };
-
-        let album = match track.album_id {
-            Some(album_id) => {
-                if let Some(album) = album_cache.get(&album_id) {
-                    Some(album.clone())
-                } else {
-                    let album: Option<Album> = album_dsl::album
-                        .filter(album_dsl::id.eq(album_id))
-                        .first(conn)
-                        .ok();
-
-                    match album {
-                        Some(album) => {
-                            album_cache.insert(album_id, album.clone());
-                            Some(album)
-                        }
-                        None => None,
-                    }
-                }
-            }
-            None => None,
-        };
+        let artist = track.artist_id.and_then(|artist_id| artists.get(&artist_id).cloned());
+        let album = track.album_id.and_then(|album_id| albums.get(&album_id).cloned());
+        let album_artist = album
+            .as_ref()
+            .and_then(|album| album.artist_id)
+            .and_then(|artist_id| artists.get(&artist_id).cloned());
+
+        let artists = credits
+            .remove(&track.id)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|credit| {
+                Some(TrackArtistDTO {
+                    artist: artists.get(&credit.artist_id)?.clone(),
+                    role: ArtistRole::from_str(&credit.role)?,
+                })
+            })
+            .collect();
+
+        let genres = genres.remove(&track.id).unwrap_or_default();
 
         track_dtos.push(TrackDTO {
             id: track.id,
             title: track.title,
+            title_romaji: track.title_romaji,
+            title_hiragana: track.title_hiragana,
             artist,
             album,
+            album_artist,
+            artists,
             number: track.number,
             disc: track.disc,
             duration_ms: track.duration_ms,
             location: track.location,
+            available: !offline_root_paths.contains(&track.mount_point),
             mount_point: track.mount_point,
             picture_id: track.picture_id,
+            year: track.year,
+            date: track.date,
+            original_date: track.original_date,
+            genre: track.genre,
+            genres,
+            composer: track.composer,
+            comment: track.comment,
+            track_total: track.track_total,
+            disc_total: track.disc_total,
+            bpm: track.bpm,
+            isrc: track.isrc,
+            label: track.label,
+            codec: track.codec,
+            bitrate: track.bitrate,
+            sample_rate: track.sample_rate,
+            bit_depth: track.bit_depth,
+            channels: track.channels,
+            file_size: track.file_size,
+            lossless: track.lossless,
+            disc_subtitle: track.disc_subtitle,
+            play_count: track.play_count,
+            last_played_at: track.last_played_at,
         });
-    };
+    }
 
     track_dtos
 }
 
+/// Counts one play of `track_id` and remembers when it happened.
+pub fn record_track_played(track_id: i32) {
+    use crate::schema::track::dsl as track_dsl;
+
+    let conn = &mut establish_connection().unwrap();
+
+    diesel::update(track_dsl::track.find(track_id))
+        .set((
+            track_dsl::play_count.eq(track_dsl::play_count + 1),
+            track_dsl::last_played_at.eq(Utc::now().naive_utc()),
+        ))
+        .execute(conn)
+        .unwrap();
+}
+
 pub fn delete_all_tracks() {
     use crate::schema::track::dsl as track_dsl;
 
     let conn = &mut establish_connection().unwrap();
 
     diesel::delete(track_dsl::track).execute(conn).unwrap();
+    delete_orphaned_metadata(conn).unwrap();
 }
 
-pub fn find_track_by_album(album_id: i32) -> Vec<TrackDTO> {
+pub fn find_track_by_album(album_id: i32, hide_offline: bool) -> Vec<TrackDTO> {
+    query_tracks(TrackQuery::sorted(
+        vec![SortKey::ascending(SortField::TrackNumber)],
+        vec![TrackFilter::Album(album_id)],
+        hide_offline,
+    ))
+}
+
+/// Distinct release years in the library, newest first.
+pub fn get_all_years(hide_offline: bool) -> Vec<i32> {
     use crate::schema::track::dsl as track_dsl;
 
     let conn = &mut establish_connection().unwrap();
+    let hidden_mount_points = hidden_mount_points(conn, hide_offline);
+
+    let years: Vec<Option<i32>> = track_dsl::track
+        .select(track_dsl::year)
+        .distinct()
+        .filter(track_dsl::year.is_not_null())
+        .filter(track_dsl::mount_point.ne_all(&hidden_mount_points))
+        .order_by(track_dsl::year.desc())
+        .load(conn)
+        .unwrap();
 
-    let tracks: Vec<Track> = track_dsl::track
-        .filter(track_dsl::album_id.eq(album_id))
-        .order_by((track_dsl::number, track_dsl::disc))
+    years.into_iter().flatten().collect()
+}
+
+pub fn find_track_by_year(year: i32, hide_offline: bool) -> Vec<TrackDTO> {
+    query_tracks(TrackQuery::sorted(
+        vec![SortKey::ascending(SortField::Album), SortKey::ascending(SortField::TrackNumber)],
+        vec![TrackFilter::Year {
+            min: Some(year),
+            max: Some(year),
+        }],
+        hide_offline,
+    ))
+}
+
+/// Every track `artist_id` is credited on, in any role.
+pub fn find_track_by_artist(artist_id: i32, hide_offline: bool) -> Vec<TrackDTO> {
+    query_tracks(TrackQuery::sorted(
+        vec![SortKey::ascending(SortField::Album), SortKey::ascending(SortField::TrackNumber)],
+        vec![TrackFilter::Artist(artist_id)],
+        hide_offline,
+    ))
+}
+
+pub struct GenreSummary {
+    pub id: i32,
+    pub name: String,
+    pub track_count: i32,
+    pub album_count: i32,
+}
+
+/// Every genre with the number of tracks and albums tagged with it, by name.
+pub fn get_all_genres(hide_offline: bool) -> Vec<GenreSummary> {
+    use crate::schema::genre::dsl as genre_dsl;
+    use crate::schema::track::dsl as track_dsl;
+    use crate::schema::track_genre::dsl as track_genre_dsl;
+    use diesel::dsl::count_distinct;
+
+    let conn = &mut establish_connection().unwrap();
+    let hidden_mount_points = hidden_mount_points(conn, hide_offline);
+
+    let genres: Vec<(i32, String, i64, i64)> = genre_dsl::genre
+        .inner_join(track_genre_dsl::track_genre.inner_join(track_dsl::track))
+        .filter(track_dsl::mount_point.ne_all(&hidden_mount_points))
+        .group_by(genre_dsl::id)
+        .select((
+            genre_dsl::id,
+            genre_dsl::name,
+            count_distinct(track_dsl::id),
+            count_distinct(track_dsl::album_id),
+        ))
+        .order_by(genre_dsl::name)
         .load(conn)
         .unwrap();
 
-    populate_tracks(conn, tracks)
+    genres
+        .into_iter()
+        .map(|(id, name, track_count, album_count)| GenreSummary {
+            id,
+            name,
+            track_count: track_count as i32,
+            album_count: album_count as i32,
+        })
+        .collect()
+}
+
+pub fn find_track_by_genre(genre_id: i32, hide_offline: bool) -> Vec<TrackDTO> {
+    query_tracks(TrackQuery::sorted(
+        vec![SortKey::ascending(SortField::Album), SortKey::ascending(SortField::TrackNumber)],
+        vec![TrackFilter::Genre(genre_id)],
+        hide_offline,
+    ))
+}
+
+/// Albums with at least one track tagged with `genre_id`, by the reading of
+/// their name.
+pub fn find_album_by_genre(genre_id: i32, hide_offline: bool) -> Vec<Album> {
+    use crate::schema::album::dsl as album_dsl;
+    use crate::schema::track::dsl as track_dsl;
+    use crate::schema::track_genre::dsl as track_genre_dsl;
+
+    let conn = &mut establish_connection().unwrap();
+    let hidden_mount_points = hidden_mount_points(conn, hide_offline);
+
+    album_dsl::album
+        .filter(
+            album_dsl::id.nullable().eq_any(
+                track_dsl::track
+                    .inner_join(track_genre_dsl::track_genre)
+                    .filter(track_genre_dsl::genre_id.eq(genre_id))
+                    .filter(track_dsl::mount_point.ne_all(&hidden_mount_points))
+                    .select(track_dsl::album_id),
+            ),
+        )
+        .order_by((ifnull(album_dsl::name_hiragana, album_dsl::name.nullable()), album_dsl::name))
+        .load(conn)
+        .unwrap()
 }
 
 pub fn pick_directory() -> Option<String> {
@@ -427,3 +689,237 @@ pub fn pick_directory() -> Option<String> {
         .pick_folder()
         .map(|s| s.to_string_lossy().to_string())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{write_wav, TestLibrary};
+
+    fn titles(conn: &mut SqliteConnection) -> Vec<Option<String>> {
+        use crate::schema::track::dsl as track_dsl;
+
+        track_dsl::track
+            .select(track_dsl::title)
+            .order_by(track_dsl::title)
+            .load(conn)
+            .unwrap()
+    }
+
+    fn names(conn: &mut SqliteConnection) -> (Vec<String>, Vec<String>) {
+        use crate::schema::album::dsl as album_dsl;
+        use crate::schema::artist::dsl as artist_dsl;
+
+        let albums = album_dsl::album
+            .select(album_dsl::name)
+            .order_by(album_dsl::name)
+            .load(conn)
+            .unwrap();
+        let artists = artist_dsl::artist
+            .select(artist_dsl::name)
+            .order_by(artist_dsl::name)
+            .load(conn)
+            .unwrap();
+
+        (albums, artists)
+    }
+
+    #[test]
+    fn sync_directory_skips_unchanged_files() {
+        let library = TestLibrary::new();
+        let kept = library.path("kept.wav");
+        let edited = library.path("edited.wav");
+        write_wav(&kept, &[("INAM", "Kept")]);
+        write_wav(&edited, &[("INAM", "Edited")]);
+
+        let summary = sync_directory(library.mount_point()).unwrap();
+        assert_eq!(
+            (summary.added, summary.updated, summary.unchanged, summary.failed),
+            (2, 0, 0, 0)
+        );
+
+        // Garbage of the same size and modification time is never opened.
+        let metadata = fs::metadata(&kept).unwrap();
+        fs::write(&kept, vec![0xff; metadata.len() as usize]).unwrap();
+        fs::File::options()
+            .write(true)
+            .open(&kept)
+            .unwrap()
+            .set_modified(metadata.modified().unwrap())
+            .unwrap();
+
+        write_wav(&edited, &[("INAM", "Edited again")]);
+
+        let summary = sync_directory(library.mount_point()).unwrap();
+        assert_eq!(
+            (summary.added, summary.updated, summary.unchanged, summary.failed),
+            (0, 1, 1, 0)
+        );
+
+        let conn = &mut establish_connection().unwrap();
+        assert_eq!(
+            titles(conn),
+            [Some("Edited again".to_string()), Some("Kept".to_string())]
+        );
+    }
+
+    #[test]
+    fn sync_directory_removes_missing_files_and_orphans() {
+        let library = TestLibrary::new();
+        write_wav(
+            &library.path("kept.wav"),
+            &[("INAM", "Kept"), ("IART", "Kept Artist"), ("IPRD", "Kept Album")],
+        );
+        write_wav(
+            &library.path("gone/gone.wav"),
+            &[("INAM", "Gone"), ("IART", "Gone Artist"), ("IPRD", "Gone Album")],
+        );
+
+        sync_directory(library.mount_point()).unwrap();
+        fs::remove_dir_all(library.path("gone")).unwrap();
+
+        let summary = sync_directory(library.mount_point()).unwrap();
+        assert_eq!((summary.unchanged, summary.removed), (1, 1));
+
+        let conn = &mut establish_connection().unwrap();
+        assert_eq!(titles(conn), [Some("Kept".to_string())]);
+        assert_eq!(
+            names(conn),
+            (vec!["Kept Album".to_string()], vec!["Kept Artist".to_string()])
+        );
+    }
+
+    #[test]
+    fn sync_directory_keeps_tracks_of_an_unreadable_mount_point() {
+        let library = TestLibrary::new();
+        write_wav(&library.path("kept.wav"), &[("INAM", "Kept")]);
+
+        sync_directory(library.mount_point()).unwrap();
+        fs::rename(&library.music_dir, library.path("../unplugged")).unwrap();
+
+        let summary = sync_directory(library.mount_point()).unwrap();
+        assert_eq!(summary.removed, 0);
+
+        let conn = &mut establish_connection().unwrap();
+        assert_eq!(titles(conn), [Some("Kept".to_string())]);
+    }
+
+    #[test]
+    fn album_identity_migration_splits_merged_albums() {
+        use crate::schema::album::dsl as album_dsl;
+        use crate::schema::track::dsl as track_dsl;
+
+        let conn = &mut SqliteConnection::establish(":memory:").unwrap();
+
+        // Bring the database up to the schema from just before the migration.
+        while !conn.pending_migrations(MIGRATIONS).unwrap()[0]
+            .name()
+            .to_string()
+            .contains("album_identity")
+        {
+            conn.run_next_migration(MIGRATIONS).unwrap();
+        }
+
+        // Both tracks ended up on one album, named after whichever artist came first.
+        conn.batch_execute(
+            "INSERT INTO artist (id, name) VALUES (1, 'First'), (2, 'Second');
+             INSERT INTO album (id, name, artist_id) VALUES (1, 'Greatest Hits', 1);
+             INSERT INTO track (id, album_id, artist_id, title, duration_ms, location, mount_point, file_modified_at)
+             VALUES (1, 1, 1, 'One', 0, '/music/1.wav', '/music', '2024-01-01 00:00:00'),
+                    (2, 1, 2, 'Two', 0, '/music/2.wav', '/music', '2024-01-01 00:00:00');",
+        )
+        .unwrap();
+
+        run_migrations(conn).unwrap();
+
+        // Album id, album artist and track artist of each track.
+        let tracks: Vec<(i32, Option<i32>, Option<i32>)> = track_dsl::track
+            .inner_join(album_dsl::album)
+            .select((album_dsl::id, album_dsl::artist_id, track_dsl::artist_id))
+            .order_by(track_dsl::id)
+            .load(conn)
+            .unwrap();
+        assert_eq!(tracks.len(), 2);
+        assert_eq!(tracks[0], (1, Some(1), Some(1)));
+        assert_eq!((tracks[1].1, tracks[1].2), (Some(2), Some(2)));
+        assert_ne!(tracks[1].0, 1);
+
+        let album_names: Vec<String> = album_dsl::album.select(album_dsl::name).load(conn).unwrap();
+        assert_eq!(album_names, ["Greatest Hits", "Greatest Hits"]);
+
+        // Every file is read again on the next scan.
+        let stamps: Vec<Option<chrono::NaiveDateTime>> =
+            track_dsl::track.select(track_dsl::file_modified_at).load(conn).unwrap();
+        assert_eq!(stamps, [None, None]);
+    }
+
+    #[test]
+    fn populate_tracks_matches_one_track_at_a_time() {
+        let library = TestLibrary::new();
+        write_wav(
+            &library.path("full.wav"),
+            &[
+                ("INAM", "Full"),
+                ("IART", "Lead feat. Guest"),
+                ("IPRD", "Album"),
+                ("IGNR", "Rock; Pop"),
+            ],
+        );
+        write_wav(
+            &library.path("same-album.wav"),
+            &[("INAM", "Same Album"), ("IART", "Other"), ("IPRD", "Album")],
+        );
+        write_wav(
+            &library.path("artist-only.wav"),
+            &[("INAM", "Artist Only"), ("IART", "Lead")],
+        );
+        write_wav(&library.path("bare.wav"), &[]);
+        sync_directory(library.mount_point()).unwrap();
+
+        // Ids of everything a DTO points at, which is what the lookups fill in.
+        type Ids = (
+            i32,
+            Option<i32>,
+            Option<i32>,
+            Option<i32>,
+            Vec<(i32, ArtistRole)>,
+            Vec<i32>,
+        );
+        fn ids(track: &TrackDTO) -> Ids {
+            (
+                track.id,
+                track.artist.as_ref().map(|artist| artist.id),
+                track.album.as_ref().map(|album| album.id),
+                track.album_artist.as_ref().map(|artist| artist.id),
+                track
+                    .artists
+                    .iter()
+                    .map(|credit| (credit.artist.id, credit.role))
+                    .collect(),
+                track.genres.iter().map(|genre| genre.id).collect(),
+            )
+        }
+
+        let conn = &mut establish_connection().unwrap();
+        let tracks: Vec<Track> = schema::track::table.order_by(schema::track::id).load(conn).unwrap();
+        assert_eq!(tracks.len(), 4);
+
+        let bulk: Vec<Ids> = populate_tracks(conn, tracks.clone()).iter().map(ids).collect();
+        let one_by_one: Vec<Ids> = tracks
+            .into_iter()
+            .flat_map(|track| populate_tracks(conn, vec![track]))
+            .map(|track| ids(&track))
+            .collect();
+        assert_eq!(bulk, one_by_one);
+
+        let dtos = get_all_tracks(false);
+        let full = dtos
+            .iter()
+            .find(|track| track.title.as_deref() == Some("Full"))
+            .unwrap();
+        assert_eq!(full.album.as_ref().map(|album| album.name.as_str()), Some("Album"));
+        assert_eq!(full.genres.len(), 2);
+        let bare = dtos.iter().find(|track| track.title.is_none()).unwrap();
+        assert!(bare.artist.is_none() && bare.album.is_none() && bare.album_artist.is_none());
+        assert!(bare.artists.is_empty() && bare.genres.is_empty());
+    }
+}
@@ -1,22 +1,40 @@
 use chrono::Duration;
 
+use super::scan::readings_of;
 use super::simple::TrackDTO;
 
+/// Whether the title, artist or album of `track` contains `query`, as
+/// written or by the readings stored when the track was scanned.
 #[flutter_rust_bridge::frb(sync)]
 pub fn track_query_filter_condition(query: String, track: TrackDTO) -> bool {
     let query = query.to_lowercase();
-    let track_name = track.title.unwrap_or_default().to_lowercase();
-    let track_artist = track.artist.map(|artist| artist.name).unwrap_or_default().to_lowercase();
-    let track_album = track.album.map(|album| album.name).unwrap_or_default().to_lowercase();
-
-    if track_name.contains(&query) || track_artist.contains(&query) || track_album.contains(&query) {
-        return true;
-    }
-
-    let query_kana = kakasi::convert(&query).romaji;
-
-    if kakasi::convert(&track_name).romaji.contains(&query_kana) || kakasi::convert(&track_artist).romaji.contains(&query_kana) || kakasi::convert(&track_album).romaji.contains(&query_kana) {
-        return true;
+    let (query_romaji, query_hiragana) = readings_of(&query);
+
+    let (artist, artist_romaji, artist_hiragana) = match track.artist {
+        Some(artist) => (Some(artist.name), artist.name_romaji, artist.name_hiragana),
+        None => (None, None, None),
+    };
+    let (album, album_romaji, album_hiragana) = match track.album {
+        Some(album) => (Some(album.name), album.name_romaji, album.name_hiragana),
+        None => (None, None, None),
+    };
+
+    let fields = [
+        (track.title, &query),
+        (artist, &query),
+        (album, &query),
+        (track.title_romaji, &query_romaji),
+        (artist_romaji, &query_romaji),
+        (album_romaji, &query_romaji),
+        (track.title_hiragana, &query_hiragana),
+        (artist_hiragana, &query_hiragana),
+        (album_hiragana, &query_hiragana),
+    ];
+
+    for (field, query) in fields {
+        if field.is_some_and(|field| field.to_lowercase().contains(query.as_str())) {
+            return true;
+        }
     }
 
     false
@@ -0,0 +1,448 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::Mutex,
+    time::Duration,
+};
+
+use chrono::NaiveDateTime;
+use diesel::{
+    BoolExpressionMethods, Connection, ExpressionMethods, OptionalExtension, QueryDsl, QueryResult, RunQueryDsl,
+    SqliteConnection,
+};
+use jwalk::WalkDir;
+use notify_debouncer_full::{
+    new_debouncer,
+    notify::{
+        event::{ModifyKind, RenameMode},
+        EventKind, RecommendedWatcher, RecursiveMode, Watcher,
+    },
+    DebounceEventResult, DebouncedEvent, Debouncer, FileIdMap,
+};
+
+use crate::frb_generated::StreamSink;
+
+use super::config::get_config;
+use super::library::refresh_library_root;
+use super::scan::{
+    clear_scan_error, delete_orphaned_metadata, match_music_file, parse_music_file, record_scan_error,
+    sniffs_as_music, FileStamp, MusicFileMatch, ScanFailure, TrackWriter,
+};
+use super::simple::establish_connection;
+
+/// How long filesystem events are collected before they are applied together.
+const DEBOUNCE_TIMEOUT: Duration = Duration::from_secs(2);
+
+static LIBRARY_WATCHER: Mutex<Option<Debouncer<RecommendedWatcher, FileIdMap>>> = Mutex::new(None);
+
+/// A track whose file was renamed or moved while being watched.
+pub struct MovedTrack {
+    pub from: String,
+    pub to: String,
+}
+
+/// Changes applied to the library from one batch of filesystem events.
+#[derive(Default)]
+pub struct LibraryChangeEvent {
+    /// Locations of tracks that were added or re-parsed.
+    pub upserted: Vec<String>,
+    /// Locations of tracks that were removed.
+    pub removed: Vec<String>,
+    /// Tracks that kept their id but changed location.
+    pub moved: Vec<MovedTrack>,
+}
+
+impl LibraryChangeEvent {
+    fn is_empty(&self) -> bool {
+        self.upserted.is_empty() && self.removed.is_empty() && self.moved.is_empty()
+    }
+
+    fn extend(&mut self, other: LibraryChangeEvent) {
+        self.upserted.extend(other.upserted);
+        self.removed.extend(other.removed);
+        self.moved.extend(other.moved);
+    }
+}
+
+/// Starts watching every enabled library root and applies file creations,
+/// modifications, renames and deletions to the `track` table as they happen.
+/// Each applied batch is reported through `sink`.
+///
+/// Calling this again replaces the previous watcher, so it can be used to pick
+/// up roots that were added, removed, enabled or disabled.
+pub fn watch_library(sink: StreamSink<LibraryChangeEvent>) -> Result<(), String> {
+    use crate::schema::library_root::dsl as library_root_dsl;
+
+    let conn = &mut establish_connection().map_err(|e| e.to_string())?;
+
+    let mount_points: Vec<PathBuf> = library_root_dsl::library_root
+        .filter(library_root_dsl::enabled.eq(true))
+        .select(library_root_dsl::path)
+        .load::<String>(conn)
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .map(PathBuf::from)
+        .filter(|mount_point| mount_point.is_dir())
+        .collect();
+
+    let handler_mount_points = mount_points.clone();
+    let mut debouncer = new_debouncer(DEBOUNCE_TIMEOUT, None, move |result: DebounceEventResult| {
+        // Without the database there is nowhere to apply the events or to
+        // record why they failed, so they are left to the next scan.
+        let Ok(mut conn) = establish_connection() else {
+            return;
+        };
+
+        let events = match result {
+            Ok(events) => events,
+            Err(errors) => {
+                for mut error in errors {
+                    let paths = std::mem::take(&mut error.paths);
+                    let _ = record_failures(&mut conn, &paths, &handler_mount_points, &error.into());
+                }
+                return;
+            }
+        };
+
+        let changes = conn.transaction(|conn| {
+            let mut writer = TrackWriter::default();
+            let mut changes = LibraryChangeEvent::default();
+
+            for event in events.iter() {
+                // Each event gets a savepoint of its own, so one that cannot
+                // be applied is skipped without undoing the rest of the batch.
+                let mut applied = LibraryChangeEvent::default();
+                let result = conn.transaction(|conn| {
+                    apply_event(conn, &mut writer, event, &handler_mount_points, &mut applied)
+                });
+
+                match result {
+                    Ok(()) => changes.extend(applied),
+                    Err(e) => {
+                        writer.forget_cached_ids();
+                        record_failures(conn, &event.paths, &handler_mount_points, &e.into())?;
+                    }
+                }
+            }
+
+            writer.finish(conn)?;
+
+            // A track moved onto another replaces it, which may orphan metadata
+            // as well.
+            if !changes.removed.is_empty() || !changes.moved.is_empty() {
+                delete_orphaned_metadata(conn)?;
+            }
+
+            if !changes.is_empty() {
+                for mount_point in handler_mount_points.iter() {
+                    refresh_library_root(conn, &mount_point.to_string_lossy(), false)?;
+                }
+            }
+
+            QueryResult::Ok(changes)
+        });
+
+        match changes {
+            Ok(changes) if !changes.is_empty() => {
+                let _ = sink.add(changes);
+            }
+            Ok(_) => {}
+            Err(e) => {
+                // The whole batch was rolled back, recorded errors included.
+                let failure = ScanFailure::from(e);
+                for event in events.iter() {
+                    let _ = record_failures(&mut conn, &event.paths, &handler_mount_points, &failure);
+                }
+            }
+        }
+    })
+    .map_err(|e| e.to_string())?;
+
+    for mount_point in mount_points.iter() {
+        debouncer
+            .watcher()
+            .watch(mount_point, RecursiveMode::Recursive)
+            .map_err(|e| e.to_string())?;
+        debouncer.cache().add_root(mount_point, RecursiveMode::Recursive);
+    }
+
+    if let Some(previous) = LIBRARY_WATCHER.lock().unwrap().replace(debouncer) {
+        previous.stop_nonblocking();
+    }
+
+    Ok(())
+}
+
+/// Stops the watcher started by [`watch_library`], if any.
+pub fn unwatch_library() {
+    if let Some(debouncer) = LIBRARY_WATCHER.lock().unwrap().take() {
+        debouncer.stop();
+    }
+}
+
+/// Applies one filesystem event to the `track` table.
+fn apply_event(
+    conn: &mut SqliteConnection,
+    writer: &mut TrackWriter,
+    event: &DebouncedEvent,
+    mount_points: &[PathBuf],
+    changes: &mut LibraryChangeEvent,
+) -> QueryResult<()> {
+    match event.kind {
+        EventKind::Modify(ModifyKind::Name(RenameMode::Both)) if event.paths.len() == 2 => {
+            move_path(conn, writer, &event.paths[0], &event.paths[1], mount_points, changes)?;
+        }
+        EventKind::Modify(ModifyKind::Name(RenameMode::From)) | EventKind::Remove(_) => {
+            for path in event.paths.iter() {
+                remove_path(conn, path, changes)?;
+            }
+        }
+        EventKind::Create(_) | EventKind::Modify(_) => {
+            for path in event.paths.iter() {
+                if path.exists() {
+                    upsert_path(conn, writer, path, mount_points, changes)?;
+                } else {
+                    remove_path(conn, path, changes)?;
+                }
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+/// Records `failure` for each of `paths` that is inside a watched mount point.
+fn record_failures(
+    conn: &mut SqliteConnection,
+    paths: &[PathBuf],
+    mount_points: &[PathBuf],
+    failure: &ScanFailure,
+) -> QueryResult<()> {
+    for path in paths {
+        if let Some(mount_point) = mount_point_of(path, mount_points) {
+            record_scan_error(conn, &path.to_string_lossy(), &mount_point.to_string_lossy(), failure)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn mount_point_of<'a>(path: &Path, mount_points: &'a [PathBuf]) -> Option<&'a PathBuf> {
+    mount_points
+        .iter()
+        .filter(|mount_point| path.starts_with(mount_point))
+        .max_by_key(|mount_point| mount_point.as_os_str().len())
+}
+
+/// Bounds of the locations below `directory`: they all start with its path
+/// and a separator, so they sort at or after that prefix and before the prefix
+/// with the separator bumped to the next character.
+///
+/// SQLite compares text byte by byte, so unlike `LIKE` this is case-sensitive
+/// and needs no escaping.
+fn descendants_range(directory: &Path) -> (String, String) {
+    let mut prefix = directory.to_string_lossy().to_string();
+    if !prefix.ends_with(std::path::MAIN_SEPARATOR) {
+        prefix.push(std::path::MAIN_SEPARATOR);
+    }
+
+    let mut end = prefix.clone();
+    end.pop();
+    end.push((std::path::MAIN_SEPARATOR as u8 + 1) as char);
+    (prefix, end)
+}
+
+/// Imports `path`, or every music file below it if it is a directory.
+fn upsert_path(
+    conn: &mut SqliteConnection,
+    writer: &mut TrackWriter,
+    path: &Path,
+    mount_points: &[PathBuf],
+    changes: &mut LibraryChangeEvent,
+) -> QueryResult<()> {
+    use crate::schema::track::dsl as track_dsl;
+
+    let mount_point = match mount_point_of(path, mount_points) {
+        Some(mount_point) => mount_point,
+        None => return Ok(()),
+    };
+
+    let config = get_config();
+    let files: Vec<(PathBuf, MusicFileMatch)> = if path.is_dir() {
+        WalkDir::new(path)
+            .into_iter()
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter_map(|path| match_music_file(&path, &config).map(|music_file_match| (path, music_file_match)))
+            .collect()
+    } else if let Some(music_file_match) = match_music_file(path, &config) {
+        vec![(path.to_path_buf(), music_file_match)]
+    } else {
+        return Ok(());
+    };
+
+    for (file, music_file_match) in files {
+        let location = file.to_string_lossy().to_string();
+
+        let stamp = match FileStamp::read(&file) {
+            Ok(stamp) => stamp,
+            Err(e) => {
+                record_scan_error(conn, &location, &mount_point.to_string_lossy(), &e.into())?;
+                continue;
+            }
+        };
+
+        let known_stamp = track_dsl::track
+            .filter(track_dsl::location.eq(&location))
+            .select((track_dsl::file_size, track_dsl::file_modified_at))
+            .first::<(i64, Option<NaiveDateTime>)>(conn)
+            .optional()?
+            .map(|(size, modified_at)| FileStamp { size, modified_at });
+
+        if known_stamp == Some(stamp) {
+            continue;
+        }
+
+        if music_file_match == MusicFileMatch::Content && !sniffs_as_music(&file) {
+            continue;
+        }
+
+        // Like a scan, a file that fails to write gets a savepoint of its own
+        // so the other files of the event still go in.
+        match parse_music_file(&file, mount_point, stamp, &config) {
+            Ok(parsed_track) => match conn.transaction(|conn| writer.write(conn, parsed_track)) {
+                Ok(()) => {
+                    clear_scan_error(conn, &location)?;
+                    changes.upserted.push(location);
+                }
+                Err(e) => {
+                    writer.forget_cached_ids();
+                    record_scan_error(conn, &location, &mount_point.to_string_lossy(), &e.into())?;
+                }
+            },
+            Err(e) => record_scan_error(conn, &location, &mount_point.to_string_lossy(), &e.into())?,
+        }
+    }
+
+    Ok(())
+}
+
+/// Forgets the recorded errors of the file at `path`, or of every file below
+/// it if it was a directory.
+fn clear_scan_errors_at(conn: &mut SqliteConnection, path: &Path) -> QueryResult<()> {
+    use crate::schema::scan_error::dsl as scan_error_dsl;
+
+    let location = path.to_string_lossy().to_string();
+    let (descendants_start, descendants_end) = descendants_range(path);
+
+    diesel::delete(
+        scan_error_dsl::scan_error.filter(
+            scan_error_dsl::location.eq(&location).or(scan_error_dsl::location
+                .ge(&descendants_start)
+                .and(scan_error_dsl::location.lt(&descendants_end))),
+        ),
+    )
+    .execute(conn)?;
+
+    Ok(())
+}
+
+/// Removes the track at `path`, or every track below it if it was a directory.
+fn remove_path(conn: &mut SqliteConnection, path: &Path, changes: &mut LibraryChangeEvent) -> QueryResult<()> {
+    use crate::schema::track::dsl as track_dsl;
+
+    let location = path.to_string_lossy().to_string();
+
+    let (descendants_start, descendants_end) = descendants_range(path);
+
+    // Errors go too, including those of files that never made it into the
+    // library.
+    clear_scan_errors_at(conn, path)?;
+
+    let removed: Vec<String> = track_dsl::track
+        .select(track_dsl::location)
+        .filter(
+            track_dsl::location.eq(&location).or(track_dsl::location
+                .ge(&descendants_start)
+                .and(track_dsl::location.lt(&descendants_end))),
+        )
+        .load(conn)?;
+
+    if removed.is_empty() {
+        return Ok(());
+    }
+
+    diesel::delete(track_dsl::track.filter(track_dsl::location.eq_any(&removed))).execute(conn)?;
+    changes.removed.extend(removed);
+
+    Ok(())
+}
+
+/// Rewrites the location of the tracks at or below `from` so they keep their
+/// ids, then imports anything at `to` that was not already known.
+fn move_path(
+    conn: &mut SqliteConnection,
+    writer: &mut TrackWriter,
+    from: &Path,
+    to: &Path,
+    mount_points: &[PathBuf],
+    changes: &mut LibraryChangeEvent,
+) -> QueryResult<()> {
+    use crate::schema::track::dsl as track_dsl;
+
+    let mount_point = match mount_point_of(to, mount_points) {
+        Some(mount_point) => mount_point.to_string_lossy().to_string(),
+        None => return remove_path(conn, from, changes),
+    };
+
+    let from_location = from.to_string_lossy().to_string();
+
+    let (descendants_start, descendants_end) = descendants_range(from);
+
+    let moved: Vec<(i32, String)> = track_dsl::track
+        .select((track_dsl::id, track_dsl::location))
+        .filter(
+            track_dsl::location.eq(&from_location).or(track_dsl::location
+                .ge(&descendants_start)
+                .and(track_dsl::location.lt(&descendants_end))),
+        )
+        .load(conn)?;
+
+    for (id, location) in moved {
+        let relative = match Path::new(&location).strip_prefix(from) {
+            Ok(relative) => relative,
+            Err(_) => continue,
+        };
+        let new_location = if relative.as_os_str().is_empty() {
+            to.to_path_buf()
+        } else {
+            to.join(relative)
+        };
+        let new_location = new_location.to_string_lossy().to_string();
+
+        // A file moved over another replaces it.
+        diesel::delete(
+            track_dsl::track
+                .filter(track_dsl::location.eq(&new_location))
+                .filter(track_dsl::id.ne(id)),
+        )
+        .execute(conn)?;
+
+        diesel::update(track_dsl::track.filter(track_dsl::id.eq(id)))
+            .set((
+                track_dsl::location.eq(&new_location),
+                track_dsl::mount_point.eq(&mount_point),
+            ))
+            .execute(conn)?;
+
+        changes.moved.push(MovedTrack {
+            from: location,
+            to: new_location,
+        });
+    }
+
+    // Files that failed to import are tried again at their new location.
+    clear_scan_errors_at(conn, from)?;
+
+    upsert_path(conn, writer, to, mount_points, changes)
+}
@@ -19,6 +19,7 @@
 
 // Section: imports
 
+use crate::api::scan::*;
 use flutter_rust_bridge::for_generated::byteorder::{NativeEndian, ReadBytesExt, WriteBytesExt};
 use flutter_rust_bridge::for_generated::transform_result_dco;
 use flutter_rust_bridge::{Handler, IntoIntoDart};
@@ -38,7 +39,7 @@ flutter_rust_bridge::frb_generated_default_handler!();
 
 // Section: wire_funcs
 
-fn wire_delete_all_tracks_impl(
+fn wire_get_album_detail_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -46,7 +47,7 @@ fn wire_delete_all_tracks_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "delete_all_tracks",
+            debug_name: "get_album_detail",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -60,16 +61,21 @@ fn wire_delete_all_tracks_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_album_id = <i32>::sse_decode(&mut deserializer);
+            let api_hide_offline = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    Result::<_, ()>::Ok(crate::api::simple::delete_all_tracks())
+                    Result::<_, ()>::Ok(crate::api::browse::get_album_detail(
+                        api_album_id,
+                        api_hide_offline,
+                    ))
                 })())
             }
         },
     )
 }
-fn wire_find_track_by_album_impl(
+fn wire_get_all_albums_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -77,7 +83,7 @@ fn wire_find_track_by_album_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "find_track_by_album",
+            debug_name: "get_all_albums",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -91,17 +97,17 @@ fn wire_find_track_by_album_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_album_id = <i32>::sse_decode(&mut deserializer);
+            let api_hide_offline = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    Result::<_, ()>::Ok(crate::api::simple::find_track_by_album(api_album_id))
+                    Result::<_, ()>::Ok(crate::api::browse::get_all_albums(api_hide_offline))
                 })())
             }
         },
     )
 }
-fn wire_frb_init_impl(
+fn wire_get_all_artists_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -109,7 +115,7 @@ fn wire_frb_init_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "frb_init",
+            debug_name: "get_all_artists",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -123,16 +129,17 @@ fn wire_frb_init_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_hide_offline = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse(
-                    (move || Result::<_, ()>::Ok(crate::api::simple::frb_init()))(),
-                )
+                transform_result_sse((move || {
+                    Result::<_, ()>::Ok(crate::api::browse::get_all_artists(api_hide_offline))
+                })())
             }
         },
     )
 }
-fn wire_get_all_track_ids_sorted_by_album_impl(
+fn wire_get_artist_detail_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -140,7 +147,7 @@ fn wire_get_all_track_ids_sorted_by_album_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_all_track_ids_sorted_by_album",
+            debug_name: "get_artist_detail",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -154,16 +161,77 @@ fn wire_get_all_track_ids_sorted_by_album_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_artist_id = <i32>::sse_decode(&mut deserializer);
+            let api_hide_offline = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    Result::<_, ()>::Ok(crate::api::simple::get_all_track_ids_sorted_by_album())
+                    Result::<_, ()>::Ok(crate::api::browse::get_artist_detail(
+                        api_artist_id,
+                        api_hide_offline,
+                    ))
                 })())
             }
         },
     )
 }
-fn wire_get_all_track_ids_sorted_by_artist_impl(
+fn wire_get_config_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_config",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse((move || {
+                Result::<_, ()>::Ok(crate::api::config::get_config())
+            })())
+        },
+    )
+}
+fn wire_get_default_config_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_default_config",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse((move || {
+                Result::<_, ()>::Ok(crate::api::config::get_default_config())
+            })())
+        },
+    )
+}
+fn wire_set_config_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -171,7 +239,7 @@ fn wire_get_all_track_ids_sorted_by_artist_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_all_track_ids_sorted_by_artist",
+            debug_name: "set_config",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -185,16 +253,15 @@ fn wire_get_all_track_ids_sorted_by_artist_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_config = <crate::api::config::Config>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse((move || {
-                    Result::<_, ()>::Ok(crate::api::simple::get_all_track_ids_sorted_by_artist())
-                })())
+                transform_result_sse((move || crate::api::config::set_config(api_config))())
             }
         },
     )
 }
-fn wire_get_all_track_ids_sorted_by_duration_impl(
+fn wire_fuzzy_search_tracks_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -202,7 +269,7 @@ fn wire_get_all_track_ids_sorted_by_duration_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_all_track_ids_sorted_by_duration",
+            debug_name: "fuzzy_search_tracks",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -216,16 +283,23 @@ fn wire_get_all_track_ids_sorted_by_duration_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
+            let api_hide_offline = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    Result::<_, ()>::Ok(crate::api::simple::get_all_track_ids_sorted_by_duration())
+                    Result::<_, ()>::Ok(crate::api::fuzzy_search::fuzzy_search_tracks(
+                        api_query,
+                        api_limit,
+                        api_hide_offline,
+                    ))
                 })())
             }
         },
     )
 }
-fn wire_get_all_track_ids_sorted_by_title_impl(
+fn wire_add_library_root_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -233,7 +307,7 @@ fn wire_get_all_track_ids_sorted_by_title_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_all_track_ids_sorted_by_title",
+            debug_name: "add_library_root",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -247,16 +321,15 @@ fn wire_get_all_track_ids_sorted_by_title_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse((move || {
-                    Result::<_, ()>::Ok(crate::api::simple::get_all_track_ids_sorted_by_title())
-                })())
+                transform_result_sse((move || crate::api::library::add_library_root(api_path))())
             }
         },
     )
 }
-fn wire_get_all_tracks_impl(
+fn wire_get_library_root_availability_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -264,7 +337,7 @@ fn wire_get_all_tracks_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_all_tracks",
+            debug_name: "get_library_root_availability",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -281,22 +354,23 @@ fn wire_get_all_tracks_impl(
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    Result::<_, ()>::Ok(crate::api::simple::get_all_tracks())
+                    Result::<_, ()>::Ok(crate::api::library::get_library_root_availability())
                 })())
             }
         },
     )
 }
-fn wire_get_cache_path_impl(
+fn wire_get_library_roots_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_cache_path",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "get_library_roots",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -309,22 +383,25 @@ fn wire_get_cache_path_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            transform_result_sse((move || {
-                Result::<_, ()>::Ok(crate::api::simple::get_cache_path())
-            })())
+            move |context| {
+                transform_result_sse((move || {
+                    Result::<_, ()>::Ok(crate::api::library::get_library_roots())
+                })())
+            }
         },
     )
 }
-fn wire_get_config_path_impl(
+fn wire_relocate_library_root_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_config_path",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "relocate_library_root",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -336,23 +413,28 @@ fn wire_get_config_path_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <i32>::sse_decode(&mut deserializer);
+            let api_new_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse((move || {
-                Result::<_, ()>::Ok(crate::api::simple::get_config_path())
-            })())
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::library::relocate_library_root(api_id, api_new_path)
+                })())
+            }
         },
     )
 }
-fn wire_get_data_path_impl(
+fn wire_remove_library_root_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_data_path",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "remove_library_root",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -364,23 +446,25 @@ fn wire_get_data_path_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <i32>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse((move || {
-                Result::<_, ()>::Ok(crate::api::simple::get_data_path())
-            })())
+            move |context| {
+                transform_result_sse((move || crate::api::library::remove_library_root(api_id))())
+            }
         },
     )
 }
-fn wire_get_db_url_impl(
+fn wire_rescan_library_root_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_db_url",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "rescan_library_root",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -392,23 +476,25 @@ fn wire_get_db_url_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <i32>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse((move || {
-                Result::<_, ()>::Ok(crate::api::simple::get_db_url())
-            })())
+            move |context| {
+                transform_result_sse((move || crate::api::library::rescan_library_root(api_id))())
+            }
         },
     )
 }
-fn wire_initialize_app_impl(
+fn wire_rescan_library_roots_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "initialize_app",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "rescan_library_roots",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -421,22 +507,25 @@ fn wire_initialize_app_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            transform_result_sse((move || {
-                Result::<_, ()>::Ok(crate::api::simple::initialize_app())
-            })())
+            move |context| {
+                transform_result_sse((move || {
+                    Result::<_, ()>::Ok(crate::api::library::rescan_library_roots())
+                })())
+            }
         },
     )
 }
-fn wire_initialize_db_impl(
+fn wire_set_library_root_enabled_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "initialize_db",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "set_library_root_enabled",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -448,14 +537,21 @@ fn wire_initialize_db_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <i32>::sse_decode(&mut deserializer);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse((move || {
-                Result::<_, ()>::Ok(crate::api::simple::initialize_db())
-            })())
+            move |context| {
+                transform_result_sse((move || {
+                    Result::<_, ()>::Ok(crate::api::library::set_library_root_enabled(
+                        api_id,
+                        api_enabled,
+                    ))
+                })())
+            }
         },
     )
 }
-fn wire_pick_directory_impl(
+fn wire_query_track_ids_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -463,7 +559,7 @@ fn wire_pick_directory_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "pick_directory",
+            debug_name: "query_track_ids",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -477,16 +573,17 @@ fn wire_pick_directory_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query = <crate::api::query::TrackQuery>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    Result::<_, ()>::Ok(crate::api::simple::pick_directory())
+                    Result::<_, ()>::Ok(crate::api::query::query_track_ids(api_query))
                 })())
             }
         },
     )
 }
-fn wire_sync_directory_impl(
+fn wire_query_tracks_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -494,7 +591,7 @@ fn wire_sync_directory_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "sync_directory",
+            debug_name: "query_tracks",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -508,24 +605,24 @@ fn wire_sync_directory_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_mount_point = <String>::sse_decode(&mut deserializer);
+            let api_query = <crate::api::query::TrackQuery>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    Result::<_, ()>::Ok(crate::api::simple::sync_directory(api_mount_point))
+                    Result::<_, ()>::Ok(crate::api::query::query_tracks(api_query))
                 })())
             }
         },
     )
 }
-fn wire_duration_to_string_impl(
+fn wire_parse_track_filters_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "duration_to_string",
+            debug_name: "parse_track_filters",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -539,22 +636,22 @@ fn wire_duration_to_string_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_duration = <chrono::Duration>::sse_decode(&mut deserializer);
+            let api_query = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse((move || {
-                Result::<_, ()>::Ok(crate::api::utils::duration_to_string(api_duration))
+                crate::api::query_language::parse_track_filters(api_query)
             })())
         },
     )
 }
-fn wire_track_query_filter_condition_impl(
+fn wire_ScanCancelToken_cancel_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "track_query_filter_condition",
+            debug_name: "ScanCancelToken_cancel",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -568,404 +665,3748 @@ fn wire_track_query_filter_condition_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_query = <String>::sse_decode(&mut deserializer);
-            let api_track = <crate::api::simple::TrackDTO>::sse_decode(&mut deserializer);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::rust_async::RwLock<ScanCancelToken>,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse((move || {
-                Result::<_, ()>::Ok(crate::api::utils::track_query_filter_condition(
-                    api_query, api_track,
-                ))
+                let api_that = api_that.rust_auto_opaque_decode_ref();
+                Result::<_, ()>::Ok(crate::api::scan::ScanCancelToken::cancel(&api_that))
             })())
         },
     )
 }
-
-// Section: dart2rust
-
-impl SseDecode for chrono::Duration {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i64>::sse_decode(deserializer);
-        return chrono::Duration::microseconds(inner);
-    }
-}
-
-impl SseDecode for chrono::NaiveDateTime {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i64>::sse_decode(deserializer);
-        return chrono::NaiveDateTime::from_timestamp_micros(inner)
-            .expect("invalid or out-of-range datetime");
-    }
-}
-
-impl SseDecode for String {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
-    }
+fn wire_ScanCancelToken_is_cancelled_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "ScanCancelToken_is_cancelled",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::rust_async::RwLock<ScanCancelToken>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || {
+                let api_that = api_that.rust_auto_opaque_decode_ref();
+                Result::<_, ()>::Ok(crate::api::scan::ScanCancelToken::is_cancelled(&api_that))
+            })())
+        },
+    )
 }
-
-impl SseDecode for crate::model::Album {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <i32>::sse_decode(deserializer);
+fn wire_ScanCancelToken_new_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "ScanCancelToken_new",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse((move || {
+                Result::<_, ()>::Ok(crate::api::scan::ScanCancelToken::new())
+            })())
+        },
+    )
+}
+fn wire_clear_scan_errors_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "clear_scan_errors",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    Result::<_, ()>::Ok(crate::api::scan::clear_scan_errors())
+                })())
+            }
+        },
+    )
+}
+fn wire_get_scan_errors_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_scan_errors",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    Result::<_, ()>::Ok(crate::api::scan::get_scan_errors())
+                })())
+            }
+        },
+    )
+}
+fn wire_scan_directory_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "scan_directory",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Stream,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_mount_point = <String>::sse_decode(&mut deserializer);
+            let api_cancel_token = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::rust_async::RwLock<ScanCancelToken>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    let api_cancel_token = api_cancel_token.rust_auto_opaque_decode_ref();
+                    crate::api::scan::scan_directory(
+                        api_mount_point,
+                        &api_cancel_token,
+                        StreamSink::new(
+                            context
+                                .rust2dart_context()
+                                .stream_sink::<_, crate::api::scan::ScanEvent>(),
+                        ),
+                    )
+                })())
+            }
+        },
+    )
+}
+fn wire_search_tracks_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_tracks",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    Result::<_, ()>::Ok(crate::api::search::search_tracks(api_query, api_limit))
+                })())
+            }
+        },
+    )
+}
+fn wire_delete_all_tracks_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "delete_all_tracks",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    Result::<_, ()>::Ok(crate::api::simple::delete_all_tracks())
+                })())
+            }
+        },
+    )
+}
+fn wire_find_album_by_genre_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "find_album_by_genre",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_genre_id = <i32>::sse_decode(&mut deserializer);
+            let api_hide_offline = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    Result::<_, ()>::Ok(crate::api::simple::find_album_by_genre(
+                        api_genre_id,
+                        api_hide_offline,
+                    ))
+                })())
+            }
+        },
+    )
+}
+fn wire_find_track_by_album_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "find_track_by_album",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_album_id = <i32>::sse_decode(&mut deserializer);
+            let api_hide_offline = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    Result::<_, ()>::Ok(crate::api::simple::find_track_by_album(
+                        api_album_id,
+                        api_hide_offline,
+                    ))
+                })())
+            }
+        },
+    )
+}
+fn wire_find_track_by_artist_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "find_track_by_artist",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_artist_id = <i32>::sse_decode(&mut deserializer);
+            let api_hide_offline = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    Result::<_, ()>::Ok(crate::api::simple::find_track_by_artist(
+                        api_artist_id,
+                        api_hide_offline,
+                    ))
+                })())
+            }
+        },
+    )
+}
+fn wire_find_track_by_genre_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "find_track_by_genre",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_genre_id = <i32>::sse_decode(&mut deserializer);
+            let api_hide_offline = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    Result::<_, ()>::Ok(crate::api::simple::find_track_by_genre(
+                        api_genre_id,
+                        api_hide_offline,
+                    ))
+                })())
+            }
+        },
+    )
+}
+fn wire_find_track_by_year_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "find_track_by_year",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_year = <i32>::sse_decode(&mut deserializer);
+            let api_hide_offline = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    Result::<_, ()>::Ok(crate::api::simple::find_track_by_year(
+                        api_year,
+                        api_hide_offline,
+                    ))
+                })())
+            }
+        },
+    )
+}
+fn wire_frb_init_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "frb_init",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse(
+                    (move || Result::<_, ()>::Ok(crate::api::simple::frb_init()))(),
+                )
+            }
+        },
+    )
+}
+fn wire_get_all_genres_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_all_genres",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_hide_offline = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    Result::<_, ()>::Ok(crate::api::simple::get_all_genres(api_hide_offline))
+                })())
+            }
+        },
+    )
+}
+fn wire_get_all_track_ids_sorted_by_album_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_all_track_ids_sorted_by_album",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_hide_offline = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    Result::<_, ()>::Ok(crate::api::simple::get_all_track_ids_sorted_by_album(
+                        api_hide_offline,
+                    ))
+                })())
+            }
+        },
+    )
+}
+fn wire_get_all_track_ids_sorted_by_artist_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_all_track_ids_sorted_by_artist",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_hide_offline = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    Result::<_, ()>::Ok(crate::api::simple::get_all_track_ids_sorted_by_artist(
+                        api_hide_offline,
+                    ))
+                })())
+            }
+        },
+    )
+}
+fn wire_get_all_track_ids_sorted_by_duration_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_all_track_ids_sorted_by_duration",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_hide_offline = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    Result::<_, ()>::Ok(crate::api::simple::get_all_track_ids_sorted_by_duration(
+                        api_hide_offline,
+                    ))
+                })())
+            }
+        },
+    )
+}
+fn wire_get_all_track_ids_sorted_by_title_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_all_track_ids_sorted_by_title",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_hide_offline = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    Result::<_, ()>::Ok(crate::api::simple::get_all_track_ids_sorted_by_title(
+                        api_hide_offline,
+                    ))
+                })())
+            }
+        },
+    )
+}
+fn wire_get_all_tracks_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_all_tracks",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_hide_offline = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    Result::<_, ()>::Ok(crate::api::simple::get_all_tracks(api_hide_offline))
+                })())
+            }
+        },
+    )
+}
+fn wire_get_all_years_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_all_years",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_hide_offline = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    Result::<_, ()>::Ok(crate::api::simple::get_all_years(api_hide_offline))
+                })())
+            }
+        },
+    )
+}
+fn wire_get_cache_path_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_cache_path",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse((move || {
+                Result::<_, ()>::Ok(crate::api::simple::get_cache_path())
+            })())
+        },
+    )
+}
+fn wire_get_config_path_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_config_path",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse((move || {
+                Result::<_, ()>::Ok(crate::api::simple::get_config_path())
+            })())
+        },
+    )
+}
+fn wire_get_data_path_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_data_path",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse((move || {
+                Result::<_, ()>::Ok(crate::api::simple::get_data_path())
+            })())
+        },
+    )
+}
+fn wire_get_db_url_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_db_url",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse((move || {
+                Result::<_, ()>::Ok(crate::api::simple::get_db_url())
+            })())
+        },
+    )
+}
+fn wire_get_track_ids_by_audio_properties_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_track_ids_by_audio_properties",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_filter =
+                <crate::api::simple::AudioPropertiesFilter>::sse_decode(&mut deserializer);
+            let api_sort_by = <crate::api::simple::AudioProperty>::sse_decode(&mut deserializer);
+            let api_descending = <bool>::sse_decode(&mut deserializer);
+            let api_hide_offline = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    Result::<_, ()>::Ok(crate::api::simple::get_track_ids_by_audio_properties(
+                        api_filter,
+                        api_sort_by,
+                        api_descending,
+                        api_hide_offline,
+                    ))
+                })())
+            }
+        },
+    )
+}
+fn wire_get_track_page_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_track_page",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_offset = <u32>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
+            let api_hide_offline = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    Result::<_, ()>::Ok(crate::api::simple::get_track_page(
+                        api_offset,
+                        api_limit,
+                        api_hide_offline,
+                    ))
+                })())
+            }
+        },
+    )
+}
+fn wire_get_tracks_by_ids_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_tracks_by_ids",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_ids = <Vec<i32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    Result::<_, ()>::Ok(crate::api::simple::get_tracks_by_ids(api_ids))
+                })())
+            }
+        },
+    )
+}
+fn wire_initialize_app_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "initialize_app",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse((move || {
+                Result::<_, ()>::Ok(crate::api::simple::initialize_app())
+            })())
+        },
+    )
+}
+fn wire_initialize_db_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "initialize_db",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse((move || {
+                Result::<_, ()>::Ok(crate::api::simple::initialize_db())
+            })())
+        },
+    )
+}
+fn wire_pick_directory_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "pick_directory",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    Result::<_, ()>::Ok(crate::api::simple::pick_directory())
+                })())
+            }
+        },
+    )
+}
+fn wire_record_track_played_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "record_track_played",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_track_id = <i32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    Result::<_, ()>::Ok(crate::api::simple::record_track_played(api_track_id))
+                })())
+            }
+        },
+    )
+}
+fn wire_sync_directory_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "sync_directory",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_mount_point = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::simple::sync_directory(api_mount_point)
+                })())
+            }
+        },
+    )
+}
+fn wire_duration_to_string_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "duration_to_string",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_duration = <chrono::Duration>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || {
+                Result::<_, ()>::Ok(crate::api::utils::duration_to_string(api_duration))
+            })())
+        },
+    )
+}
+fn wire_track_query_filter_condition_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "track_query_filter_condition",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            let api_track = <crate::api::simple::TrackDTO>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || {
+                Result::<_, ()>::Ok(crate::api::utils::track_query_filter_condition(
+                    api_query, api_track,
+                ))
+            })())
+        },
+    )
+}
+fn wire_unwatch_library_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "unwatch_library",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    Result::<_, ()>::Ok(crate::api::watcher::unwatch_library())
+                })())
+            }
+        },
+    )
+}
+fn wire_watch_library_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "watch_library",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Stream,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::watcher::watch_library(StreamSink::new(
+                        context
+                            .rust2dart_context()
+                            .stream_sink::<_, crate::api::watcher::LibraryChangeEvent>(),
+                    ))
+                })())
+            }
+        },
+    )
+}
+
+// Section: related_funcs
+
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::rust_async::RwLock<ScanCancelToken>
+);
+
+// Section: dart2rust
+
+impl SseDecode for ScanCancelToken {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::rust_async::RwLock<ScanCancelToken>,
+        >>::sse_decode(deserializer);
+        return inner.rust_auto_opaque_decode_owned();
+    }
+}
+
+impl SseDecode for chrono::Duration {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i64>::sse_decode(deserializer);
+        return chrono::Duration::microseconds(inner);
+    }
+}
+
+impl SseDecode for chrono::NaiveDateTime {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i64>::sse_decode(deserializer);
+        return chrono::NaiveDateTime::from_timestamp_micros(inner)
+            .expect("invalid or out-of-range datetime");
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::rust_async::RwLock<ScanCancelToken>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for crate::model::Album {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i32>::sse_decode(deserializer);
         let mut var_name = <String>::sse_decode(deserializer);
         let mut var_artistId = <Option<i32>>::sse_decode(deserializer);
         let mut var_createdAt = <chrono::NaiveDateTime>::sse_decode(deserializer);
+        let mut var_musicbrainzReleaseId = <Option<String>>::sse_decode(deserializer);
+        let mut var_releaseType = <Option<String>>::sse_decode(deserializer);
+        let mut var_nameRomaji = <Option<String>>::sse_decode(deserializer);
+        let mut var_nameHiragana = <Option<String>>::sse_decode(deserializer);
         return crate::model::Album {
             id: var_id,
-            name: var_name,
-            artist_id: var_artistId,
-            created_at: var_createdAt,
+            name: var_name,
+            artist_id: var_artistId,
+            created_at: var_createdAt,
+            musicbrainz_release_id: var_musicbrainzReleaseId,
+            release_type: var_releaseType,
+            name_romaji: var_nameRomaji,
+            name_hiragana: var_nameHiragana,
+        };
+    }
+}
+
+impl SseDecode for crate::api::browse::AlbumDetail {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_album = <crate::api::browse::AlbumSummary>::sse_decode(deserializer);
+        let mut var_discs = <Vec<crate::api::browse::DiscGroup>>::sse_decode(deserializer);
+        return crate::api::browse::AlbumDetail {
+            album: var_album,
+            discs: var_discs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::browse::AlbumSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i32>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_artist = <Option<crate::model::Artist>>::sse_decode(deserializer);
+        let mut var_releaseType = <Option<String>>::sse_decode(deserializer);
+        let mut var_year = <Option<i32>>::sse_decode(deserializer);
+        let mut var_trackCount = <i32>::sse_decode(deserializer);
+        let mut var_durationMs = <i64>::sse_decode(deserializer);
+        let mut var_pictureId = <Option<String>>::sse_decode(deserializer);
+        return crate::api::browse::AlbumSummary {
+            id: var_id,
+            name: var_name,
+            artist: var_artist,
+            release_type: var_releaseType,
+            year: var_year,
+            track_count: var_trackCount,
+            duration_ms: var_durationMs,
+            picture_id: var_pictureId,
+        };
+    }
+}
+
+impl SseDecode for crate::model::Artist {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i32>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_createdAt = <chrono::NaiveDateTime>::sse_decode(deserializer);
+        let mut var_nameRomaji = <Option<String>>::sse_decode(deserializer);
+        let mut var_nameHiragana = <Option<String>>::sse_decode(deserializer);
+        return crate::model::Artist {
+            id: var_id,
+            name: var_name,
+            created_at: var_createdAt,
+            name_romaji: var_nameRomaji,
+            name_hiragana: var_nameHiragana,
+        };
+    }
+}
+
+impl SseDecode for crate::api::browse::ArtistDetail {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_artist = <crate::api::browse::ArtistSummary>::sse_decode(deserializer);
+        let mut var_releaseGroups =
+            <Vec<crate::api::browse::ReleaseGroup>>::sse_decode(deserializer);
+        let mut var_appearsOn = <Vec<crate::api::browse::AlbumSummary>>::sse_decode(deserializer);
+        return crate::api::browse::ArtistDetail {
+            artist: var_artist,
+            release_groups: var_releaseGroups,
+            appears_on: var_appearsOn,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::ArtistRole {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::simple::ArtistRole::Main,
+            1 => crate::api::simple::ArtistRole::Featured,
+            2 => crate::api::simple::ArtistRole::Remixer,
+            3 => crate::api::simple::ArtistRole::Composer,
+            _ => unreachable!("Invalid variant for ArtistRole: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::browse::ArtistSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i32>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_trackCount = <i32>::sse_decode(deserializer);
+        let mut var_albumCount = <i32>::sse_decode(deserializer);
+        let mut var_durationMs = <i64>::sse_decode(deserializer);
+        let mut var_pictureId = <Option<String>>::sse_decode(deserializer);
+        return crate::api::browse::ArtistSummary {
+            id: var_id,
+            name: var_name,
+            track_count: var_trackCount,
+            album_count: var_albumCount,
+            duration_ms: var_durationMs,
+            picture_id: var_pictureId,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::AudioPropertiesFilter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_codec = <Option<String>>::sse_decode(deserializer);
+        let mut var_lossless = <Option<bool>>::sse_decode(deserializer);
+        let mut var_minBitrate = <Option<i32>>::sse_decode(deserializer);
+        let mut var_maxBitrate = <Option<i32>>::sse_decode(deserializer);
+        let mut var_minSampleRate = <Option<i32>>::sse_decode(deserializer);
+        let mut var_minBitDepth = <Option<i32>>::sse_decode(deserializer);
+        let mut var_channels = <Option<i32>>::sse_decode(deserializer);
+        return crate::api::simple::AudioPropertiesFilter {
+            codec: var_codec,
+            lossless: var_lossless,
+            min_bitrate: var_minBitrate,
+            max_bitrate: var_maxBitrate,
+            min_sample_rate: var_minSampleRate,
+            min_bit_depth: var_minBitDepth,
+            channels: var_channels,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::AudioProperty {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::simple::AudioProperty::Bitrate,
+            1 => crate::api::simple::AudioProperty::SampleRate,
+            2 => crate::api::simple::AudioProperty::BitDepth,
+            3 => crate::api::simple::AudioProperty::Channels,
+            4 => crate::api::simple::AudioProperty::FileSize,
+            _ => unreachable!("Invalid variant for AudioProperty: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for Box<crate::api::query::TrackFilter> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        return Box::new(<crate::api::query::TrackFilter>::sse_decode(deserializer));
+    }
+}
+
+impl SseDecode for crate::api::config::Config {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_extensions = <Vec<String>>::sse_decode(deserializer);
+        let mut var_detectByContent = <bool>::sse_decode(deserializer);
+        let mut var_sniffedExtensions = <Vec<String>>::sse_decode(deserializer);
+        let mut var_artistSeparators = <Vec<String>>::sse_decode(deserializer);
+        let mut var_genreSeparators = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::config::Config {
+            extensions: var_extensions,
+            detect_by_content: var_detectByContent,
+            sniffed_extensions: var_sniffedExtensions,
+            artist_separators: var_artistSeparators,
+            genre_separators: var_genreSeparators,
+        };
+    }
+}
+
+impl SseDecode for crate::api::browse::DiscGroup {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_disc = <Option<i32>>::sse_decode(deserializer);
+        let mut var_subtitle = <Option<String>>::sse_decode(deserializer);
+        let mut var_tracks = <Vec<crate::api::simple::TrackDTO>>::sse_decode(deserializer);
+        return crate::api::browse::DiscGroup {
+            disc: var_disc,
+            subtitle: var_subtitle,
+            tracks: var_tracks,
+        };
+    }
+}
+
+impl SseDecode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::model::Genre {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i32>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_createdAt = <chrono::NaiveDateTime>::sse_decode(deserializer);
+        return crate::model::Genre {
+            id: var_id,
+            name: var_name,
+            created_at: var_createdAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::GenreSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i32>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_trackCount = <i32>::sse_decode(deserializer);
+        let mut var_albumCount = <i32>::sse_decode(deserializer);
+        return crate::api::simple::GenreSummary {
+            id: var_id,
+            name: var_name,
+            track_count: var_trackCount,
+            album_count: var_albumCount,
+        };
+    }
+}
+
+impl SseDecode for crate::api::fuzzy_search::HighlightRange {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_start = <u32>::sse_decode(deserializer);
+        let mut var_end = <u32>::sse_decode(deserializer);
+        return crate::api::fuzzy_search::HighlightRange {
+            start: var_start,
+            end: var_end,
+        };
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::watcher::LibraryChangeEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_upserted = <Vec<String>>::sse_decode(deserializer);
+        let mut var_removed = <Vec<String>>::sse_decode(deserializer);
+        let mut var_moved = <Vec<crate::api::watcher::MovedTrack>>::sse_decode(deserializer);
+        return crate::api::watcher::LibraryChangeEvent {
+            upserted: var_upserted,
+            removed: var_removed,
+            moved: var_moved,
+        };
+    }
+}
+
+impl SseDecode for crate::model::LibraryRoot {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i32>::sse_decode(deserializer);
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_enabled = <bool>::sse_decode(deserializer);
+        let mut var_trackCount = <i32>::sse_decode(deserializer);
+        let mut var_lastScannedAt = <Option<chrono::NaiveDateTime>>::sse_decode(deserializer);
+        let mut var_createdAt = <chrono::NaiveDateTime>::sse_decode(deserializer);
+        return crate::model::LibraryRoot {
+            id: var_id,
+            path: var_path,
+            enabled: var_enabled,
+            track_count: var_trackCount,
+            last_scanned_at: var_lastScannedAt,
+            created_at: var_createdAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::library::LibraryRootAvailability {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i32>::sse_decode(deserializer);
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_available = <bool>::sse_decode(deserializer);
+        return crate::api::library::LibraryRootAvailability {
+            id: var_id,
+            path: var_path,
+            available: var_available,
+        };
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::model::Album> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::model::Album>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::browse::AlbumSummary> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::browse::AlbumSummary>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::browse::ArtistSummary> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::browse::ArtistSummary>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::browse::DiscGroup> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::browse::DiscGroup>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::model::Genre> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::model::Genre>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::simple::GenreSummary> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::simple::GenreSummary>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::fuzzy_search::HighlightRange> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::fuzzy_search::HighlightRange>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::model::LibraryRoot> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::model::LibraryRoot>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::library::LibraryRootAvailability> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::library::LibraryRootAvailability>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::watcher::MovedTrack> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::watcher::MovedTrack>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<i32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<i32>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::browse::ReleaseGroup> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::browse::ReleaseGroup>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::model::ScanError> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::model::ScanError>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::query::SortKey> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::query::SortKey>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::scan::SyncSummary> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::scan::SyncSummary>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::simple::TrackArtistDTO> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::simple::TrackArtistDTO>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::simple::TrackDTO> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::simple::TrackDTO>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::query::TrackFilter> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::query::TrackFilter>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::fuzzy_search::TrackSearchResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::fuzzy_search::TrackSearchResult>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::api::watcher::MovedTrack {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_from = <String>::sse_decode(deserializer);
+        let mut var_to = <String>::sse_decode(deserializer);
+        return crate::api::watcher::MovedTrack {
+            from: var_from,
+            to: var_to,
+        };
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<chrono::NaiveDateTime> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<chrono::NaiveDateTime>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::model::Album> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::model::Album>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::browse::AlbumDetail> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::browse::AlbumDetail>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::model::Artist> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::model::Artist>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::browse::ArtistDetail> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::browse::ArtistDetail>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<bool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<bool>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<f64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<i32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<i32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::api::query_language::QueryParseError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_start = <u32>::sse_decode(deserializer);
+        let mut var_end = <u32>::sse_decode(deserializer);
+        return crate::api::query_language::QueryParseError {
+            message: var_message,
+            start: var_start,
+            end: var_end,
+        };
+    }
+}
+
+impl SseDecode for crate::api::browse::ReleaseGroup {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_releaseType = <Option<String>>::sse_decode(deserializer);
+        let mut var_albums = <Vec<crate::api::browse::AlbumSummary>>::sse_decode(deserializer);
+        return crate::api::browse::ReleaseGroup {
+            release_type: var_releaseType,
+            albums: var_albums,
+        };
+    }
+}
+
+impl SseDecode for crate::model::ScanError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i32>::sse_decode(deserializer);
+        let mut var_location = <String>::sse_decode(deserializer);
+        let mut var_mountPoint = <String>::sse_decode(deserializer);
+        let mut var_kind = <String>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_occurredAt = <chrono::NaiveDateTime>::sse_decode(deserializer);
+        return crate::model::ScanError {
+            id: var_id,
+            location: var_location,
+            mount_point: var_mountPoint,
+            kind: var_kind,
+            message: var_message,
+            occurred_at: var_occurredAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::scan::ScanEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 = <crate::api::scan::ScanProgress>::sse_decode(deserializer);
+                return crate::api::scan::ScanEvent::Progress(var_field0);
+            }
+            1 => {
+                let mut var_path = <String>::sse_decode(deserializer);
+                let mut var_kind = <String>::sse_decode(deserializer);
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::api::scan::ScanEvent::Error {
+                    path: var_path,
+                    kind: var_kind,
+                    message: var_message,
+                };
+            }
+            2 => {
+                let mut var_field0 = <crate::api::scan::SyncSummary>::sse_decode(deserializer);
+                return crate::api::scan::ScanEvent::Finished(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::scan::ScanProgress {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_discovered = <u32>::sse_decode(deserializer);
+        let mut var_parsed = <u32>::sse_decode(deserializer);
+        let mut var_written = <u32>::sse_decode(deserializer);
+        let mut var_currentPath = <String>::sse_decode(deserializer);
+        return crate::api::scan::ScanProgress {
+            discovered: var_discovered,
+            parsed: var_parsed,
+            written: var_written,
+            current_path: var_currentPath,
+        };
+    }
+}
+
+impl SseDecode for crate::api::query::SortField {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::query::SortField::Title,
+            1 => crate::api::query::SortField::Artist,
+            2 => crate::api::query::SortField::AlbumArtist,
+            3 => crate::api::query::SortField::Album,
+            4 => crate::api::query::SortField::TrackNumber,
+            5 => crate::api::query::SortField::Duration,
+            6 => crate::api::query::SortField::Year,
+            7 => crate::api::query::SortField::DateAdded,
+            8 => crate::api::query::SortField::Bitrate,
+            9 => crate::api::query::SortField::SampleRate,
+            10 => crate::api::query::SortField::BitDepth,
+            11 => crate::api::query::SortField::Channels,
+            12 => crate::api::query::SortField::FileSize,
+            13 => crate::api::query::SortField::PlayCount,
+            14 => crate::api::query::SortField::LastPlayed,
+            _ => unreachable!("Invalid variant for SortField: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::query::SortKey {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field = <crate::api::query::SortField>::sse_decode(deserializer);
+        let mut var_descending = <bool>::sse_decode(deserializer);
+        return crate::api::query::SortKey {
+            field: var_field,
+            descending: var_descending,
+        };
+    }
+}
+
+impl SseDecode for crate::api::scan::SyncSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_added = <u32>::sse_decode(deserializer);
+        let mut var_updated = <u32>::sse_decode(deserializer);
+        let mut var_unchanged = <u32>::sse_decode(deserializer);
+        let mut var_failed = <u32>::sse_decode(deserializer);
+        let mut var_removed = <u32>::sse_decode(deserializer);
+        let mut var_cancelled = <bool>::sse_decode(deserializer);
+        return crate::api::scan::SyncSummary {
+            added: var_added,
+            updated: var_updated,
+            unchanged: var_unchanged,
+            failed: var_failed,
+            removed: var_removed,
+            cancelled: var_cancelled,
+        };
+    }
+}
+
+impl SseDecode for crate::api::query::TextField {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::query::TextField::Any,
+            1 => crate::api::query::TextField::Title,
+            2 => crate::api::query::TextField::Artist,
+            3 => crate::api::query::TextField::Album,
+            4 => crate::api::query::TextField::Genre,
+            5 => crate::api::query::TextField::Composer,
+            _ => unreachable!("Invalid variant for TextField: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::TrackArtistDTO {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_artist = <crate::model::Artist>::sse_decode(deserializer);
+        let mut var_role = <crate::api::simple::ArtistRole>::sse_decode(deserializer);
+        return crate::api::simple::TrackArtistDTO {
+            artist: var_artist,
+            role: var_role,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::TrackDTO {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i32>::sse_decode(deserializer);
+        let mut var_title = <Option<String>>::sse_decode(deserializer);
+        let mut var_titleRomaji = <Option<String>>::sse_decode(deserializer);
+        let mut var_titleHiragana = <Option<String>>::sse_decode(deserializer);
+        let mut var_artist = <Option<crate::model::Artist>>::sse_decode(deserializer);
+        let mut var_album = <Option<crate::model::Album>>::sse_decode(deserializer);
+        let mut var_albumArtist = <Option<crate::model::Artist>>::sse_decode(deserializer);
+        let mut var_artists = <Vec<crate::api::simple::TrackArtistDTO>>::sse_decode(deserializer);
+        let mut var_number = <Option<i32>>::sse_decode(deserializer);
+        let mut var_disc = <Option<i32>>::sse_decode(deserializer);
+        let mut var_durationMs = <i32>::sse_decode(deserializer);
+        let mut var_location = <String>::sse_decode(deserializer);
+        let mut var_mountPoint = <String>::sse_decode(deserializer);
+        let mut var_pictureId = <Option<String>>::sse_decode(deserializer);
+        let mut var_available = <bool>::sse_decode(deserializer);
+        let mut var_year = <Option<i32>>::sse_decode(deserializer);
+        let mut var_date = <Option<String>>::sse_decode(deserializer);
+        let mut var_originalDate = <Option<String>>::sse_decode(deserializer);
+        let mut var_genre = <Option<String>>::sse_decode(deserializer);
+        let mut var_genres = <Vec<crate::model::Genre>>::sse_decode(deserializer);
+        let mut var_composer = <Option<String>>::sse_decode(deserializer);
+        let mut var_comment = <Option<String>>::sse_decode(deserializer);
+        let mut var_trackTotal = <Option<i32>>::sse_decode(deserializer);
+        let mut var_discTotal = <Option<i32>>::sse_decode(deserializer);
+        let mut var_bpm = <Option<f64>>::sse_decode(deserializer);
+        let mut var_isrc = <Option<String>>::sse_decode(deserializer);
+        let mut var_label = <Option<String>>::sse_decode(deserializer);
+        let mut var_codec = <Option<String>>::sse_decode(deserializer);
+        let mut var_bitrate = <Option<i32>>::sse_decode(deserializer);
+        let mut var_sampleRate = <Option<i32>>::sse_decode(deserializer);
+        let mut var_bitDepth = <Option<i32>>::sse_decode(deserializer);
+        let mut var_channels = <Option<i32>>::sse_decode(deserializer);
+        let mut var_fileSize = <i64>::sse_decode(deserializer);
+        let mut var_lossless = <bool>::sse_decode(deserializer);
+        let mut var_discSubtitle = <Option<String>>::sse_decode(deserializer);
+        let mut var_playCount = <i32>::sse_decode(deserializer);
+        let mut var_lastPlayedAt = <Option<chrono::NaiveDateTime>>::sse_decode(deserializer);
+        return crate::api::simple::TrackDTO {
+            id: var_id,
+            title: var_title,
+            title_romaji: var_titleRomaji,
+            title_hiragana: var_titleHiragana,
+            artist: var_artist,
+            album: var_album,
+            album_artist: var_albumArtist,
+            artists: var_artists,
+            number: var_number,
+            disc: var_disc,
+            duration_ms: var_durationMs,
+            location: var_location,
+            mount_point: var_mountPoint,
+            picture_id: var_pictureId,
+            available: var_available,
+            year: var_year,
+            date: var_date,
+            original_date: var_originalDate,
+            genre: var_genre,
+            genres: var_genres,
+            composer: var_composer,
+            comment: var_comment,
+            track_total: var_trackTotal,
+            disc_total: var_discTotal,
+            bpm: var_bpm,
+            isrc: var_isrc,
+            label: var_label,
+            codec: var_codec,
+            bitrate: var_bitrate,
+            sample_rate: var_sampleRate,
+            bit_depth: var_bitDepth,
+            channels: var_channels,
+            file_size: var_fileSize,
+            lossless: var_lossless,
+            disc_subtitle: var_discSubtitle,
+            play_count: var_playCount,
+            last_played_at: var_lastPlayedAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::query::TrackFilter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 = <i32>::sse_decode(deserializer);
+                return crate::api::query::TrackFilter::Artist(var_field0);
+            }
+            1 => {
+                let mut var_field0 = <i32>::sse_decode(deserializer);
+                return crate::api::query::TrackFilter::Album(var_field0);
+            }
+            2 => {
+                let mut var_field0 = <i32>::sse_decode(deserializer);
+                return crate::api::query::TrackFilter::Genre(var_field0);
+            }
+            3 => {
+                let mut var_min = <Option<i32>>::sse_decode(deserializer);
+                let mut var_max = <Option<i32>>::sse_decode(deserializer);
+                return crate::api::query::TrackFilter::Year {
+                    min: var_min,
+                    max: var_max,
+                };
+            }
+            4 => {
+                let mut var_min = <Option<i32>>::sse_decode(deserializer);
+                let mut var_max = <Option<i32>>::sse_decode(deserializer);
+                return crate::api::query::TrackFilter::DurationMs {
+                    min: var_min,
+                    max: var_max,
+                };
+            }
+            5 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::query::TrackFilter::Codec(var_field0);
+            }
+            6 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::query::TrackFilter::FileExtension(var_field0);
+            }
+            7 => {
+                let mut var_field0 = <bool>::sse_decode(deserializer);
+                return crate::api::query::TrackFilter::Lossless(var_field0);
+            }
+            8 => {
+                let mut var_min = <Option<i32>>::sse_decode(deserializer);
+                let mut var_max = <Option<i32>>::sse_decode(deserializer);
+                return crate::api::query::TrackFilter::Bitrate {
+                    min: var_min,
+                    max: var_max,
+                };
+            }
+            9 => {
+                let mut var_field0 = <i32>::sse_decode(deserializer);
+                return crate::api::query::TrackFilter::MinSampleRate(var_field0);
+            }
+            10 => {
+                let mut var_field0 = <i32>::sse_decode(deserializer);
+                return crate::api::query::TrackFilter::MinBitDepth(var_field0);
+            }
+            11 => {
+                let mut var_field0 = <i32>::sse_decode(deserializer);
+                return crate::api::query::TrackFilter::Channels(var_field0);
+            }
+            12 => {
+                let mut var_field0 = <i32>::sse_decode(deserializer);
+                return crate::api::query::TrackFilter::LibraryRoot(var_field0);
+            }
+            13 => {
+                let mut var_field = <crate::api::query::TextField>::sse_decode(deserializer);
+                let mut var_text = <String>::sse_decode(deserializer);
+                return crate::api::query::TrackFilter::Text {
+                    field: var_field,
+                    text: var_text,
+                };
+            }
+            14 => {
+                let mut var_field0 =
+                    <Box<crate::api::query::TrackFilter>>::sse_decode(deserializer);
+                return crate::api::query::TrackFilter::Not(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::simple::TrackPage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_tracks = <Vec<crate::api::simple::TrackDTO>>::sse_decode(deserializer);
+        let mut var_total = <u32>::sse_decode(deserializer);
+        return crate::api::simple::TrackPage {
+            tracks: var_tracks,
+            total: var_total,
+        };
+    }
+}
+
+impl SseDecode for crate::api::query::TrackQuery {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_sort = <Vec<crate::api::query::SortKey>>::sse_decode(deserializer);
+        let mut var_filters = <Vec<crate::api::query::TrackFilter>>::sse_decode(deserializer);
+        let mut var_hideOffline = <bool>::sse_decode(deserializer);
+        let mut var_offset = <u32>::sse_decode(deserializer);
+        let mut var_limit = <Option<u32>>::sse_decode(deserializer);
+        return crate::api::query::TrackQuery {
+            sort: var_sort,
+            filters: var_filters,
+            hide_offline: var_hideOffline,
+            offset: var_offset,
+            limit: var_limit,
+        };
+    }
+}
+
+impl SseDecode for crate::api::fuzzy_search::TrackSearchResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_track = <crate::api::simple::TrackDTO>::sse_decode(deserializer);
+        let mut var_score = <f64>::sse_decode(deserializer);
+        let mut var_titleRanges =
+            <Vec<crate::api::fuzzy_search::HighlightRange>>::sse_decode(deserializer);
+        let mut var_albumRanges =
+            <Vec<crate::api::fuzzy_search::HighlightRange>>::sse_decode(deserializer);
+        let mut var_artistRanges =
+            <Vec<crate::api::fuzzy_search::HighlightRange>>::sse_decode(deserializer);
+        return crate::api::fuzzy_search::TrackSearchResult {
+            track: var_track,
+            score: var_score,
+            title_ranges: var_titleRanges,
+            album_ranges: var_albumRanges,
+            artist_ranges: var_artistRanges,
         };
     }
 }
 
-impl SseDecode for crate::model::Artist {
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap()
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+impl SseDecode for usize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    }
+}
+
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        3 => wire_get_album_detail_impl(port, ptr, rust_vec_len, data_len),
+        1 => wire_get_all_albums_impl(port, ptr, rust_vec_len, data_len),
+        2 => wire_get_all_artists_impl(port, ptr, rust_vec_len, data_len),
+        4 => wire_get_artist_detail_impl(port, ptr, rust_vec_len, data_len),
+        6 => wire_set_config_impl(port, ptr, rust_vec_len, data_len),
+        8 => wire_fuzzy_search_tracks_impl(port, ptr, rust_vec_len, data_len),
+        9 => wire_add_library_root_impl(port, ptr, rust_vec_len, data_len),
+        11 => wire_get_library_root_availability_impl(port, ptr, rust_vec_len, data_len),
+        10 => wire_get_library_roots_impl(port, ptr, rust_vec_len, data_len),
+        16 => wire_relocate_library_root_impl(port, ptr, rust_vec_len, data_len),
+        12 => wire_remove_library_root_impl(port, ptr, rust_vec_len, data_len),
+        14 => wire_rescan_library_root_impl(port, ptr, rust_vec_len, data_len),
+        15 => wire_rescan_library_roots_impl(port, ptr, rust_vec_len, data_len),
+        13 => wire_set_library_root_enabled_impl(port, ptr, rust_vec_len, data_len),
+        17 => wire_query_track_ids_impl(port, ptr, rust_vec_len, data_len),
+        18 => wire_query_tracks_impl(port, ptr, rust_vec_len, data_len),
+        21 => wire_clear_scan_errors_impl(port, ptr, rust_vec_len, data_len),
+        20 => wire_get_scan_errors_impl(port, ptr, rust_vec_len, data_len),
+        22 => wire_scan_directory_impl(port, ptr, rust_vec_len, data_len),
+        26 => wire_search_tracks_impl(port, ptr, rust_vec_len, data_len),
+        44 => wire_delete_all_tracks_impl(port, ptr, rust_vec_len, data_len),
+        51 => wire_find_album_by_genre_impl(port, ptr, rust_vec_len, data_len),
+        45 => wire_find_track_by_album_impl(port, ptr, rust_vec_len, data_len),
+        48 => wire_find_track_by_artist_impl(port, ptr, rust_vec_len, data_len),
+        50 => wire_find_track_by_genre_impl(port, ptr, rust_vec_len, data_len),
+        47 => wire_find_track_by_year_impl(port, ptr, rust_vec_len, data_len),
+        31 => wire_frb_init_impl(port, ptr, rust_vec_len, data_len),
+        49 => wire_get_all_genres_impl(port, ptr, rust_vec_len, data_len),
+        40 => wire_get_all_track_ids_sorted_by_album_impl(port, ptr, rust_vec_len, data_len),
+        39 => wire_get_all_track_ids_sorted_by_artist_impl(port, ptr, rust_vec_len, data_len),
+        41 => wire_get_all_track_ids_sorted_by_duration_impl(port, ptr, rust_vec_len, data_len),
+        38 => wire_get_all_track_ids_sorted_by_title_impl(port, ptr, rust_vec_len, data_len),
+        35 => wire_get_all_tracks_impl(port, ptr, rust_vec_len, data_len),
+        46 => wire_get_all_years_impl(port, ptr, rust_vec_len, data_len),
+        42 => wire_get_track_ids_by_audio_properties_impl(port, ptr, rust_vec_len, data_len),
+        36 => wire_get_track_page_impl(port, ptr, rust_vec_len, data_len),
+        37 => wire_get_tracks_by_ids_impl(port, ptr, rust_vec_len, data_len),
+        52 => wire_pick_directory_impl(port, ptr, rust_vec_len, data_len),
+        43 => wire_record_track_played_impl(port, ptr, rust_vec_len, data_len),
+        34 => wire_sync_directory_impl(port, ptr, rust_vec_len, data_len),
+        56 => wire_unwatch_library_impl(port, ptr, rust_vec_len, data_len),
+        55 => wire_watch_library_impl(port, ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        5 => wire_get_config_impl(ptr, rust_vec_len, data_len),
+        7 => wire_get_default_config_impl(ptr, rust_vec_len, data_len),
+        19 => wire_parse_track_filters_impl(ptr, rust_vec_len, data_len),
+        24 => wire_ScanCancelToken_cancel_impl(ptr, rust_vec_len, data_len),
+        25 => wire_ScanCancelToken_is_cancelled_impl(ptr, rust_vec_len, data_len),
+        23 => wire_ScanCancelToken_new_impl(ptr, rust_vec_len, data_len),
+        29 => wire_get_cache_path_impl(ptr, rust_vec_len, data_len),
+        28 => wire_get_config_path_impl(ptr, rust_vec_len, data_len),
+        30 => wire_get_data_path_impl(ptr, rust_vec_len, data_len),
+        27 => wire_get_db_url_impl(ptr, rust_vec_len, data_len),
+        32 => wire_initialize_app_impl(ptr, rust_vec_len, data_len),
+        33 => wire_initialize_db_impl(ptr, rust_vec_len, data_len),
+        54 => wire_duration_to_string_impl(ptr, rust_vec_len, data_len),
+        53 => wire_track_query_filter_condition_impl(ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+pub struct Local_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(
+    ScanCancelToken,
+);
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for Local_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for Local_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        Local_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken,
+    > for ScanCancelToken
+{
+    fn into_into_dart(
+        self,
+    ) -> Local_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken
+    {
+        Local_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedrust_asyncRwLockScanCancelToken(
+            self,
+        )
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::Album {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.artist_id.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.musicbrainz_release_id.into_into_dart().into_dart(),
+            self.release_type.into_into_dart().into_dart(),
+            self.name_romaji.into_into_dart().into_dart(),
+            self.name_hiragana.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::Album {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::Album> for crate::model::Album {
+    fn into_into_dart(self) -> crate::model::Album {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::browse::AlbumDetail {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.album.into_into_dart().into_dart(),
+            self.discs.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::browse::AlbumDetail
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::browse::AlbumDetail>
+    for crate::api::browse::AlbumDetail
+{
+    fn into_into_dart(self) -> crate::api::browse::AlbumDetail {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::browse::AlbumSummary {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.artist.into_into_dart().into_dart(),
+            self.release_type.into_into_dart().into_dart(),
+            self.year.into_into_dart().into_dart(),
+            self.track_count.into_into_dart().into_dart(),
+            self.duration_ms.into_into_dart().into_dart(),
+            self.picture_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::browse::AlbumSummary
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::browse::AlbumSummary>
+    for crate::api::browse::AlbumSummary
+{
+    fn into_into_dart(self) -> crate::api::browse::AlbumSummary {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::Artist {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.name_romaji.into_into_dart().into_dart(),
+            self.name_hiragana.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::Artist {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::Artist> for crate::model::Artist {
+    fn into_into_dart(self) -> crate::model::Artist {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::browse::ArtistDetail {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.artist.into_into_dart().into_dart(),
+            self.release_groups.into_into_dart().into_dart(),
+            self.appears_on.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::browse::ArtistDetail
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::browse::ArtistDetail>
+    for crate::api::browse::ArtistDetail
+{
+    fn into_into_dart(self) -> crate::api::browse::ArtistDetail {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::ArtistRole {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Main => 0.into_dart(),
+            Self::Featured => 1.into_dart(),
+            Self::Remixer => 2.into_dart(),
+            Self::Composer => 3.into_dart(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::ArtistRole
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::ArtistRole>
+    for crate::api::simple::ArtistRole
+{
+    fn into_into_dart(self) -> crate::api::simple::ArtistRole {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::browse::ArtistSummary {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.track_count.into_into_dart().into_dart(),
+            self.album_count.into_into_dart().into_dart(),
+            self.duration_ms.into_into_dart().into_dart(),
+            self.picture_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::browse::ArtistSummary
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::browse::ArtistSummary>
+    for crate::api::browse::ArtistSummary
+{
+    fn into_into_dart(self) -> crate::api::browse::ArtistSummary {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::AudioPropertiesFilter {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.codec.into_into_dart().into_dart(),
+            self.lossless.into_into_dart().into_dart(),
+            self.min_bitrate.into_into_dart().into_dart(),
+            self.max_bitrate.into_into_dart().into_dart(),
+            self.min_sample_rate.into_into_dart().into_dart(),
+            self.min_bit_depth.into_into_dart().into_dart(),
+            self.channels.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::AudioPropertiesFilter
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::AudioPropertiesFilter>
+    for crate::api::simple::AudioPropertiesFilter
+{
+    fn into_into_dart(self) -> crate::api::simple::AudioPropertiesFilter {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::AudioProperty {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Bitrate => 0.into_dart(),
+            Self::SampleRate => 1.into_dart(),
+            Self::BitDepth => 2.into_dart(),
+            Self::Channels => 3.into_dart(),
+            Self::FileSize => 4.into_dart(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::AudioProperty
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::AudioProperty>
+    for crate::api::simple::AudioProperty
+{
+    fn into_into_dart(self) -> crate::api::simple::AudioProperty {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::config::Config {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.extensions.into_into_dart().into_dart(),
+            self.detect_by_content.into_into_dart().into_dart(),
+            self.sniffed_extensions.into_into_dart().into_dart(),
+            self.artist_separators.into_into_dart().into_dart(),
+            self.genre_separators.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::config::Config {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::config::Config> for crate::api::config::Config {
+    fn into_into_dart(self) -> crate::api::config::Config {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::browse::DiscGroup {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.disc.into_into_dart().into_dart(),
+            self.subtitle.into_into_dart().into_dart(),
+            self.tracks.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::browse::DiscGroup {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::browse::DiscGroup>
+    for crate::api::browse::DiscGroup
+{
+    fn into_into_dart(self) -> crate::api::browse::DiscGroup {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::Genre {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::Genre {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::Genre> for crate::model::Genre {
+    fn into_into_dart(self) -> crate::model::Genre {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::GenreSummary {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.track_count.into_into_dart().into_dart(),
+            self.album_count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::GenreSummary
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::GenreSummary>
+    for crate::api::simple::GenreSummary
+{
+    fn into_into_dart(self) -> crate::api::simple::GenreSummary {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::fuzzy_search::HighlightRange {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.start.into_into_dart().into_dart(),
+            self.end.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::fuzzy_search::HighlightRange
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::fuzzy_search::HighlightRange>
+    for crate::api::fuzzy_search::HighlightRange
+{
+    fn into_into_dart(self) -> crate::api::fuzzy_search::HighlightRange {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::watcher::LibraryChangeEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.upserted.into_into_dart().into_dart(),
+            self.removed.into_into_dart().into_dart(),
+            self.moved.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::watcher::LibraryChangeEvent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::watcher::LibraryChangeEvent>
+    for crate::api::watcher::LibraryChangeEvent
+{
+    fn into_into_dart(self) -> crate::api::watcher::LibraryChangeEvent {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::LibraryRoot {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.path.into_into_dart().into_dart(),
+            self.enabled.into_into_dart().into_dart(),
+            self.track_count.into_into_dart().into_dart(),
+            self.last_scanned_at.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::LibraryRoot {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::LibraryRoot> for crate::model::LibraryRoot {
+    fn into_into_dart(self) -> crate::model::LibraryRoot {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::library::LibraryRootAvailability {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.path.into_into_dart().into_dart(),
+            self.available.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::library::LibraryRootAvailability
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::library::LibraryRootAvailability>
+    for crate::api::library::LibraryRootAvailability
+{
+    fn into_into_dart(self) -> crate::api::library::LibraryRootAvailability {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::watcher::MovedTrack {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.from.into_into_dart().into_dart(),
+            self.to.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::watcher::MovedTrack
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::watcher::MovedTrack>
+    for crate::api::watcher::MovedTrack
+{
+    fn into_into_dart(self) -> crate::api::watcher::MovedTrack {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::query_language::QueryParseError {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.message.into_into_dart().into_dart(),
+            self.start.into_into_dart().into_dart(),
+            self.end.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::query_language::QueryParseError
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::query_language::QueryParseError>
+    for crate::api::query_language::QueryParseError
+{
+    fn into_into_dart(self) -> crate::api::query_language::QueryParseError {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::browse::ReleaseGroup {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.release_type.into_into_dart().into_dart(),
+            self.albums.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::browse::ReleaseGroup
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::browse::ReleaseGroup>
+    for crate::api::browse::ReleaseGroup
+{
+    fn into_into_dart(self) -> crate::api::browse::ReleaseGroup {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::ScanError {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.location.into_into_dart().into_dart(),
+            self.mount_point.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.occurred_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::ScanError {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::ScanError> for crate::model::ScanError {
+    fn into_into_dart(self) -> crate::model::ScanError {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scan::ScanEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::scan::ScanEvent::Progress(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::scan::ScanEvent::Error {
+                path,
+                kind,
+                message,
+            } => [
+                1.into_dart(),
+                path.into_into_dart().into_dart(),
+                kind.into_into_dart().into_dart(),
+                message.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::scan::ScanEvent::Finished(field0) => {
+                [2.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::scan::ScanEvent {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scan::ScanEvent>
+    for crate::api::scan::ScanEvent
+{
+    fn into_into_dart(self) -> crate::api::scan::ScanEvent {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scan::ScanProgress {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.discovered.into_into_dart().into_dart(),
+            self.parsed.into_into_dart().into_dart(),
+            self.written.into_into_dart().into_dart(),
+            self.current_path.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::scan::ScanProgress
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scan::ScanProgress>
+    for crate::api::scan::ScanProgress
+{
+    fn into_into_dart(self) -> crate::api::scan::ScanProgress {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::query::SortField {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Title => 0.into_dart(),
+            Self::Artist => 1.into_dart(),
+            Self::AlbumArtist => 2.into_dart(),
+            Self::Album => 3.into_dart(),
+            Self::TrackNumber => 4.into_dart(),
+            Self::Duration => 5.into_dart(),
+            Self::Year => 6.into_dart(),
+            Self::DateAdded => 7.into_dart(),
+            Self::Bitrate => 8.into_dart(),
+            Self::SampleRate => 9.into_dart(),
+            Self::BitDepth => 10.into_dart(),
+            Self::Channels => 11.into_dart(),
+            Self::FileSize => 12.into_dart(),
+            Self::PlayCount => 13.into_dart(),
+            Self::LastPlayed => 14.into_dart(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::query::SortField {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::query::SortField>
+    for crate::api::query::SortField
+{
+    fn into_into_dart(self) -> crate::api::query::SortField {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::query::SortKey {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.field.into_into_dart().into_dart(),
+            self.descending.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::query::SortKey {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::query::SortKey> for crate::api::query::SortKey {
+    fn into_into_dart(self) -> crate::api::query::SortKey {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scan::SyncSummary {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.added.into_into_dart().into_dart(),
+            self.updated.into_into_dart().into_dart(),
+            self.unchanged.into_into_dart().into_dart(),
+            self.failed.into_into_dart().into_dart(),
+            self.removed.into_into_dart().into_dart(),
+            self.cancelled.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::scan::SyncSummary {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scan::SyncSummary>
+    for crate::api::scan::SyncSummary
+{
+    fn into_into_dart(self) -> crate::api::scan::SyncSummary {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::query::TextField {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Any => 0.into_dart(),
+            Self::Title => 1.into_dart(),
+            Self::Artist => 2.into_dart(),
+            Self::Album => 3.into_dart(),
+            Self::Genre => 4.into_dart(),
+            Self::Composer => 5.into_dart(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::query::TextField {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::query::TextField>
+    for crate::api::query::TextField
+{
+    fn into_into_dart(self) -> crate::api::query::TextField {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::TrackArtistDTO {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.artist.into_into_dart().into_dart(),
+            self.role.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::TrackArtistDTO
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::TrackArtistDTO>
+    for crate::api::simple::TrackArtistDTO
+{
+    fn into_into_dart(self) -> crate::api::simple::TrackArtistDTO {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::TrackDTO {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.title_romaji.into_into_dart().into_dart(),
+            self.title_hiragana.into_into_dart().into_dart(),
+            self.artist.into_into_dart().into_dart(),
+            self.album.into_into_dart().into_dart(),
+            self.album_artist.into_into_dart().into_dart(),
+            self.artists.into_into_dart().into_dart(),
+            self.number.into_into_dart().into_dart(),
+            self.disc.into_into_dart().into_dart(),
+            self.duration_ms.into_into_dart().into_dart(),
+            self.location.into_into_dart().into_dart(),
+            self.mount_point.into_into_dart().into_dart(),
+            self.picture_id.into_into_dart().into_dart(),
+            self.available.into_into_dart().into_dart(),
+            self.year.into_into_dart().into_dart(),
+            self.date.into_into_dart().into_dart(),
+            self.original_date.into_into_dart().into_dart(),
+            self.genre.into_into_dart().into_dart(),
+            self.genres.into_into_dart().into_dart(),
+            self.composer.into_into_dart().into_dart(),
+            self.comment.into_into_dart().into_dart(),
+            self.track_total.into_into_dart().into_dart(),
+            self.disc_total.into_into_dart().into_dart(),
+            self.bpm.into_into_dart().into_dart(),
+            self.isrc.into_into_dart().into_dart(),
+            self.label.into_into_dart().into_dart(),
+            self.codec.into_into_dart().into_dart(),
+            self.bitrate.into_into_dart().into_dart(),
+            self.sample_rate.into_into_dart().into_dart(),
+            self.bit_depth.into_into_dart().into_dart(),
+            self.channels.into_into_dart().into_dart(),
+            self.file_size.into_into_dart().into_dart(),
+            self.lossless.into_into_dart().into_dart(),
+            self.disc_subtitle.into_into_dart().into_dart(),
+            self.play_count.into_into_dart().into_dart(),
+            self.last_played_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::simple::TrackDTO {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::TrackDTO>
+    for crate::api::simple::TrackDTO
+{
+    fn into_into_dart(self) -> crate::api::simple::TrackDTO {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::query::TrackFilter {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::query::TrackFilter::Artist(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::query::TrackFilter::Album(field0) => {
+                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::query::TrackFilter::Genre(field0) => {
+                [2.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::query::TrackFilter::Year { min, max } => [
+                3.into_dart(),
+                min.into_into_dart().into_dart(),
+                max.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::query::TrackFilter::DurationMs { min, max } => [
+                4.into_dart(),
+                min.into_into_dart().into_dart(),
+                max.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::query::TrackFilter::Codec(field0) => {
+                [5.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::query::TrackFilter::FileExtension(field0) => {
+                [6.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::query::TrackFilter::Lossless(field0) => {
+                [7.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::query::TrackFilter::Bitrate { min, max } => [
+                8.into_dart(),
+                min.into_into_dart().into_dart(),
+                max.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::query::TrackFilter::MinSampleRate(field0) => {
+                [9.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::query::TrackFilter::MinBitDepth(field0) => {
+                [10.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::query::TrackFilter::Channels(field0) => {
+                [11.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::query::TrackFilter::LibraryRoot(field0) => {
+                [12.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::query::TrackFilter::Text { field, text } => [
+                13.into_dart(),
+                field.into_into_dart().into_dart(),
+                text.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::query::TrackFilter::Not(field0) => {
+                [14.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::query::TrackFilter
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::query::TrackFilter>
+    for crate::api::query::TrackFilter
+{
+    fn into_into_dart(self) -> crate::api::query::TrackFilter {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::TrackPage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.tracks.into_into_dart().into_dart(),
+            self.total.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::simple::TrackPage {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::TrackPage>
+    for crate::api::simple::TrackPage
+{
+    fn into_into_dart(self) -> crate::api::simple::TrackPage {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::query::TrackQuery {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.sort.into_into_dart().into_dart(),
+            self.filters.into_into_dart().into_dart(),
+            self.hide_offline.into_into_dart().into_dart(),
+            self.offset.into_into_dart().into_dart(),
+            self.limit.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::query::TrackQuery {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::query::TrackQuery>
+    for crate::api::query::TrackQuery
+{
+    fn into_into_dart(self) -> crate::api::query::TrackQuery {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::fuzzy_search::TrackSearchResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.track.into_into_dart().into_dart(),
+            self.score.into_into_dart().into_dart(),
+            self.title_ranges.into_into_dart().into_dart(),
+            self.album_ranges.into_into_dart().into_dart(),
+            self.artist_ranges.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::fuzzy_search::TrackSearchResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::fuzzy_search::TrackSearchResult>
+    for crate::api::fuzzy_search::TrackSearchResult
+{
+    fn into_into_dart(self) -> crate::api::fuzzy_search::TrackSearchResult {
+        self
+    }
+}
+
+impl SseEncode for ScanCancelToken {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::rust_async::RwLock<ScanCancelToken>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+    }
+}
+
+impl SseEncode for chrono::Duration {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(
+            self.num_microseconds()
+                .expect("cannot get microseconds from time"),
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for chrono::NaiveDateTime {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.timestamp_micros(), serializer);
+    }
+}
+
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::rust_async::RwLock<ScanCancelToken>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
+    }
+}
+
+impl SseEncode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+    }
+}
+
+impl SseEncode for crate::model::Album {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <Option<i32>>::sse_encode(self.artist_id, serializer);
+        <chrono::NaiveDateTime>::sse_encode(self.created_at, serializer);
+        <Option<String>>::sse_encode(self.musicbrainz_release_id, serializer);
+        <Option<String>>::sse_encode(self.release_type, serializer);
+        <Option<String>>::sse_encode(self.name_romaji, serializer);
+        <Option<String>>::sse_encode(self.name_hiragana, serializer);
+    }
+}
+
+impl SseEncode for crate::api::browse::AlbumDetail {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::browse::AlbumSummary>::sse_encode(self.album, serializer);
+        <Vec<crate::api::browse::DiscGroup>>::sse_encode(self.discs, serializer);
+    }
+}
+
+impl SseEncode for crate::api::browse::AlbumSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <Option<crate::model::Artist>>::sse_encode(self.artist, serializer);
+        <Option<String>>::sse_encode(self.release_type, serializer);
+        <Option<i32>>::sse_encode(self.year, serializer);
+        <i32>::sse_encode(self.track_count, serializer);
+        <i64>::sse_encode(self.duration_ms, serializer);
+        <Option<String>>::sse_encode(self.picture_id, serializer);
+    }
+}
+
+impl SseEncode for crate::model::Artist {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <i32>::sse_decode(deserializer);
-        let mut var_name = <String>::sse_decode(deserializer);
-        let mut var_createdAt = <chrono::NaiveDateTime>::sse_decode(deserializer);
-        return crate::model::Artist {
-            id: var_id,
-            name: var_name,
-            created_at: var_createdAt,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <chrono::NaiveDateTime>::sse_encode(self.created_at, serializer);
+        <Option<String>>::sse_encode(self.name_romaji, serializer);
+        <Option<String>>::sse_encode(self.name_hiragana, serializer);
     }
 }
 
-impl SseDecode for bool {
+impl SseEncode for crate::api::browse::ArtistDetail {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::browse::ArtistSummary>::sse_encode(self.artist, serializer);
+        <Vec<crate::api::browse::ReleaseGroup>>::sse_encode(self.release_groups, serializer);
+        <Vec<crate::api::browse::AlbumSummary>>::sse_encode(self.appears_on, serializer);
     }
 }
 
-impl SseDecode for i32 {
+impl SseEncode for crate::api::simple::ArtistRole {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::simple::ArtistRole::Main => 0,
+                crate::api::simple::ArtistRole::Featured => 1,
+                crate::api::simple::ArtistRole::Remixer => 2,
+                crate::api::simple::ArtistRole::Composer => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseDecode for i64 {
+impl SseEncode for crate::api::browse::ArtistSummary {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <i32>::sse_encode(self.track_count, serializer);
+        <i32>::sse_encode(self.album_count, serializer);
+        <i64>::sse_encode(self.duration_ms, serializer);
+        <Option<String>>::sse_encode(self.picture_id, serializer);
     }
 }
 
-impl SseDecode for Vec<i32> {
+impl SseEncode for crate::api::simple::AudioPropertiesFilter {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<i32>::sse_decode(deserializer));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.codec, serializer);
+        <Option<bool>>::sse_encode(self.lossless, serializer);
+        <Option<i32>>::sse_encode(self.min_bitrate, serializer);
+        <Option<i32>>::sse_encode(self.max_bitrate, serializer);
+        <Option<i32>>::sse_encode(self.min_sample_rate, serializer);
+        <Option<i32>>::sse_encode(self.min_bit_depth, serializer);
+        <Option<i32>>::sse_encode(self.channels, serializer);
     }
 }
 
-impl SseDecode for Vec<u8> {
+impl SseEncode for crate::api::simple::AudioProperty {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::simple::AudioProperty::Bitrate => 0,
+                crate::api::simple::AudioProperty::SampleRate => 1,
+                crate::api::simple::AudioProperty::BitDepth => 2,
+                crate::api::simple::AudioProperty::Channels => 3,
+                crate::api::simple::AudioProperty::FileSize => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseDecode for Vec<crate::api::simple::TrackDTO> {
+impl SseEncode for bool {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::simple::TrackDTO>::sse_decode(deserializer));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self as _).unwrap();
     }
 }
 
-impl SseDecode for Option<String> {
+impl SseEncode for Box<crate::api::query::TrackFilter> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<String>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::query::TrackFilter>::sse_encode(*self, serializer);
     }
 }
 
-impl SseDecode for Option<crate::model::Album> {
+impl SseEncode for crate::api::config::Config {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::model::Album>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<String>>::sse_encode(self.extensions, serializer);
+        <bool>::sse_encode(self.detect_by_content, serializer);
+        <Vec<String>>::sse_encode(self.sniffed_extensions, serializer);
+        <Vec<String>>::sse_encode(self.artist_separators, serializer);
+        <Vec<String>>::sse_encode(self.genre_separators, serializer);
     }
 }
 
-impl SseDecode for Option<crate::model::Artist> {
+impl SseEncode for crate::api::browse::DiscGroup {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::model::Artist>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<i32>>::sse_encode(self.disc, serializer);
+        <Option<String>>::sse_encode(self.subtitle, serializer);
+        <Vec<crate::api::simple::TrackDTO>>::sse_encode(self.tracks, serializer);
     }
 }
 
-impl SseDecode for Option<i32> {
+impl SseEncode for f64 {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<i32>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
     }
 }
 
-impl SseDecode for crate::api::simple::TrackDTO {
+impl SseEncode for crate::model::Genre {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <i32>::sse_decode(deserializer);
-        let mut var_title = <Option<String>>::sse_decode(deserializer);
-        let mut var_artist = <Option<crate::model::Artist>>::sse_decode(deserializer);
-        let mut var_album = <Option<crate::model::Album>>::sse_decode(deserializer);
-        let mut var_number = <Option<i32>>::sse_decode(deserializer);
-        let mut var_disc = <Option<i32>>::sse_decode(deserializer);
-        let mut var_durationMs = <i32>::sse_decode(deserializer);
-        let mut var_location = <String>::sse_decode(deserializer);
-        let mut var_mountPoint = <String>::sse_decode(deserializer);
-        let mut var_pictureId = <Option<String>>::sse_decode(deserializer);
-        return crate::api::simple::TrackDTO {
-            id: var_id,
-            title: var_title,
-            artist: var_artist,
-            album: var_album,
-            number: var_number,
-            disc: var_disc,
-            duration_ms: var_durationMs,
-            location: var_location,
-            mount_point: var_mountPoint,
-            picture_id: var_pictureId,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <chrono::NaiveDateTime>::sse_encode(self.created_at, serializer);
     }
 }
 
-impl SseDecode for u8 {
+impl SseEncode for crate::api::simple::GenreSummary {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <i32>::sse_encode(self.track_count, serializer);
+        <i32>::sse_encode(self.album_count, serializer);
     }
 }
 
-impl SseDecode for () {
+impl SseEncode for crate::api::fuzzy_search::HighlightRange {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.start, serializer);
+        <u32>::sse_encode(self.end, serializer);
+    }
 }
 
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        14 => wire_delete_all_tracks_impl(port, ptr, rust_vec_len, data_len),
-        15 => wire_find_track_by_album_impl(port, ptr, rust_vec_len, data_len),
-        5 => wire_frb_init_impl(port, ptr, rust_vec_len, data_len),
-        12 => wire_get_all_track_ids_sorted_by_album_impl(port, ptr, rust_vec_len, data_len),
-        11 => wire_get_all_track_ids_sorted_by_artist_impl(port, ptr, rust_vec_len, data_len),
-        13 => wire_get_all_track_ids_sorted_by_duration_impl(port, ptr, rust_vec_len, data_len),
-        10 => wire_get_all_track_ids_sorted_by_title_impl(port, ptr, rust_vec_len, data_len),
-        9 => wire_get_all_tracks_impl(port, ptr, rust_vec_len, data_len),
-        16 => wire_pick_directory_impl(port, ptr, rust_vec_len, data_len),
-        8 => wire_sync_directory_impl(port, ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+impl SseEncode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
     }
 }
 
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        3 => wire_get_cache_path_impl(ptr, rust_vec_len, data_len),
-        2 => wire_get_config_path_impl(ptr, rust_vec_len, data_len),
-        4 => wire_get_data_path_impl(ptr, rust_vec_len, data_len),
-        1 => wire_get_db_url_impl(ptr, rust_vec_len, data_len),
-        6 => wire_initialize_app_impl(ptr, rust_vec_len, data_len),
-        7 => wire_initialize_db_impl(ptr, rust_vec_len, data_len),
-        18 => wire_duration_to_string_impl(ptr, rust_vec_len, data_len),
-        17 => wire_track_query_filter_condition_impl(ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+impl SseEncode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
     }
 }
 
-// Section: rust2dart
+impl SseEncode for crate::api::watcher::LibraryChangeEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<String>>::sse_encode(self.upserted, serializer);
+        <Vec<String>>::sse_encode(self.removed, serializer);
+        <Vec<crate::api::watcher::MovedTrack>>::sse_encode(self.moved, serializer);
+    }
+}
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::model::Album {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.id.into_into_dart().into_dart(),
-            self.name.into_into_dart().into_dart(),
-            self.artist_id.into_into_dart().into_dart(),
-            self.created_at.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+impl SseEncode for crate::model::LibraryRoot {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.path, serializer);
+        <bool>::sse_encode(self.enabled, serializer);
+        <i32>::sse_encode(self.track_count, serializer);
+        <Option<chrono::NaiveDateTime>>::sse_encode(self.last_scanned_at, serializer);
+        <chrono::NaiveDateTime>::sse_encode(self.created_at, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::Album {}
-impl flutter_rust_bridge::IntoIntoDart<crate::model::Album> for crate::model::Album {
-    fn into_into_dart(self) -> crate::model::Album {
-        self
+
+impl SseEncode for crate::api::library::LibraryRootAvailability {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.path, serializer);
+        <bool>::sse_encode(self.available, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::model::Artist {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.id.into_into_dart().into_dart(),
-            self.name.into_into_dart().into_dart(),
-            self.created_at.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::Artist {}
-impl flutter_rust_bridge::IntoIntoDart<crate::model::Artist> for crate::model::Artist {
-    fn into_into_dart(self) -> crate::model::Artist {
-        self
+
+impl SseEncode for Vec<crate::model::Album> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::model::Album>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::simple::TrackDTO {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.id.into_into_dart().into_dart(),
-            self.title.into_into_dart().into_dart(),
-            self.artist.into_into_dart().into_dart(),
-            self.album.into_into_dart().into_dart(),
-            self.number.into_into_dart().into_dart(),
-            self.disc.into_into_dart().into_dart(),
-            self.duration_ms.into_into_dart().into_dart(),
-            self.location.into_into_dart().into_dart(),
-            self.mount_point.into_into_dart().into_dart(),
-            self.picture_id.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<crate::api::browse::AlbumSummary> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::browse::AlbumSummary>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::browse::ArtistSummary> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::browse::ArtistSummary>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::browse::DiscGroup> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::browse::DiscGroup>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::model::Genre> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::model::Genre>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::simple::TrackDTO {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::TrackDTO>
-    for crate::api::simple::TrackDTO
-{
-    fn into_into_dart(self) -> crate::api::simple::TrackDTO {
-        self
+
+impl SseEncode for Vec<crate::api::simple::GenreSummary> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::simple::GenreSummary>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for chrono::Duration {
+impl SseEncode for Vec<crate::api::fuzzy_search::HighlightRange> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i64>::sse_encode(
-            self.num_microseconds()
-                .expect("cannot get microseconds from time"),
-            serializer,
-        );
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::fuzzy_search::HighlightRange>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for chrono::NaiveDateTime {
+impl SseEncode for Vec<crate::model::LibraryRoot> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i64>::sse_encode(self.timestamp_micros(), serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::model::LibraryRoot>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for String {
+impl SseEncode for Vec<crate::api::library::LibraryRootAvailability> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::library::LibraryRootAvailability>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::model::Album {
+impl SseEncode for Vec<crate::api::watcher::MovedTrack> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.id, serializer);
-        <String>::sse_encode(self.name, serializer);
-        <Option<i32>>::sse_encode(self.artist_id, serializer);
-        <chrono::NaiveDateTime>::sse_encode(self.created_at, serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::watcher::MovedTrack>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::model::Artist {
+impl SseEncode for Vec<i32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.id, serializer);
-        <String>::sse_encode(self.name, serializer);
-        <chrono::NaiveDateTime>::sse_encode(self.created_at, serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <i32>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for bool {
+impl SseEncode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u8>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for i32 {
+impl SseEncode for Vec<crate::api::browse::ReleaseGroup> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::browse::ReleaseGroup>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for i64 {
+impl SseEncode for Vec<crate::model::ScanError> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::model::ScanError>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for Vec<i32> {
+impl SseEncode for Vec<crate::api::query::SortKey> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
         for item in self {
-            <i32>::sse_encode(item, serializer);
+            <crate::api::query::SortKey>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<u8> {
+impl SseEncode for Vec<crate::api::scan::SyncSummary> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
         for item in self {
-            <u8>::sse_encode(item, serializer);
+            <crate::api::scan::SyncSummary>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::simple::TrackArtistDTO> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::simple::TrackArtistDTO>::sse_encode(item, serializer);
         }
     }
 }
@@ -980,6 +4421,34 @@ impl SseEncode for Vec<crate::api::simple::TrackDTO> {
     }
 }
 
+impl SseEncode for Vec<crate::api::query::TrackFilter> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::query::TrackFilter>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::fuzzy_search::TrackSearchResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::fuzzy_search::TrackSearchResult>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for crate::api::watcher::MovedTrack {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.from, serializer);
+        <String>::sse_encode(self.to, serializer);
+    }
+}
+
 impl SseEncode for Option<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -990,6 +4459,16 @@ impl SseEncode for Option<String> {
     }
 }
 
+impl SseEncode for Option<chrono::NaiveDateTime> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <chrono::NaiveDateTime>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<crate::model::Album> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1000,6 +4479,16 @@ impl SseEncode for Option<crate::model::Album> {
     }
 }
 
+impl SseEncode for Option<crate::api::browse::AlbumDetail> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::browse::AlbumDetail>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<crate::model::Artist> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1010,6 +4499,36 @@ impl SseEncode for Option<crate::model::Artist> {
     }
 }
 
+impl SseEncode for Option<crate::api::browse::ArtistDetail> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::browse::ArtistDetail>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<bool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <bool>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <f64>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<i32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1020,19 +4539,307 @@ impl SseEncode for Option<i32> {
     }
 }
 
+impl SseEncode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u32>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for crate::api::query_language::QueryParseError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.message, serializer);
+        <u32>::sse_encode(self.start, serializer);
+        <u32>::sse_encode(self.end, serializer);
+    }
+}
+
+impl SseEncode for crate::api::browse::ReleaseGroup {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.release_type, serializer);
+        <Vec<crate::api::browse::AlbumSummary>>::sse_encode(self.albums, serializer);
+    }
+}
+
+impl SseEncode for crate::model::ScanError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.location, serializer);
+        <String>::sse_encode(self.mount_point, serializer);
+        <String>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <chrono::NaiveDateTime>::sse_encode(self.occurred_at, serializer);
+    }
+}
+
+impl SseEncode for crate::api::scan::ScanEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::scan::ScanEvent::Progress(field0) => {
+                <i32>::sse_encode(0, serializer);
+                <crate::api::scan::ScanProgress>::sse_encode(field0, serializer);
+            }
+            crate::api::scan::ScanEvent::Error {
+                path,
+                kind,
+                message,
+            } => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(path, serializer);
+                <String>::sse_encode(kind, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            crate::api::scan::ScanEvent::Finished(field0) => {
+                <i32>::sse_encode(2, serializer);
+                <crate::api::scan::SyncSummary>::sse_encode(field0, serializer);
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::scan::ScanProgress {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.discovered, serializer);
+        <u32>::sse_encode(self.parsed, serializer);
+        <u32>::sse_encode(self.written, serializer);
+        <String>::sse_encode(self.current_path, serializer);
+    }
+}
+
+impl SseEncode for crate::api::query::SortField {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::query::SortField::Title => 0,
+                crate::api::query::SortField::Artist => 1,
+                crate::api::query::SortField::AlbumArtist => 2,
+                crate::api::query::SortField::Album => 3,
+                crate::api::query::SortField::TrackNumber => 4,
+                crate::api::query::SortField::Duration => 5,
+                crate::api::query::SortField::Year => 6,
+                crate::api::query::SortField::DateAdded => 7,
+                crate::api::query::SortField::Bitrate => 8,
+                crate::api::query::SortField::SampleRate => 9,
+                crate::api::query::SortField::BitDepth => 10,
+                crate::api::query::SortField::Channels => 11,
+                crate::api::query::SortField::FileSize => 12,
+                crate::api::query::SortField::PlayCount => 13,
+                crate::api::query::SortField::LastPlayed => 14,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::query::SortKey {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::query::SortField>::sse_encode(self.field, serializer);
+        <bool>::sse_encode(self.descending, serializer);
+    }
+}
+
+impl SseEncode for crate::api::scan::SyncSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.added, serializer);
+        <u32>::sse_encode(self.updated, serializer);
+        <u32>::sse_encode(self.unchanged, serializer);
+        <u32>::sse_encode(self.failed, serializer);
+        <u32>::sse_encode(self.removed, serializer);
+        <bool>::sse_encode(self.cancelled, serializer);
+    }
+}
+
+impl SseEncode for crate::api::query::TextField {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::query::TextField::Any => 0,
+                crate::api::query::TextField::Title => 1,
+                crate::api::query::TextField::Artist => 2,
+                crate::api::query::TextField::Album => 3,
+                crate::api::query::TextField::Genre => 4,
+                crate::api::query::TextField::Composer => 5,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::simple::TrackArtistDTO {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::model::Artist>::sse_encode(self.artist, serializer);
+        <crate::api::simple::ArtistRole>::sse_encode(self.role, serializer);
+    }
+}
+
 impl SseEncode for crate::api::simple::TrackDTO {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.id, serializer);
         <Option<String>>::sse_encode(self.title, serializer);
+        <Option<String>>::sse_encode(self.title_romaji, serializer);
+        <Option<String>>::sse_encode(self.title_hiragana, serializer);
         <Option<crate::model::Artist>>::sse_encode(self.artist, serializer);
         <Option<crate::model::Album>>::sse_encode(self.album, serializer);
+        <Option<crate::model::Artist>>::sse_encode(self.album_artist, serializer);
+        <Vec<crate::api::simple::TrackArtistDTO>>::sse_encode(self.artists, serializer);
         <Option<i32>>::sse_encode(self.number, serializer);
         <Option<i32>>::sse_encode(self.disc, serializer);
         <i32>::sse_encode(self.duration_ms, serializer);
         <String>::sse_encode(self.location, serializer);
         <String>::sse_encode(self.mount_point, serializer);
         <Option<String>>::sse_encode(self.picture_id, serializer);
+        <bool>::sse_encode(self.available, serializer);
+        <Option<i32>>::sse_encode(self.year, serializer);
+        <Option<String>>::sse_encode(self.date, serializer);
+        <Option<String>>::sse_encode(self.original_date, serializer);
+        <Option<String>>::sse_encode(self.genre, serializer);
+        <Vec<crate::model::Genre>>::sse_encode(self.genres, serializer);
+        <Option<String>>::sse_encode(self.composer, serializer);
+        <Option<String>>::sse_encode(self.comment, serializer);
+        <Option<i32>>::sse_encode(self.track_total, serializer);
+        <Option<i32>>::sse_encode(self.disc_total, serializer);
+        <Option<f64>>::sse_encode(self.bpm, serializer);
+        <Option<String>>::sse_encode(self.isrc, serializer);
+        <Option<String>>::sse_encode(self.label, serializer);
+        <Option<String>>::sse_encode(self.codec, serializer);
+        <Option<i32>>::sse_encode(self.bitrate, serializer);
+        <Option<i32>>::sse_encode(self.sample_rate, serializer);
+        <Option<i32>>::sse_encode(self.bit_depth, serializer);
+        <Option<i32>>::sse_encode(self.channels, serializer);
+        <i64>::sse_encode(self.file_size, serializer);
+        <bool>::sse_encode(self.lossless, serializer);
+        <Option<String>>::sse_encode(self.disc_subtitle, serializer);
+        <i32>::sse_encode(self.play_count, serializer);
+        <Option<chrono::NaiveDateTime>>::sse_encode(self.last_played_at, serializer);
+    }
+}
+
+impl SseEncode for crate::api::query::TrackFilter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::query::TrackFilter::Artist(field0) => {
+                <i32>::sse_encode(0, serializer);
+                <i32>::sse_encode(field0, serializer);
+            }
+            crate::api::query::TrackFilter::Album(field0) => {
+                <i32>::sse_encode(1, serializer);
+                <i32>::sse_encode(field0, serializer);
+            }
+            crate::api::query::TrackFilter::Genre(field0) => {
+                <i32>::sse_encode(2, serializer);
+                <i32>::sse_encode(field0, serializer);
+            }
+            crate::api::query::TrackFilter::Year { min, max } => {
+                <i32>::sse_encode(3, serializer);
+                <Option<i32>>::sse_encode(min, serializer);
+                <Option<i32>>::sse_encode(max, serializer);
+            }
+            crate::api::query::TrackFilter::DurationMs { min, max } => {
+                <i32>::sse_encode(4, serializer);
+                <Option<i32>>::sse_encode(min, serializer);
+                <Option<i32>>::sse_encode(max, serializer);
+            }
+            crate::api::query::TrackFilter::Codec(field0) => {
+                <i32>::sse_encode(5, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::query::TrackFilter::FileExtension(field0) => {
+                <i32>::sse_encode(6, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::query::TrackFilter::Lossless(field0) => {
+                <i32>::sse_encode(7, serializer);
+                <bool>::sse_encode(field0, serializer);
+            }
+            crate::api::query::TrackFilter::Bitrate { min, max } => {
+                <i32>::sse_encode(8, serializer);
+                <Option<i32>>::sse_encode(min, serializer);
+                <Option<i32>>::sse_encode(max, serializer);
+            }
+            crate::api::query::TrackFilter::MinSampleRate(field0) => {
+                <i32>::sse_encode(9, serializer);
+                <i32>::sse_encode(field0, serializer);
+            }
+            crate::api::query::TrackFilter::MinBitDepth(field0) => {
+                <i32>::sse_encode(10, serializer);
+                <i32>::sse_encode(field0, serializer);
+            }
+            crate::api::query::TrackFilter::Channels(field0) => {
+                <i32>::sse_encode(11, serializer);
+                <i32>::sse_encode(field0, serializer);
+            }
+            crate::api::query::TrackFilter::LibraryRoot(field0) => {
+                <i32>::sse_encode(12, serializer);
+                <i32>::sse_encode(field0, serializer);
+            }
+            crate::api::query::TrackFilter::Text { field, text } => {
+                <i32>::sse_encode(13, serializer);
+                <crate::api::query::TextField>::sse_encode(field, serializer);
+                <String>::sse_encode(text, serializer);
+            }
+            crate::api::query::TrackFilter::Not(field0) => {
+                <i32>::sse_encode(14, serializer);
+                <Box<crate::api::query::TrackFilter>>::sse_encode(field0, serializer);
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::simple::TrackPage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::simple::TrackDTO>>::sse_encode(self.tracks, serializer);
+        <u32>::sse_encode(self.total, serializer);
+    }
+}
+
+impl SseEncode for crate::api::query::TrackQuery {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::query::SortKey>>::sse_encode(self.sort, serializer);
+        <Vec<crate::api::query::TrackFilter>>::sse_encode(self.filters, serializer);
+        <bool>::sse_encode(self.hide_offline, serializer);
+        <u32>::sse_encode(self.offset, serializer);
+        <Option<u32>>::sse_encode(self.limit, serializer);
+    }
+}
+
+impl SseEncode for crate::api::fuzzy_search::TrackSearchResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::simple::TrackDTO>::sse_encode(self.track, serializer);
+        <f64>::sse_encode(self.score, serializer);
+        <Vec<crate::api::fuzzy_search::HighlightRange>>::sse_encode(self.title_ranges, serializer);
+        <Vec<crate::api::fuzzy_search::HighlightRange>>::sse_encode(self.album_ranges, serializer);
+        <Vec<crate::api::fuzzy_search::HighlightRange>>::sse_encode(self.artist_ranges, serializer);
+    }
+}
+
+impl SseEncode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u32::<NativeEndian>(self).unwrap();
     }
 }
 
@@ -1048,6 +4855,16 @@ impl SseEncode for () {
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
 }
 
+impl SseEncode for usize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer
+            .cursor
+            .write_u64::<NativeEndian>(self as _)
+            .unwrap();
+    }
+}
+
 #[cfg(not(target_family = "wasm"))]
 #[path = "frb_generated.io.rs"]
 mod io;
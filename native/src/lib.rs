@@ -2,4 +2,10 @@ pub mod api;
 mod frb_generated; /* AUTO INJECTED BY flutter_rust_bridge. This line may not be accurate, and you can change it according to your needs. */
 
 pub mod model;
-pub mod schema;
\ No newline at end of file
+pub mod schema;
+
+// The bridge codegen looks for every file module under its own name and gives
+// up on ones that only exist in tests, so this one lives under another name.
+#[cfg(test)]
+#[path = "test_support.rs"]
+mod testing;
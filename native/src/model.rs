@@ -1,7 +1,12 @@
 use chrono::NaiveDateTime;
 
+use diesel::sql_types::{Nullable, Text};
+
 use crate::schema::*;
 
+// Kept outside `api` so the bridge codegen does not try to expose it.
+diesel::sql_function!(fn ifnull(x: Nullable<Text>, y: Nullable<Text>) -> Nullable<Text>);
+
 #[derive(diesel::Insertable, diesel::AsChangeset)]
 #[diesel(table_name = track)]
 pub struct NewTrack {
@@ -14,6 +19,28 @@ pub struct NewTrack {
     pub duration_ms: i32,
     pub location: String,
     pub mount_point: String,
+    pub file_size: i64,
+    pub file_modified_at: Option<NaiveDateTime>,
+    pub year: Option<i32>,
+    pub date: Option<String>,
+    pub original_date: Option<String>,
+    pub genre: Option<String>,
+    pub composer: Option<String>,
+    pub comment: Option<String>,
+    pub track_total: Option<i32>,
+    pub disc_total: Option<i32>,
+    pub bpm: Option<f64>,
+    pub isrc: Option<String>,
+    pub label: Option<String>,
+    pub codec: Option<String>,
+    pub bitrate: Option<i32>,
+    pub sample_rate: Option<i32>,
+    pub bit_depth: Option<i32>,
+    pub channels: Option<i32>,
+    pub lossless: bool,
+    pub disc_subtitle: Option<String>,
+    pub title_romaji: Option<String>,
+    pub title_hiragana: Option<String>,
 }
 
 #[derive(diesel::Queryable, diesel::Selectable, diesel::Identifiable, diesel::Associations, Clone)]
@@ -32,6 +59,33 @@ pub struct Track {
     pub location: String,
     pub mount_point: String,
     pub created_at: NaiveDateTime,
+    pub file_size: i64,
+    pub file_modified_at: Option<NaiveDateTime>,
+    pub year: Option<i32>,
+    pub date: Option<String>,
+    pub original_date: Option<String>,
+    pub genre: Option<String>,
+    pub composer: Option<String>,
+    pub comment: Option<String>,
+    pub track_total: Option<i32>,
+    pub disc_total: Option<i32>,
+    pub bpm: Option<f64>,
+    pub isrc: Option<String>,
+    pub label: Option<String>,
+    pub codec: Option<String>,
+    pub bitrate: Option<i32>,
+    pub sample_rate: Option<i32>,
+    pub bit_depth: Option<i32>,
+    pub channels: Option<i32>,
+    pub lossless: bool,
+    pub disc_subtitle: Option<String>,
+    pub play_count: i32,
+    pub last_played_at: Option<NaiveDateTime>,
+    /// Romaji reading of the title, for searching Japanese text in Latin
+    /// letters.
+    pub title_romaji: Option<String>,
+    /// Hiragana reading of the title, which Japanese titles sort by.
+    pub title_hiragana: Option<String>,
 }
 
 #[derive(diesel::Insertable)]
@@ -39,6 +93,9 @@ pub struct Track {
 pub struct NewAlbum {
     pub name: String,
     pub artist_id: Option<i32>,
+    pub musicbrainz_release_id: Option<String>,
+    pub name_romaji: Option<String>,
+    pub name_hiragana: Option<String>,
 }
 
 
@@ -49,12 +106,20 @@ pub struct Album {
     pub name: String,
     pub artist_id: Option<i32>,
     pub created_at: NaiveDateTime,
+    pub musicbrainz_release_id: Option<String>,
+    /// Lowercase MusicBrainz release type, e.g. `album`, `ep`, `single`,
+    /// `compilation` or `live`.
+    pub release_type: Option<String>,
+    pub name_romaji: Option<String>,
+    pub name_hiragana: Option<String>,
 }
 
 #[derive(diesel::Insertable)]
 #[diesel(table_name = artist)]
 pub struct NewArtist {
     pub name: String,
+    pub name_romaji: Option<String>,
+    pub name_hiragana: Option<String>,
 }
 
 #[derive(diesel::Queryable, diesel::Selectable, diesel::Identifiable, Clone)]
@@ -63,4 +128,88 @@ pub struct Artist {
     pub id: i32,
     pub name: String,
     pub created_at: NaiveDateTime,
-}
\ No newline at end of file
+    pub name_romaji: Option<String>,
+    pub name_hiragana: Option<String>,
+}
+
+#[derive(diesel::Insertable)]
+#[diesel(table_name = genre)]
+pub struct NewGenre {
+    pub name: String,
+}
+
+#[derive(diesel::Queryable, diesel::Selectable, diesel::Identifiable, Clone)]
+#[diesel(table_name = genre)]
+pub struct Genre {
+    pub id: i32,
+    pub name: String,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(diesel::Insertable)]
+#[diesel(table_name = library_root)]
+pub struct NewLibraryRoot {
+    pub path: String,
+}
+
+#[derive(diesel::Queryable, diesel::Selectable, diesel::Identifiable, Clone)]
+#[diesel(table_name = library_root)]
+pub struct LibraryRoot {
+    pub id: i32,
+    pub path: String,
+    pub enabled: bool,
+    pub track_count: i32,
+    pub last_scanned_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(diesel::Insertable)]
+#[diesel(table_name = track_artist)]
+pub struct NewTrackArtist {
+    pub track_id: i32,
+    pub artist_id: i32,
+    pub role: String,
+    pub position: i32,
+}
+
+#[derive(diesel::Queryable, diesel::Selectable, Clone)]
+#[diesel(table_name = track_artist)]
+pub struct TrackArtist {
+    pub track_id: i32,
+    pub artist_id: i32,
+    /// One of `main`, `featured`, `remixer` or `composer`.
+    pub role: String,
+    /// Order of the artist among the track's credits.
+    pub position: i32,
+}
+
+#[derive(diesel::Insertable)]
+#[diesel(table_name = track_genre)]
+pub struct NewTrackGenre {
+    pub track_id: i32,
+    pub genre_id: i32,
+    pub position: i32,
+}
+
+#[derive(diesel::Insertable, diesel::AsChangeset)]
+#[diesel(table_name = scan_error)]
+pub struct NewScanError {
+    pub location: String,
+    pub mount_point: String,
+    pub kind: String,
+    pub message: String,
+    pub occurred_at: NaiveDateTime,
+}
+
+/// A file that failed to import during a scan.
+#[derive(diesel::Queryable, diesel::Selectable, diesel::Identifiable, Clone)]
+#[diesel(table_name = scan_error)]
+pub struct ScanError {
+    pub id: i32,
+    pub location: String,
+    pub mount_point: String,
+    /// One of `io`, `unsupported_format`, `decode` or `database`.
+    pub kind: String,
+    pub message: String,
+    pub occurred_at: NaiveDateTime,
+}
@@ -6,6 +6,10 @@ diesel::table! {
         name -> Text,
         artist_id -> Nullable<Integer>,
         created_at -> Timestamp,
+        musicbrainz_release_id -> Nullable<Text>,
+        release_type -> Nullable<Text>,
+        name_romaji -> Nullable<Text>,
+        name_hiragana -> Nullable<Text>,
     }
 }
 
@@ -14,6 +18,38 @@ diesel::table! {
         id -> Integer,
         name -> Text,
         created_at -> Timestamp,
+        name_romaji -> Nullable<Text>,
+        name_hiragana -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    genre (id) {
+        id -> Integer,
+        name -> Text,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    library_root (id) {
+        id -> Integer,
+        path -> Text,
+        enabled -> Bool,
+        track_count -> Integer,
+        last_scanned_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    scan_error (id) {
+        id -> Integer,
+        location -> Text,
+        mount_point -> Text,
+        kind -> Text,
+        message -> Text,
+        occurred_at -> Timestamp,
     }
 }
 
@@ -30,15 +66,65 @@ diesel::table! {
         location -> Text,
         mount_point -> Text,
         created_at -> Timestamp,
+        file_size -> BigInt,
+        file_modified_at -> Nullable<Timestamp>,
+        year -> Nullable<Integer>,
+        date -> Nullable<Text>,
+        original_date -> Nullable<Text>,
+        genre -> Nullable<Text>,
+        composer -> Nullable<Text>,
+        comment -> Nullable<Text>,
+        track_total -> Nullable<Integer>,
+        disc_total -> Nullable<Integer>,
+        bpm -> Nullable<Double>,
+        isrc -> Nullable<Text>,
+        label -> Nullable<Text>,
+        codec -> Nullable<Text>,
+        bitrate -> Nullable<Integer>,
+        sample_rate -> Nullable<Integer>,
+        bit_depth -> Nullable<Integer>,
+        channels -> Nullable<Integer>,
+        lossless -> Bool,
+        disc_subtitle -> Nullable<Text>,
+        play_count -> Integer,
+        last_played_at -> Nullable<Timestamp>,
+        title_romaji -> Nullable<Text>,
+        title_hiragana -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    track_artist (track_id, artist_id, role) {
+        track_id -> Integer,
+        artist_id -> Integer,
+        role -> Text,
+        position -> Integer,
+    }
+}
+
+diesel::table! {
+    track_genre (track_id, genre_id) {
+        track_id -> Integer,
+        genre_id -> Integer,
+        position -> Integer,
     }
 }
 
 diesel::joinable!(album -> artist (artist_id));
 diesel::joinable!(track -> album (album_id));
 diesel::joinable!(track -> artist (artist_id));
+diesel::joinable!(track_artist -> artist (artist_id));
+diesel::joinable!(track_artist -> track (track_id));
+diesel::joinable!(track_genre -> genre (genre_id));
+diesel::joinable!(track_genre -> track (track_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
     album,
     artist,
+    genre,
+    library_root,
+    scan_error,
     track,
+    track_artist,
+    track_genre,
 );
@@ -0,0 +1,134 @@
+//! A throwaway library for tests that go through the database and the files
+//! on disk.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::{Mutex, MutexGuard, OnceLock},
+};
+
+use crate::api::simple::{get_data_path, initialize_app, initialize_db};
+
+/// The database lives at a fixed place under the data directory, so tests
+/// using it take turns.
+static LIBRARY_LOCK: Mutex<()> = Mutex::new(());
+
+/// Directory standing in for the user's home for the whole test run, so the
+/// data, config and cache directories all end up below it.
+fn test_home() -> &'static Path {
+    static HOME: OnceLock<PathBuf> = OnceLock::new();
+
+    HOME.get_or_init(|| {
+        let home = std::env::temp_dir().join(format!("ncudio-test-{}", std::process::id()));
+
+        std::env::set_var("HOME", &home);
+        std::env::set_var("XDG_DATA_HOME", home.join("data"));
+        std::env::set_var("XDG_CONFIG_HOME", home.join("config"));
+        std::env::set_var("XDG_CACHE_HOME", home.join("cache"));
+
+        home
+    })
+}
+
+/// An empty library with a directory to put music files in. Everything is
+/// deleted again when it is dropped.
+pub(crate) struct TestLibrary {
+    pub(crate) music_dir: PathBuf,
+    _lock: MutexGuard<'static, ()>,
+}
+
+impl TestLibrary {
+    pub(crate) fn new() -> TestLibrary {
+        // A test that failed while holding the lock leaves nothing behind
+        // that the next one does not wipe.
+        let lock = LIBRARY_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let home = test_home();
+
+        // Never wipe a real library.
+        assert!(
+            Path::new(&get_data_path()).starts_with(home),
+            "data directory {} is not below {}",
+            get_data_path(),
+            home.display()
+        );
+
+        let _ = fs::remove_dir_all(home);
+        initialize_app();
+        initialize_db();
+
+        let music_dir = home.join("music");
+        fs::create_dir_all(&music_dir).unwrap();
+
+        TestLibrary { music_dir, _lock: lock }
+    }
+
+    /// Location of `name` inside [`TestLibrary::music_dir`].
+    pub(crate) fn path(&self, name: &str) -> PathBuf {
+        self.music_dir.join(name)
+    }
+
+    /// [`TestLibrary::music_dir`] as the scanner takes it.
+    pub(crate) fn mount_point(&self) -> String {
+        self.music_dir.to_string_lossy().to_string()
+    }
+}
+
+impl Drop for TestLibrary {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(test_home());
+    }
+}
+
+/// Writes a short silent WAV file at `path`, creating its directory if needed,
+/// tagged with RIFF INFO `tags` such as `("INAM", "Title")`, `("IART",
+/// "Artist")`, `("IPRD", "Album")` or `("IGNR", "Genre")`.
+pub(crate) fn write_wav(path: &Path, tags: &[(&str, &str)]) {
+    const SAMPLE_RATE: u32 = 8000;
+    const SAMPLES: usize = 800;
+
+    let mut info = b"INFO".to_vec();
+    for (key, value) in tags {
+        assert_eq!(key.len(), 4, "RIFF INFO keys have four characters");
+
+        let mut value = value.as_bytes().to_vec();
+        value.push(0);
+        let size = value.len() as u32;
+        if value.len() % 2 == 1 {
+            value.push(0);
+        }
+
+        info.extend_from_slice(key.as_bytes());
+        info.extend_from_slice(&size.to_le_bytes());
+        info.extend_from_slice(&value);
+    }
+
+    let mut chunks = Vec::new();
+
+    // Mono 16-bit PCM.
+    chunks.extend_from_slice(b"fmt ");
+    chunks.extend_from_slice(&16u32.to_le_bytes());
+    chunks.extend_from_slice(&1u16.to_le_bytes());
+    chunks.extend_from_slice(&1u16.to_le_bytes());
+    chunks.extend_from_slice(&SAMPLE_RATE.to_le_bytes());
+    chunks.extend_from_slice(&(SAMPLE_RATE * 2).to_le_bytes());
+    chunks.extend_from_slice(&2u16.to_le_bytes());
+    chunks.extend_from_slice(&16u16.to_le_bytes());
+
+    chunks.extend_from_slice(b"data");
+    chunks.extend_from_slice(&(SAMPLES as u32 * 2).to_le_bytes());
+    chunks.extend_from_slice(&vec![0; SAMPLES * 2]);
+
+    if !tags.is_empty() {
+        chunks.extend_from_slice(b"LIST");
+        chunks.extend_from_slice(&(info.len() as u32).to_le_bytes());
+        chunks.extend_from_slice(&info);
+    }
+
+    let mut file = b"RIFF".to_vec();
+    file.extend_from_slice(&(chunks.len() as u32 + 4).to_le_bytes());
+    file.extend_from_slice(b"WAVE");
+    file.extend_from_slice(&chunks);
+
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, file).unwrap();
+}